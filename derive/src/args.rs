@@ -114,6 +114,10 @@ pub struct SimpleObjectField {
     pub guard: Option<Meta>,
     #[darling(default)]
     pub visible: Option<Visible>,
+    #[darling(default)]
+    pub complexity: Option<ComplexityType>,
+    #[darling(default)]
+    pub flatten: bool,
 }
 
 #[derive(FromDeriveInput)]
@@ -142,6 +146,8 @@ pub struct SimpleObject {
     pub visible: Option<Visible>,
     #[darling(default, multiple, rename = "concrete")]
     pub concretes: Vec<ConcreteType>,
+    #[darling(default)]
+    pub complex: bool,
 }
 
 #[derive(FromMeta, Default)]
@@ -152,8 +158,10 @@ pub struct Argument {
     pub default: Option<DefaultValue>,
     pub default_with: Option<LitStr>,
     pub validator: Option<Meta>,
+    pub validator_async: Option<Meta>,
     pub key: bool, // for entity
     pub visible: Option<Visible>,
+    pub secret: bool,
 }
 
 #[derive(FromMeta, Default)]
@@ -169,6 +177,14 @@ pub struct Object {
     pub visible: Option<Visible>,
 }
 
+#[derive(FromMeta, Default)]
+#[darling(default)]
+pub struct ComplexObject {
+    pub internal: bool,
+    pub rename_fields: Option<RenameRule>,
+    pub rename_args: Option<RenameRule>,
+}
+
 pub enum ComplexityType {
     Const(usize),
     Fn(String),
@@ -206,6 +222,8 @@ pub struct ObjectField {
     pub guard: Option<Meta>,
     pub visible: Option<Visible>,
     pub complexity: Option<ComplexityType>,
+    pub cached: bool,
+    pub cache_key: Option<String>,
 }
 
 #[derive(FromDeriveInput)]
@@ -284,6 +302,8 @@ pub struct InputObjectField {
     #[darling(default)]
     pub default_with: Option<LitStr>,
     #[darling(default)]
+    pub from_fields: bool,
+    #[darling(default)]
     pub validator: Option<Meta>,
     #[darling(default)]
     pub flatten: bool,
@@ -291,6 +311,10 @@ pub struct InputObjectField {
     pub skip: bool,
     #[darling(default)]
     pub visible: Option<Visible>,
+    #[darling(default)]
+    pub secret: bool,
+    #[darling(multiple, rename = "alias")]
+    pub aliases: Vec<String>,
 }
 
 #[derive(FromDeriveInput)]
@@ -311,6 +335,10 @@ pub struct InputObject {
     pub visible: Option<Visible>,
     #[darling(default, multiple, rename = "concrete")]
     pub concretes: Vec<ConcreteType>,
+    #[darling(default)]
+    pub one_of: bool,
+    #[darling(default)]
+    pub validator: Option<String>,
 }
 
 #[derive(FromMeta)]
@@ -325,6 +353,8 @@ pub struct InterfaceFieldArgument {
     #[darling(default)]
     pub default_with: Option<LitStr>,
     #[darling(default)]
+    pub validator: Option<Meta>,
+    #[darling(default)]
     pub visible: Option<Visible>,
 }
 
@@ -351,6 +381,21 @@ pub struct InterfaceField {
     pub visible: Option<Visible>,
 }
 
+#[derive(FromMeta, Default)]
+#[darling(default)]
+pub struct InterfaceImpl {
+    pub internal: bool,
+    pub name: Option<String>,
+    pub rename_fields: Option<RenameRule>,
+    pub rename_args: Option<RenameRule>,
+    #[darling(multiple, rename = "field")]
+    pub fields: Vec<InterfaceField>,
+    #[darling(multiple, rename = "possible_type")]
+    pub possible_types: Vec<LitStr>,
+    pub extends: bool,
+    pub visible: Option<Visible>,
+}
+
 #[derive(FromVariant)]
 pub struct InterfaceMember {
     pub ident: Ident,
@@ -388,6 +433,8 @@ pub struct Scalar {
     pub name: Option<String>,
     pub use_type_description: bool,
     pub visible: Option<Visible>,
+    pub specified_by_url: Option<String>,
+    pub accept: Option<PathList>,
 }
 
 #[derive(FromMeta, Default)]
@@ -408,7 +455,9 @@ pub struct SubscriptionFieldArgument {
     pub default: Option<DefaultValue>,
     pub default_with: Option<LitStr>,
     pub validator: Option<Meta>,
+    pub validator_async: Option<Meta>,
     pub visible: Option<Visible>,
+    pub secret: bool,
 }
 
 #[derive(FromMeta, Default)]
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Error, ImplItem, ItemImpl};
+
+use crate::args;
+use crate::object_field::{self, ObjectField};
+use crate::utils::{
+    get_crate_name, get_type_path_and_name, parse_graphql_attrs, remove_graphql_attrs,
+    GeneratorResult,
+};
+
+pub fn generate(
+    object_args: &args::ComplexObject,
+    item_impl: &mut ItemImpl,
+) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_name(object_args.internal);
+    let (self_ty, self_name) = get_type_path_and_name(item_impl.self_ty.as_ref())?;
+    let generics = &item_impl.generics;
+    let where_clause = &item_impl.generics.where_clause;
+
+    let mut resolvers = Vec::new();
+    let mut schema_fields = Vec::new();
+    let mut field_names = HashSet::new();
+
+    for item in &mut item_impl.items {
+        if let ImplItem::Method(method) = item {
+            let method_args: args::ObjectField =
+                parse_graphql_attrs(&method.attrs)?.unwrap_or_default();
+
+            if method_args.entity {
+                return Err(Error::new_spanned(
+                    &method,
+                    "Entity lookup functions must be defined on an Object.",
+                )
+                .into());
+            } else if !method_args.skip {
+                let ObjectField {
+                    cfg_attrs,
+                    name,
+                    meta_field,
+                    resolver,
+                } = object_field::generate(
+                    &crate_name,
+                    &self_name,
+                    &quote!(&<Self as #crate_name::Type>::type_name()),
+                    &object_args.rename_fields,
+                    &object_args.rename_args,
+                    method,
+                    &method_args,
+                )?;
+                // Methods behind `cfg` attributes may be alternatives of each other.
+                if cfg_attrs.is_empty() && !field_names.insert(name.clone()) {
+                    return Err(Error::new_spanned(
+                        &method.sig.ident,
+                        format!("Field \"{}\" is already defined.", name),
+                    )
+                    .into());
+                }
+                schema_fields.push(quote! {
+                    #(#cfg_attrs)*
+                    fields.push((::std::borrow::ToOwned::to_owned(#name), #meta_field));
+                });
+                resolvers.push(quote! {
+                    #(#cfg_attrs)*
+                    #resolver
+                });
+            }
+
+            remove_graphql_attrs(&mut method.attrs);
+        }
+    }
+
+    let expanded = quote! {
+        #item_impl
+
+        #[allow(clippy::all, clippy::pedantic, clippy::suspicious_else_formatting)]
+        #[allow(unused_braces, unused_variables, unused_parens, unused_mut)]
+        #[#crate_name::async_trait::async_trait]
+        impl #generics #crate_name::ComplexObject for #self_ty #where_clause {
+            fn fields(registry: &mut #crate_name::registry::Registry) -> ::std::vec::Vec<(::std::string::String, #crate_name::registry::MetaField)> {
+                let mut fields = ::std::vec::Vec::new();
+                #(#schema_fields)*
+                fields
+            }
+
+            async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                #(#resolvers)*
+                ::std::result::Result::Ok(::std::option::Option::None)
+            }
+        }
+    };
+    Ok(expanded.into())
+}
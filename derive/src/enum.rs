@@ -5,7 +5,9 @@ use syn::ext::IdentExt;
 use syn::Error;
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, visible_fn, GeneratorResult};
+use crate::utils::{
+    check_user_defined_name, get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
+};
 
 pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(enum_args.internal);
@@ -19,6 +21,9 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !enum_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&enum_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
@@ -46,6 +51,7 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
                 .rename_items
                 .rename(variant.ident.unraw().to_string(), RenameTarget::EnumItem)
         });
+        check_user_defined_name(&gql_item_name, item_ident)?;
         let item_deprecation = variant
             .deprecation
             .as_ref()
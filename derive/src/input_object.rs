@@ -6,7 +6,8 @@ use syn::Error;
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
 use crate::utils::{
-    generate_default, generate_validator, get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
+    check_user_defined_name, generate_default, generate_default_value_check, generate_validator,
+    get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
 };
 
 pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream> {
@@ -42,6 +43,9 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !object_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&object_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
@@ -53,6 +57,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
     let mut schema_fields = Vec::new();
     let mut flatten_fields = Vec::new();
     let mut federation_fields = Vec::new();
+    let mut get_default_fields = Vec::new();
 
     for field in &s.fields {
         let ident = field.ident.as_ref().unwrap();
@@ -71,6 +76,13 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             continue;
         }
 
+        if !field.flatten {
+            check_user_defined_name(&name, ident)?;
+            for alias in &field.aliases {
+                check_user_defined_name(alias, ident)?;
+            }
+        }
+
         federation_fields.push((ty, name.clone()));
 
         if field.flatten {
@@ -111,9 +123,37 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         let desc = get_rustdoc(&field.attrs)?
             .map(|s| quote! { ::std::option::Option::Some(#s) })
             .unwrap_or_else(|| quote! {::std::option::Option::None});
+        // A `default_with` expression that refers to other fields can only be evaluated when
+        // parsing, so the field is nullable in the schema and has no default value there.
+        let computed_default = field.from_fields;
+        if computed_default && (field.default_with.is_none() || field.default.is_some()) {
+            return Err(Error::new_spanned(
+                ident,
+                "from_fields can only be used together with default_with",
+            )
+            .into());
+        }
         let default = generate_default(&field.default, &field.default_with)?;
+        let schema_ty = if computed_default {
+            quote!(::std::option::Option<#ty>)
+        } else {
+            quote!(#ty)
+        };
+
+        let default_value_check = if computed_default {
+            quote! {}
+        } else {
+            generate_default_value_check(
+                &crate_name,
+                ty,
+                &default,
+                &field.validator,
+                &format!(r#"field "{}.{}""#, gql_typename, name),
+            )?
+        };
         let schema_default = default
             .as_ref()
+            .filter(|_| !computed_default)
             .map(|value| {
                 quote! {
                     ::std::option::Option::Some(::std::string::ToString::to_string(
@@ -123,23 +163,39 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             })
             .unwrap_or_else(|| quote!(::std::option::Option::None));
 
+        let aliases = &field.aliases;
+        let get_value = quote! {
+            obj.get(#name)#(.or_else(|| obj.get(#aliases)))*
+        };
+
         if let Some(default) = default {
+            // An explicit `null` takes the computed default, just like a missing value.
+            let null_pattern = if computed_default {
+                quote!(::std::option::Option::Some(#crate_name::Value::Null) |)
+            } else {
+                quote!()
+            };
             get_fields.push(quote! {
                 #[allow(non_snake_case)]
-                let #ident: #ty = {
-                    match obj.get(#name) {
-                        ::std::option::Option::Some(value) => {
-                            #crate_name::InputType::parse(::std::option::Option::Some(::std::clone::Clone::clone(&value)))
-                                .map_err(#crate_name::InputValueError::propagate)?
-                        },
-                        ::std::option::Option::None => #default,
-                    }
+                let #ident: ::std::option::Option<#ty> = match #get_value {
+                    #null_pattern ::std::option::Option::None => ::std::option::Option::None,
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some(
+                        #crate_name::InputType::parse(::std::option::Option::Some(::std::clone::Clone::clone(&value)))
+                            .map_err(#crate_name::InputValueError::propagate)?
+                    ),
+                };
+            });
+            get_default_fields.push(quote! {
+                #[allow(non_snake_case)]
+                let #ident: #ty = match #ident {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => #default,
                 };
             });
         } else {
             get_fields.push(quote! {
                 #[allow(non_snake_case)]
-                let #ident: #ty = #crate_name::InputType::parse(obj.get(#name).cloned())
+                let #ident: #ty = #crate_name::InputType::parse(#get_value.cloned())
                     .map_err(#crate_name::InputValueError::propagate)?;
             });
         }
@@ -153,14 +209,29 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
 
         fields.push(ident);
         let visible = visible_fn(&field.visible);
+        let secret = field.secret;
+        let nullable_check = if object_args.one_of {
+            let target = format!(r#"Field "{}.{}""#, gql_typename, name);
+            quote! {
+                if <#ty as #crate_name::Type>::qualified_type_name().ends_with('!') {
+                    ::std::panic!("{} of a one_of input object must be nullable", #target);
+                }
+            }
+        } else {
+            quote! {}
+        };
         schema_fields.push(quote! {
+            #default_value_check
+            #nullable_check
             fields.insert(::std::borrow::ToOwned::to_owned(#name), #crate_name::registry::MetaInputValue {
                 name: #name,
                 description: #desc,
-                ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                ty: <#schema_ty as #crate_name::Type>::create_type_info(registry),
                 default_value: #schema_default,
                 validator: #validator,
                 visible: #visible,
+                aliases: &[#(#aliases),*],
+                is_secret: #secret,
             });
         })
     }
@@ -174,6 +245,34 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
     }
 
     let visible = visible_fn(&object_args.visible);
+    let one_of = object_args.one_of;
+    let one_of_check = if one_of {
+        quote! {
+            let fields = obj
+                .iter()
+                .filter(|(_, value)| !::std::matches!(value, #crate_name::Value::Null))
+                .map(|(name, _)| name.as_str())
+                .collect::<::std::vec::Vec<_>>();
+            if let ::std::option::Option::Some(reason) = #crate_name::registry::check_one_of_fields(&fields) {
+                return ::std::result::Result::Err(#crate_name::InputValueError::custom(reason));
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let validator_check = match &object_args.validator {
+        Some(validator) => {
+            let path = syn::parse_str::<syn::Path>(validator)
+                .map_err(|err| Error::new_spanned(&ident, err))?;
+            quote! {
+                if let ::std::result::Result::Err(reason) = #path(&value) {
+                    return ::std::result::Result::Err(#crate_name::InputValueError::custom(reason));
+                }
+            }
+        }
+        None => quote! {},
+    };
 
     let get_federation_fields = {
         let fields = federation_fields.into_iter().map(|(ty, name)| {
@@ -210,6 +309,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                             fields
                         },
                         visible: #visible,
+                        one_of: #one_of,
                     })
                 }
             }
@@ -218,8 +318,12 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             impl #crate_name::InputType for #ident {
                 fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
                     if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
+                        #one_of_check
                         #(#get_fields)*
-                        ::std::result::Result::Ok(Self { #(#fields),* })
+                        #(#get_default_fields)*
+                        let value = Self { #(#fields),* };
+                        #validator_check
+                        ::std::result::Result::Ok(value)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
                     }
@@ -254,13 +358,18 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                             fields
                         },
                         visible: #visible,
+                        one_of: #one_of,
                     })
                 }
 
                 fn __internal_parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> where Self: #crate_name::InputType {
                     if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
+                        #one_of_check
                         #(#get_fields)*
-                        ::std::result::Result::Ok(Self { #(#fields),* })
+                        #(#get_default_fields)*
+                        let value = Self { #(#fields),* };
+                        #validator_check
+                        ::std::result::Result::Ok(value)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
                     }
@@ -7,9 +7,14 @@ use quote::quote;
 use syn::visit_mut::VisitMut;
 use syn::{visit_mut, Error, Lifetime, Type};
 
-use crate::args::{self, InterfaceField, InterfaceFieldArgument, RenameRuleExt, RenameTarget};
+use crate::args::{
+    self, InterfaceField, InterfaceFieldArgument, RenameRule, RenameRuleExt, RenameTarget,
+};
 use crate::output_type::OutputType;
-use crate::utils::{generate_default, get_crate_name, get_rustdoc, visible_fn, GeneratorResult};
+use crate::utils::{
+    check_user_defined_name, generate_default, generate_default_value_check, generate_validator,
+    get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
+};
 
 pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(interface_args.internal);
@@ -31,6 +36,9 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !interface_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&interface_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
@@ -130,19 +138,10 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         .into());
     }
 
-    for InterfaceField {
-        name,
-        method,
-        desc,
-        ty,
-        args,
-        deprecation,
-        external,
-        provides,
-        requires,
-        visible,
-    } in &interface_args.fields
-    {
+    for field in &interface_args.fields {
+        let InterfaceField {
+            name, method, ty, ..
+        } = field;
         let (name, method_name) = if let Some(method) = method {
             (name.to_string(), Ident::new(method, Span::call_site()))
         } else {
@@ -162,27 +161,17 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         let mut use_params = Vec::new();
         let mut decl_params = Vec::new();
         let mut get_params = Vec::new();
-        let mut schema_args = Vec::new();
-        let requires = match &requires {
-            Some(requires) => quote! { ::std::option::Option::Some(#requires) },
-            None => quote! { ::std::option::Option::None },
-        };
-        let provides = match &provides {
-            Some(provides) => quote! { ::std::option::Option::Some(#provides) },
-            None => quote! { ::std::option::Option::None },
-        };
 
         decl_params.push(quote! { ctx: &'ctx #crate_name::Context<'ctx> });
         use_params.push(quote! { ctx });
 
         for InterfaceFieldArgument {
             name,
-            desc,
             ty,
             default,
             default_with,
-            visible,
-        } in args
+            ..
+        } in &field.args
         {
             let ident = Ident::new(name, Span::call_site());
             let name = interface_args
@@ -203,55 +192,24 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             get_params.push(quote! {
                 let #ident: #ty = ctx.param_value(#name, #get_default)?;
             });
-
-            let desc = desc
-                .as_ref()
-                .map(|s| quote! {::std::option::Option::Some(#s)})
-                .unwrap_or_else(|| quote! {::std::option::Option::None});
-            let schema_default = default
-                .as_ref()
-                .map(|value| {
-                    quote! {
-                        ::std::option::Option::Some(::std::string::ToString::to_string(
-                            &<#ty as #crate_name::InputType>::to_value(&#value)
-                        ))
-                    }
-                })
-                .unwrap_or_else(|| quote! {::std::option::Option::None});
-            let visible = visible_fn(&visible);
-            schema_args.push(quote! {
-                args.insert(#name, #crate_name::registry::MetaInputValue {
-                    name: #name,
-                    description: #desc,
-                    ty: <#ty as #crate_name::Type>::create_type_info(registry),
-                    default_value: #schema_default,
-                    validator: ::std::option::Option::None,
-                    visible: #visible,
-                });
-            });
         }
 
         for enum_name in &enum_names {
             calls.push(quote! {
-                #ident::#enum_name(obj) => obj.#method_name(#(#use_params),*).await.map(::std::convert::Into::into)
+                #ident::#enum_name(obj) => obj.#method_name(#(#use_params),*)
+                    .await
+                    .map(::std::convert::Into::into)
+                    .map_err(|err| {
+                        use #crate_name::ErrorExtensions as _;
+                        (&err).extend()
+                    })
             });
         }
 
-        let desc = desc
-            .as_ref()
-            .map(|s| quote! {::std::option::Option::Some(#s)})
-            .unwrap_or_else(|| quote! {::std::option::Option::None});
-        let deprecation = deprecation
-            .as_ref()
-            .map(|s| quote! {::std::option::Option::Some(#s)})
-            .unwrap_or_else(|| quote! {::std::option::Option::None});
-
-        let oty = OutputType::parse(&ty)?;
-        let ty = match oty {
+        let ty = match OutputType::parse(&ty)? {
             OutputType::Value(ty) => ty,
             OutputType::Result(_, ty) => ty,
         };
-        let schema_ty = oty.value_type();
 
         methods.push(quote! {
             #[inline]
@@ -262,26 +220,13 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             }
         });
 
-        let visible = visible_fn(&visible);
-        schema_fields.push(quote! {
-            fields.insert(::std::string::ToString::to_string(#name), #crate_name::registry::MetaField {
-                name: ::std::string::ToString::to_string(#name),
-                description: #desc,
-                args: {
-                    let mut args = #crate_name::indexmap::IndexMap::new();
-                    #(#schema_args)*
-                    args
-                },
-                ty: <#schema_ty as #crate_name::Type>::create_type_info(registry),
-                deprecation: #deprecation,
-                cache_control: ::std::default::Default::default(),
-                external: #external,
-                provides: #provides,
-                requires: #requires,
-                visible: #visible,
-                compute_complexity: ::std::option::Option::None,
-            });
-        });
+        schema_fields.push(generate_schema_field(
+            &crate_name,
+            &gql_typename,
+            &interface_args.rename_fields,
+            &interface_args.rename_args,
+            field,
+        )?);
 
         let resolve_obj = quote! {
             self.#method_name(#(#use_params),*)
@@ -379,3 +324,141 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
     };
     Ok(expanded.into())
 }
+
+/// Generates the registry entry of a field declared with `field(...)` on an interface.
+pub fn generate_schema_field(
+    crate_name: &proc_macro2::TokenStream,
+    type_name: &str,
+    rename_fields: &Option<RenameRule>,
+    rename_args: &Option<RenameRule>,
+    field: &InterfaceField,
+) -> GeneratorResult<proc_macro2::TokenStream> {
+    let InterfaceField {
+        name,
+        method,
+        desc,
+        ty,
+        args,
+        deprecation,
+        external,
+        provides,
+        requires,
+        visible,
+    } = field;
+
+    let name = if method.is_some() {
+        name.to_string()
+    } else {
+        rename_fields.rename(name, RenameTarget::Field)
+    };
+    check_user_defined_name(&name, ty)?;
+    let ty = match syn::parse_str::<syn::Type>(&ty.value()) {
+        Ok(ty) => ty,
+        Err(_) => return Err(Error::new_spanned(&ty, "Expect type").into()),
+    };
+    let requires = match &requires {
+        Some(requires) => quote! { ::std::option::Option::Some(#requires) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let provides = match &provides {
+        Some(provides) => quote! { ::std::option::Option::Some(#provides) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let field_name = name.clone();
+    let mut schema_args = Vec::new();
+    for InterfaceFieldArgument {
+        name,
+        desc,
+        ty,
+        default,
+        default_with,
+        validator,
+        visible,
+    } in args
+    {
+        let name = rename_args.rename(name, RenameTarget::Argument);
+        check_user_defined_name(&name, &ty)?;
+        let ty = match syn::parse_str::<syn::Type>(&ty.value()) {
+            Ok(ty) => ty,
+            Err(_) => return Err(Error::new_spanned(&ty, "Expect type").into()),
+        };
+        let desc = desc
+            .as_ref()
+            .map(|s| quote! {::std::option::Option::Some(#s)})
+            .unwrap_or_else(|| quote! {::std::option::Option::None});
+        let default = generate_default(&default, &default_with)?;
+        let schema_default = default
+            .as_ref()
+            .map(|value| {
+                quote! {
+                    ::std::option::Option::Some(::std::string::ToString::to_string(
+                        &<#ty as #crate_name::InputType>::to_value(&#value)
+                    ))
+                }
+            })
+            .unwrap_or_else(|| quote! {::std::option::Option::None});
+        let default_value_check = generate_default_value_check(
+            crate_name,
+            &ty,
+            &default,
+            &validator,
+            &format!(
+                r#"argument "{}" of field "{}.{}""#,
+                name, type_name, field_name
+            ),
+        )?;
+        let validator = match &validator {
+            Some(meta) => {
+                let stream = generate_validator(crate_name, meta)?;
+                quote!(::std::option::Option::Some(#stream))
+            }
+            None => quote!(::std::option::Option::None),
+        };
+        let visible = visible_fn(&visible);
+        schema_args.push(quote! {
+            #default_value_check
+            args.insert(#name, #crate_name::registry::MetaInputValue {
+                name: #name,
+                description: #desc,
+                ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                default_value: #schema_default,
+                validator: #validator,
+                visible: #visible,
+                aliases: &[],
+                is_secret: false,
+            });
+        });
+    }
+
+    let desc = desc
+        .as_ref()
+        .map(|s| quote! {::std::option::Option::Some(#s)})
+        .unwrap_or_else(|| quote! {::std::option::Option::None});
+    let deprecation = deprecation
+        .as_ref()
+        .map(|s| quote! {::std::option::Option::Some(#s)})
+        .unwrap_or_else(|| quote! {::std::option::Option::None});
+    let schema_ty = OutputType::parse(&ty)?.value_type();
+    let visible = visible_fn(&visible);
+
+    Ok(quote! {
+        fields.insert(::std::string::ToString::to_string(#name), #crate_name::registry::MetaField {
+            name: ::std::string::ToString::to_string(#name),
+            description: #desc,
+            args: {
+                let mut args = #crate_name::indexmap::IndexMap::new();
+                #(#schema_args)*
+                args
+            },
+            ty: <#schema_ty as #crate_name::Type>::create_type_info(registry),
+            deprecation: #deprecation,
+            cache_control: ::std::default::Default::default(),
+            external: #external,
+            provides: #provides,
+            requires: #requires,
+            visible: #visible,
+            compute_complexity: ::std::option::Option::None,
+        });
+    })
+}
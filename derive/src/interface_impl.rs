@@ -0,0 +1,139 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Error, FnArg, ImplItem, ItemImpl};
+
+use crate::args::{self, RenameTarget};
+use crate::interface::generate_schema_field;
+use crate::utils::{
+    check_user_defined_name, get_crate_name, get_rustdoc, get_type_path_and_name, visible_fn,
+    GeneratorResult,
+};
+
+pub fn generate(
+    interface_args: &args::InterfaceImpl,
+    item_impl: &mut ItemImpl,
+) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_name(interface_args.internal);
+    let (self_ty, self_name) = get_type_path_and_name(item_impl.self_ty.as_ref())?;
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+    let extends = interface_args.extends;
+    let gql_typename = interface_args
+        .name
+        .clone()
+        .unwrap_or_else(|| RenameTarget::Type.rename(self_name.clone()));
+    if !interface_args.internal {
+        check_user_defined_name(&gql_typename, &item_impl.self_ty)?;
+    }
+
+    let desc = get_rustdoc(&item_impl.attrs)?
+        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .unwrap_or_else(|| quote! {::std::option::Option::None});
+
+    let has_discriminator = item_impl.items.iter().any(|item| match item {
+        ImplItem::Method(method) => {
+            method.sig.ident == "type_name"
+                && method.sig.inputs.len() == 1
+                && matches!(method.sig.inputs.first(), Some(FnArg::Receiver(_)))
+        }
+        _ => false,
+    });
+    if !has_discriminator {
+        return Err(Error::new_spanned(
+            &item_impl.self_ty,
+            "The discriminator `fn type_name(&self) -> &str` must be defined in this impl block.",
+        )
+        .into());
+    }
+
+    if interface_args.fields.is_empty() {
+        return Err(Error::new_spanned(
+            &item_impl.self_ty,
+            "A GraphQL Interface type must define one or more fields.",
+        )
+        .into());
+    }
+
+    let mut schema_fields = Vec::new();
+    for field in &interface_args.fields {
+        schema_fields.push(generate_schema_field(
+            &crate_name,
+            &gql_typename,
+            &interface_args.rename_fields,
+            &interface_args.rename_args,
+            field,
+        )?);
+    }
+
+    let mut type_asserts = Vec::new();
+    let mut registry_types = Vec::new();
+    let mut possible_types = Vec::new();
+    for ty in &interface_args.possible_types {
+        let ty = match syn::parse_str::<syn::Type>(&ty.value()) {
+            Ok(ty) => ty,
+            Err(_) => return Err(Error::new_spanned(&ty, "Expect type").into()),
+        };
+        type_asserts.push(quote! {
+            #crate_name::static_assertions::assert_impl_all!(#ty: #crate_name::ObjectType);
+        });
+        registry_types.push(quote! {
+            <#ty as #crate_name::Type>::create_type_info(registry);
+            registry.add_implements(&<#ty as #crate_name::Type>::type_name(), #gql_typename);
+        });
+        possible_types.push(quote! {
+            possible_types.insert(<#ty as #crate_name::Type>::type_name().into_owned());
+        });
+    }
+
+    let visible = visible_fn(&interface_args.visible);
+    let expanded = quote! {
+        #item_impl
+
+        #(#type_asserts)*
+
+        #[allow(clippy::all, clippy::pedantic)]
+        impl #impl_generics #crate_name::Type for #self_ty #where_clause {
+            fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
+                ::std::borrow::Cow::Borrowed(#gql_typename)
+            }
+
+            fn introspection_type_name(&self) -> ::std::borrow::Cow<'static, ::std::primitive::str> {
+                ::std::borrow::Cow::Owned(::std::string::ToString::to_string(<#self_ty>::type_name(self)))
+            }
+
+            fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
+                registry.create_type::<Self, _>(|registry| {
+                    #(#registry_types)*
+
+                    #crate_name::registry::MetaType::Interface {
+                        name: ::std::string::ToString::to_string(#gql_typename),
+                        description: #desc,
+                        fields: {
+                            let mut fields = #crate_name::indexmap::IndexMap::new();
+                            #(#schema_fields)*
+                            fields
+                        },
+                        possible_types: {
+                            let mut possible_types = #crate_name::indexmap::IndexSet::new();
+                            #(#possible_types)*
+                            possible_types
+                        },
+                        extends: #extends,
+                        keys: ::std::option::Option::None,
+                        visible: #visible,
+                    }
+                })
+            }
+        }
+
+        #[allow(clippy::all, clippy::pedantic)]
+        #[#crate_name::async_trait::async_trait]
+        impl #impl_generics #crate_name::OutputType for #self_ty #where_clause {
+            async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                #crate_name::resolver_utils::resolve_container(ctx, self).await
+            }
+        }
+
+        impl #impl_generics #crate_name::InterfaceType for #self_ty #where_clause {}
+    };
+    Ok(expanded.into())
+}
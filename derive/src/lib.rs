@@ -4,14 +4,17 @@
 extern crate proc_macro;
 
 mod args;
+mod complex_object;
 mod description;
 mod r#enum;
 mod input_object;
 mod interface;
+mod interface_impl;
 mod merged_object;
 mod merged_subscription;
 mod newtype;
 mod object;
+mod object_field;
 mod output_type;
 mod scalar;
 mod simple_object;
@@ -51,6 +54,21 @@ pub fn derive_simple_object(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ComplexObject(args: TokenStream, input: TokenStream) -> TokenStream {
+    let object_args =
+        match args::ComplexObject::from_list(&parse_macro_input!(args as AttributeArgs)) {
+            Ok(object_args) => object_args,
+            Err(err) => return TokenStream::from(err.write_errors()),
+        };
+    let mut item_impl = parse_macro_input!(input as ItemImpl);
+    match complex_object::generate(&object_args, &mut item_impl) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(Enum, attributes(graphql))]
 pub fn derive_enum(input: TokenStream) -> TokenStream {
     let enum_args = match args::Enum::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
@@ -89,6 +107,21 @@ pub fn derive_interface(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn InterfaceImpl(args: TokenStream, input: TokenStream) -> TokenStream {
+    let interface_args =
+        match args::InterfaceImpl::from_list(&parse_macro_input!(args as AttributeArgs)) {
+            Ok(interface_args) => interface_args,
+            Err(err) => return TokenStream::from(err.write_errors()),
+        };
+    let mut item_impl = parse_macro_input!(input as ItemImpl);
+    match interface_impl::generate(&interface_args, &mut item_impl) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
+
 #[proc_macro_derive(Union, attributes(graphql))]
 pub fn derive_union(input: TokenStream) -> TokenStream {
     let union_args = match args::Union::from_derive_input(&parse_macro_input!(input as DeriveInput))
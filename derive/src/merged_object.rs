@@ -5,7 +5,9 @@ use quote::quote;
 use syn::{Error, LitInt};
 
 use crate::args::{self, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, visible_fn, GeneratorResult};
+use crate::utils::{
+    check_user_defined_name, get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
+};
 
 pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
@@ -16,6 +18,9 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !object_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&object_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
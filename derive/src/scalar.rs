@@ -1,10 +1,11 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::ItemImpl;
+use syn::{Error, ItemImpl};
 
 use crate::args::{self, RenameTarget};
 use crate::utils::{
-    get_crate_name, get_rustdoc, get_type_path_and_name, visible_fn, GeneratorResult,
+    check_user_defined_name, get_crate_name, get_rustdoc, get_type_path_and_name, visible_fn,
+    GeneratorResult,
 };
 
 pub fn generate(
@@ -17,6 +18,9 @@ pub fn generate(
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(self_name.clone()));
+    if !scalar_args.internal {
+        check_user_defined_name(&gql_typename, &item_impl.self_ty)?;
+    }
 
     let desc = if scalar_args.use_type_description {
         quote! { ::std::option::Option::Some(<Self as #crate_name::Description>::description()) }
@@ -30,6 +34,56 @@ pub fn generate(
     let generic = &item_impl.generics;
     let where_clause = &item_impl.generics.where_clause;
     let visible = visible_fn(&scalar_args.visible);
+    let specified_by_url = match &scalar_args.specified_by_url {
+        Some(url) => quote! { ::std::option::Option::Some(#url) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let (is_valid, check_kind) = match &scalar_args.accept {
+        Some(kinds) => {
+            let mut checks = Vec::new();
+            for kind in &kinds.0 {
+                let check = match kind.get_ident().map(ToString::to_string).as_deref() {
+                    Some("String") => quote!(#crate_name::Value::String(_)),
+                    Some("Int") => quote!(#crate_name::Value::Number(n) if n.is_i64()),
+                    Some("Float") => quote!(#crate_name::Value::Number(_)),
+                    Some("Boolean") => quote!(#crate_name::Value::Boolean(_)),
+                    Some("Enum") => quote!(#crate_name::Value::Enum(_)),
+                    Some("List") => quote!(#crate_name::Value::List(_)),
+                    Some("Object") => quote!(#crate_name::Value::Object(_)),
+                    _ => {
+                        return Err(Error::new_spanned(
+                            kind,
+                            "Expected one of String, Int, Float, Boolean, Enum, List or Object",
+                        )
+                        .into())
+                    }
+                };
+                checks.push(quote!(::std::matches!(value, #check)));
+            }
+            let accepted = quote!(|value: &#crate_name::Value| false #(|| #checks)*);
+            (
+                quote! {
+                    |value| {
+                        let accepted = #accepted;
+                        accepted(value) && <#self_ty as #crate_name::ScalarType>::is_valid(value)
+                    }
+                },
+                quote! {
+                    let value = value.unwrap_or_default();
+                    let accepted = #accepted;
+                    if !accepted(&value) {
+                        return ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value));
+                    }
+                    <#self_ty as #crate_name::ScalarType>::parse(value)
+                },
+            )
+        }
+        None => (
+            quote!(|value| <#self_ty as #crate_name::ScalarType>::is_valid(value)),
+            quote!(<#self_ty as #crate_name::ScalarType>::parse(value.unwrap_or_default())),
+        ),
+    };
     let expanded = quote! {
         #item_impl
 
@@ -43,8 +97,9 @@ pub fn generate(
                 registry.create_type::<#self_ty, _>(|_| #crate_name::registry::MetaType::Scalar {
                     name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                     description: #desc,
-                    is_valid: |value| <#self_ty as #crate_name::ScalarType>::is_valid(value),
+                    is_valid: #is_valid,
                     visible: #visible,
+                    specified_by_url: #specified_by_url,
                 })
             }
         }
@@ -52,7 +107,7 @@ pub fn generate(
         #[allow(clippy::all, clippy::pedantic)]
         impl #generic #crate_name::InputType for #self_ty #where_clause {
             fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
-                <#self_ty as #crate_name::ScalarType>::parse(value.unwrap_or_default())
+                #check_kind
             }
 
             fn to_value(&self) -> #crate_name::Value {
@@ -1,11 +1,16 @@
+use std::collections::HashSet;
+
 use darling::ast::Data;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::ext::IdentExt;
 use syn::Error;
 
-use crate::args::{self, RenameRuleExt, RenameTarget};
-use crate::utils::{generate_guards, get_crate_name, get_rustdoc, visible_fn, GeneratorResult};
+use crate::args::{self, ComplexityType, RenameRuleExt, RenameTarget};
+use crate::utils::{
+    check_user_defined_name, generate_guards, get_crate_name, get_rustdoc, parse_complexity_expr,
+    visible_fn, GeneratorResult,
+};
 
 pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
@@ -16,6 +21,9 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !object_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&object_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
@@ -34,6 +42,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
     let mut getters = Vec::new();
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut field_names = HashSet::new();
+    let mut has_flatten = false;
 
     for field in &s.fields {
         if field.skip {
@@ -44,11 +54,47 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             None => return Err(Error::new_spanned(&ident, "All fields must be named.").into()),
         };
 
+        if field.flatten {
+            let ty = &field.ty;
+            has_flatten = true;
+            // The fields of the flattened type are only known when the schema is built.
+            schema_fields.push(quote! {
+                #crate_name::static_assertions::assert_impl_one!(#ty: #crate_name::ObjectType);
+                if let #crate_name::registry::MetaType::Object { fields: flatten_fields, .. } =
+                    registry.create_dummy_type::<#ty>() {
+                    for (name, field) in flatten_fields {
+                        if fields.contains_key(&name) {
+                            ::std::panic!(
+                                "Field \"{}\" of the flattened type \"{}\" is already defined.",
+                                name,
+                                <#ty as #crate_name::Type>::type_name(),
+                            );
+                        }
+                        fields.insert(name, field);
+                    }
+                }
+            });
+            resolvers.push(quote! {
+                if let ::std::option::Option::Some(value) = #crate_name::resolver_utils::ContainerType::resolve_field(&self.#ident, ctx).await? {
+                    return ::std::result::Result::Ok(::std::option::Option::Some(value));
+                }
+            });
+            continue;
+        }
+
         let field_name = field.name.clone().unwrap_or_else(|| {
             object_args
                 .rename_fields
                 .rename(ident.unraw().to_string(), RenameTarget::Field)
         });
+        check_user_defined_name(&field_name, ident)?;
+        if !field_names.insert(field_name.clone()) {
+            return Err(Error::new_spanned(
+                ident,
+                format!("Field \"{}\" is already defined.", field_name),
+            )
+            .into());
+        }
         let field_desc = get_rustdoc(&field.attrs)?
             .map(|s| quote! {::std::option::Option::Some(#s)})
             .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -82,7 +128,45 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
 
         let visible = visible_fn(&field.visible);
 
+        let complexity = match &field.complexity {
+            Some(ComplexityType::Const(n)) => {
+                quote! { ::std::option::Option::Some(#crate_name::registry::ComplexityType::Const(#n)) }
+            }
+            Some(ComplexityType::Fn(s)) => {
+                let (variables, expr) = parse_complexity_expr(s)?;
+                if let Some(variable) = variables.into_iter().next() {
+                    return Err(Error::new_spanned(
+                        &ty,
+                        format!(
+                            "The complexity of a SimpleObject field can only use `child_complexity`, but `{}` was used.",
+                            variable
+                        ),
+                    )
+                    .into());
+                }
+                quote! {
+                    ::std::option::Option::Some(#crate_name::registry::ComplexityType::Fn(|__ctx, __variables_definition, __field, child_complexity| {
+                        ::std::result::Result::Ok(#expr)
+                    }))
+                }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+
+        let duplicate_check = if has_flatten {
+            quote! {
+                if fields.contains_key(#field_name) {
+                    ::std::panic!(
+                        "Field \"{}\" is already defined by a flattened type.",
+                        #field_name,
+                    );
+                }
+            }
+        } else {
+            quote! {}
+        };
         schema_fields.push(quote! {
+            #duplicate_check
             fields.insert(::std::borrow::ToOwned::to_owned(#field_name), #crate_name::registry::MetaField {
                 name: ::std::borrow::ToOwned::to_owned(#field_name),
                 description: #field_desc,
@@ -94,7 +178,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 provides: #provides,
                 requires: #requires,
                 visible: #visible,
-                compute_complexity: ::std::option::Option::None,
+                compute_complexity: #complexity,
             });
         });
 
@@ -132,7 +216,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         });
     }
 
-    if !object_args.dummy && resolvers.is_empty() {
+    if !object_args.dummy && !object_args.complex && resolvers.is_empty() {
         return Err(Error::new_spanned(
             &ident,
             "A GraphQL Object type must define one or more fields.",
@@ -153,6 +237,31 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
 
     let visible = visible_fn(&object_args.visible);
 
+    let (concat_complex_fields, complex_resolver, complex_bound) = if object_args.complex {
+        (
+            quote! {
+                // The fields of the `ComplexObject` are only known when the schema is built.
+                for (name, field) in <Self as #crate_name::ComplexObject>::fields(registry) {
+                    if fields.contains_key(&name) {
+                        ::std::panic!(
+                            "Field \"{}\" of the ComplexObject is already defined.",
+                            name,
+                        );
+                    }
+                    fields.insert(name, field);
+                }
+            },
+            quote! {
+                if let ::std::option::Option::Some(value) = <Self as #crate_name::ComplexObject>::resolve_field(self, ctx).await? {
+                    return ::std::result::Result::Ok(::std::option::Option::Some(value));
+                }
+            },
+            quote! { + #crate_name::ComplexObject },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
     let expanded = if object_args.concretes.is_empty() {
         quote! {
             #[allow(clippy::all, clippy::pedantic)]
@@ -173,6 +282,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                         fields: {
                             let mut fields = #crate_name::indexmap::IndexMap::new();
                             #(#schema_fields)*
+                            #concat_complex_fields
                             fields
                         },
                         cache_control: #cache_control,
@@ -188,6 +298,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
 
             impl #impl_generics #crate_name::resolver_utils::ContainerType for #ident #ty_generics #where_clause {
                 async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                    #complex_resolver
                     #(#resolvers)*
                     ::std::result::Result::Ok(::std::option::Option::None)
                 }
@@ -210,13 +321,14 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             impl #impl_generics #ident #ty_generics #where_clause {
                 #(#getters)*
 
-                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> ::std::string::String where Self: #crate_name::OutputType {
+                fn __internal_create_type_info(registry: &mut #crate_name::registry::Registry, name: &str) -> ::std::string::String where Self: #crate_name::OutputType #complex_bound {
                     registry.create_type::<Self, _>(|registry| #crate_name::registry::MetaType::Object {
                         name: ::std::borrow::ToOwned::to_owned(name),
                         description: #desc,
                         fields: {
                             let mut fields = #crate_name::indexmap::IndexMap::new();
                             #(#schema_fields)*
+                            #concat_complex_fields
                             fields
                         },
                         cache_control: #cache_control,
@@ -226,7 +338,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     })
                 }
 
-                async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> where Self: #crate_name::ContainerType {
+                async fn __internal_resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> where Self: #crate_name::ContainerType #complex_bound {
+                    #complex_resolver
                     #(#resolvers)*
                     ::std::result::Result::Ok(::std::option::Option::None)
                 }
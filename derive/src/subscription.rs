@@ -9,9 +9,10 @@ use syn::{
 use crate::args::{self, ComplexityType, RenameRuleExt, RenameTarget, SubscriptionField};
 use crate::output_type::OutputType;
 use crate::utils::{
-    generate_default, generate_guards, generate_validator, get_cfg_attrs, get_crate_name,
-    get_param_getter_ident, get_rustdoc, get_type_path_and_name, parse_complexity_expr,
-    parse_graphql_attrs, remove_graphql_attrs, visible_fn, GeneratorResult,
+    check_user_defined_name, generate_async_validator_check, generate_default,
+    generate_default_value_check, generate_guards, generate_validator, get_cfg_attrs,
+    get_crate_name, get_param_getter_ident, get_rustdoc, get_type_path_and_name,
+    parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs, visible_fn, GeneratorResult,
 };
 
 pub fn generate(
@@ -25,6 +26,9 @@ pub fn generate(
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(self_name.clone()));
+    if !subscription_args.internal {
+        check_user_defined_name(&gql_typename, &item_impl.self_ty)?;
+    }
 
     let desc = if subscription_args.use_type_description {
         quote! { ::std::option::Option::Some(<Self as #crate_name::Description>::description()) }
@@ -51,6 +55,7 @@ pub fn generate(
                     .rename_fields
                     .rename(method.sig.ident.unraw().to_string(), RenameTarget::Field)
             });
+            check_user_defined_name(&field_name, &method.sig.ident)?;
             let field_desc = get_rustdoc(&method.attrs)?
                 .map(|s| quote! {::std::option::Option::Some(#s)})
                 .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -148,7 +153,9 @@ pub fn generate(
                     default,
                     default_with,
                     validator,
+                    validator_async,
                     visible: arg_visible,
+                    secret,
                 },
             ) in &args
             {
@@ -157,11 +164,22 @@ pub fn generate(
                         .rename_args
                         .rename(ident.ident.unraw().to_string(), RenameTarget::Argument)
                 });
+                check_user_defined_name(&name, &ident.ident)?;
                 let desc = desc
                     .as_ref()
                     .map(|s| quote! {::std::option::Option::Some(#s)})
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
                 let default = generate_default(&default, &default_with)?;
+                let default_value_check = generate_default_value_check(
+                    &crate_name,
+                    ty,
+                    &default,
+                    &validator,
+                    &format!(
+                        r#"argument "{}" of field "{}.{}""#,
+                        name, gql_typename, field_name
+                    ),
+                )?;
 
                 let validator = match &validator {
                     Some(meta) => {
@@ -184,6 +202,7 @@ pub fn generate(
 
                 let visible = visible_fn(&arg_visible);
                 schema_args.push(quote! {
+                    #default_value_check
                     args.insert(#name, #crate_name::registry::MetaInputValue {
                         name: #name,
                         description: #desc,
@@ -191,6 +210,8 @@ pub fn generate(
                         default_value: #schema_default,
                         validator: #validator,
                         visible: #visible,
+                        aliases: &[],
+                        is_secret: #secret,
                     });
                 });
 
@@ -201,11 +222,14 @@ pub fn generate(
                     None => quote! { ::std::option::Option::None },
                 };
                 let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
+                let validator_async_check =
+                    generate_async_validator_check(&crate_name, &ident.ident, validator_async)?;
                 get_params.push(quote! {
                     #[allow(non_snake_case)]
                     let #param_getter_name = || -> #crate_name::ServerResult<#ty> { ctx.param_value(#name, #default) };
                     #[allow(non_snake_case)]
                     let #ident: #ty = ctx.param_value(#name, #default)?;
+                    #validator_async_check
                 });
             }
 
@@ -313,7 +337,8 @@ pub fn generate(
                 self.#ident(ctx, #(#use_params),*)
                     .await
                     .map_err(|err| {
-                        err.into_server_error().at(ctx.item.pos)
+                        use #crate_name::ErrorExtensions as _;
+                        (&err).extend().into_server_error().at(ctx.item.pos)
                     })?
             };
 
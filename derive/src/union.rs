@@ -7,7 +7,9 @@ use syn::visit_mut::VisitMut;
 use syn::{visit_mut, Error, Lifetime, Type};
 
 use crate::args::{self, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, visible_fn, GeneratorResult};
+use crate::utils::{
+    check_user_defined_name, get_crate_name, get_rustdoc, visible_fn, GeneratorResult,
+};
 
 pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(union_args.internal);
@@ -26,6 +28,9 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    if !union_args.internal {
+        check_user_defined_name(&gql_typename, ident)?;
+    }
 
     let desc = get_rustdoc(&union_args.attrs)?
         .map(|s| quote! { ::std::option::Option::Some(#s) })
@@ -125,6 +125,121 @@ pub fn generate_validator(crate_name: &TokenStream, args: &Meta) -> GeneratorRes
     }
 }
 
+pub fn generate_async_validator(
+    crate_name: &TokenStream,
+    args: &Meta,
+) -> GeneratorResult<TokenStream> {
+    match args {
+        Meta::List(args) => {
+            if args.nested.len() != 1 {
+                return Err(Error::new_spanned(
+                    args,
+                    "Exactly one async validator must be defined",
+                )
+                .into());
+            }
+            match &args.nested[0] {
+                NestedMeta::Meta(Meta::List(ls))
+                    if ls.path.is_ident("and") || ls.path.is_ident("or") =>
+                {
+                    Err(Error::new_spanned(
+                        ls,
+                        "Async validators cannot be combined with `and` or `or`",
+                    )
+                    .into())
+                }
+                nested_meta => generate_nested_validator(crate_name, nested_meta),
+            }
+        }
+        _ => Err(Error::new_spanned(args, "Invalid validator").into()),
+    }
+}
+
+/// Generates the code that runs the async validator of a parsed argument.
+pub fn generate_async_validator_check(
+    crate_name: &TokenStream,
+    ident: &impl quote::ToTokens,
+    validator_async: &Option<Meta>,
+) -> GeneratorResult<TokenStream> {
+    match validator_async {
+        Some(meta) => {
+            let validator = generate_async_validator(crate_name, meta)?;
+            Ok(quote! {
+                {
+                    let value = #crate_name::InputType::to_value(&#ident);
+                    if value != #crate_name::Value::Null {
+                        #crate_name::validators::AsyncInputValueValidator::validate(
+                            &#validator,
+                            ctx,
+                            &value,
+                        )
+                        .await
+                        .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+                    }
+                }
+            })
+        }
+        None => Ok(quote! {}),
+    }
+}
+
+/// Generates a check that panics when registering the type if the default value does not pass
+/// the validator.
+pub fn generate_default_value_check(
+    crate_name: &TokenStream,
+    ty: &Type,
+    default: &Option<TokenStream>,
+    validator: &Option<Meta>,
+    target: &str,
+) -> GeneratorResult<TokenStream> {
+    let default = match default {
+        Some(default) => default,
+        None => return Ok(quote! {}),
+    };
+
+    // The schema advertises `to_value(default)`, so in debug builds make sure parsing it back
+    // gives the same value the resolver would use when the argument is absent.
+    let consistency_check = quote! {
+        if ::std::cfg!(debug_assertions) {
+            let schema_default = <#ty as #crate_name::InputType>::to_value(&#default);
+            let applied_default = <#ty as #crate_name::InputType>::parse(
+                ::std::option::Option::Some(::std::clone::Clone::clone(&schema_default)),
+            )
+            .map(|value| <#ty as #crate_name::InputType>::to_value(&value));
+            if applied_default.as_ref().ok() != ::std::option::Option::Some(&schema_default) {
+                ::std::panic!(
+                    "The default value for {} is advertised as {} but does not parse back to the same value",
+                    #target,
+                    schema_default,
+                );
+            }
+        }
+    };
+
+    let validator_check = match validator {
+        Some(validator) => {
+            let validator = generate_validator(crate_name, validator)?;
+            quote! {
+                let default_value = <#ty as #crate_name::InputType>::to_value(&#default);
+                if default_value != #crate_name::Value::Null {
+                    if let ::std::result::Result::Err(reason) = #crate_name::validators::InputValueValidator::is_valid(
+                        &*#validator,
+                        &default_value,
+                    ) {
+                        ::std::panic!("Invalid default value for {}: {}", #target, reason);
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    Ok(quote! {
+        #consistency_check
+        #validator_check
+    })
+}
+
 pub fn generate_guards(
     crate_name: &TokenStream,
     args: &Meta,
@@ -277,6 +392,20 @@ pub fn generate_guards(
     }
 }
 
+pub fn check_user_defined_name(name: &str, span: impl quote::ToTokens) -> GeneratorResult<()> {
+    if !async_graphql_value::Name::is_valid_user_defined(name) {
+        return Err(Error::new_spanned(
+            span,
+            format!(
+                r#""{}" is not a valid name, names must match /[_A-Za-z][_0-9A-Za-z]*/ and must not begin with "__"."#,
+                name
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 pub fn get_rustdoc(attrs: &[Attribute]) -> GeneratorResult<Option<String>> {
     let mut full_docs = String::new();
     for attr in attrs {
@@ -440,3 +569,20 @@ pub fn parse_complexity_expr(s: &str) -> GeneratorResult<(HashSet<String>, Expr)
     visit.visit_expr(&expr);
     Ok((visit.variables, expr))
 }
+
+/// Generates the expression of the parent cache key of a cached field, an `Option<String>`.
+pub fn generate_cache_key(
+    cache_key: &Option<String>,
+    span: &impl quote::ToTokens,
+) -> GeneratorResult<TokenStream> {
+    match cache_key {
+        Some(cache_key) => {
+            let expr = syn::parse_str::<Expr>(cache_key)
+                .map_err(|_| Error::new_spanned(span, "Invalid cache_key expression."))?;
+            Ok(quote! {
+                ::std::option::Option::Some(::std::string::ToString::to_string(&(#expr)))
+            })
+        }
+        None => Ok(quote! { ::std::option::Option::None }),
+    }
+}
@@ -16,7 +16,7 @@ use actix_web::{http, Error, FromRequest, HttpRequest, HttpResponse, Responder,
 use futures_util::future::{self, FutureExt, Ready};
 use futures_util::{StreamExt, TryStreamExt};
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{GetRequest, MultipartOptions};
 use async_graphql::ParseRequestError;
 
 /// Extractor for GraphQL request.
@@ -73,8 +73,13 @@ impl FromRequest for BatchRequest {
         let config = req.app_data::<Self::Config>().cloned().unwrap_or_default();
 
         if req.method() == Method::GET {
-            let res = serde_urlencoded::from_str(req.query_string());
-            Box::pin(async move { Ok(Self(async_graphql::BatchRequest::Single(res?))) })
+            let res = serde_urlencoded::from_str::<GetRequest>(req.query_string());
+            Box::pin(async move {
+                let request = res?
+                    .into_request()
+                    .map_err(actix_web::error::ErrorBadRequest)?;
+                Ok(Self(async_graphql::BatchRequest::Single(request)))
+            })
         } else if req.method() == Method::POST {
             let content_type = req
                 .headers()
@@ -162,7 +167,12 @@ impl Responder for Response {
     type Future = Ready<Result<HttpResponse>>;
 
     fn respond_to(self, _req: &HttpRequest) -> Self::Future {
-        let mut res = HttpResponse::build(StatusCode::OK);
+        let status = self
+            .0
+            .http_status()
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(StatusCode::OK);
+        let mut res = HttpResponse::build(status);
         res.content_type("application/json");
         if self.0.is_ok() {
             if let Some(cache_control) = self.0.cache_control().value() {
@@ -13,7 +13,7 @@
 
 use std::io::Cursor;
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{GetRequest, MultipartOptions};
 use async_graphql::{ObjectType, ParseRequestError, Schema, SubscriptionType};
 use query_deserializer::QueryDeserializer;
 use rocket::{
@@ -124,9 +124,10 @@ impl<'q> FromQuery<'q> for Request {
     type Error = serde::de::value::Error;
 
     fn from_query(query: request::Query<'_>) -> Result<Self, Self::Error> {
-        Ok(Self(async_graphql::Request::deserialize(
-            QueryDeserializer(query),
-        )?))
+        GetRequest::deserialize(QueryDeserializer(query))?
+            .into_request()
+            .map(Self)
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -169,6 +170,9 @@ impl<'r> Responder<'r, 'static> for Response {
 
         let mut response = rocket::Response::new();
         response.set_header(ContentType::new("application", "json"));
+        if let Some(status) = self.0.http_status().and_then(Status::from_code) {
+            response.set_status(status);
+        }
 
         if self.0.is_ok() {
             if let Some(cache_control) = self.0.cache_control().value() {
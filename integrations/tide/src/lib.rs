@@ -13,7 +13,9 @@
 
 mod subscription;
 
-use async_graphql::http::MultipartOptions;
+use std::convert::TryFrom;
+
+use async_graphql::http::{GetRequest, MultipartOptions};
 use async_graphql::{ObjectType, ParseRequestError, Schema, SubscriptionType};
 use tide::utils::async_trait;
 use tide::{
@@ -131,7 +133,11 @@ pub async fn receive_batch_request_opts<State: Clone + Send + Sync + 'static>(
     opts: MultipartOptions,
 ) -> tide::Result<async_graphql::BatchRequest> {
     if request.method() == Method::Get {
-        request.query::<async_graphql::Request>().map(Into::into)
+        request
+            .query::<GetRequest>()?
+            .into_request()
+            .map(Into::into)
+            .map_err(|e| tide::Error::new(StatusCode::BadRequest, e))
     } else if request.method() == Method::Post {
         let body = request.take_body();
         let content_type = request
@@ -163,6 +169,12 @@ pub fn respond(resp: impl Into<async_graphql::BatchResponse>) -> tide::Result {
     let resp = resp.into();
 
     let mut response = Response::new(StatusCode::Ok);
+    if let Some(status) = resp
+        .http_status()
+        .and_then(|status| StatusCode::try_from(status).ok())
+    {
+        response.set_status(status);
+    }
     if resp.is_ok() {
         if let Some(cache_control) = resp.cache_control().value() {
             response.insert_header(headers::CACHE_CONTROL, cache_control);
@@ -2,9 +2,10 @@ use std::convert::TryInto;
 use std::io;
 use std::io::ErrorKind;
 
-use async_graphql::http::MultipartOptions;
+use async_graphql::http::{GetRequest, MultipartOptions};
 use async_graphql::{BatchRequest, ObjectType, Schema, SubscriptionType};
 use futures_util::TryStreamExt;
+use warp::http::StatusCode;
 use warp::hyper::header::HeaderName;
 use warp::reply::Response as WarpResponse;
 use warp::{Buf, Filter, Rejection, Reply};
@@ -38,7 +39,16 @@ where
     Subscription: SubscriptionType + 'static,
 {
     warp::any()
-        .and(warp::get().and(warp::query()).map(BatchRequest::Single))
+        .and(
+            warp::get()
+                .and(warp::query())
+                .and_then(|request: GetRequest| async move {
+                    request
+                        .into_request()
+                        .map(BatchRequest::Single)
+                        .map_err(|e| warp::reject::custom(BadRequest(e)))
+                }),
+        )
         .or(warp::post()
             .and(warp::header::optional::<String>("content-type"))
             .and(warp::body::stream())
@@ -79,6 +89,13 @@ impl Reply for BatchResponse {
         )
         .into_response();
 
+        if let Some(status) = self
+            .0
+            .http_status()
+            .and_then(|status| StatusCode::from_u16(status).ok())
+        {
+            *resp.status_mut() = status;
+        }
         if self.0.is_ok() {
             if let Some(cache_control) = self.0.cache_control().value() {
                 if let Ok(value) = cache_control.try_into() {
@@ -432,4 +432,22 @@ mod tests {
         assert!(parse_query(query_ok).is_ok());
         assert!(parse_query(query_overflow).is_ok());
     }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_parse_big_int() {
+        let doc = parse_query("{ user(id: 12345678901234567890123) }").unwrap();
+        let operation = match doc.operations {
+            DocumentOperations::Single(operation) => operation,
+            DocumentOperations::Multiple(_) => unreachable!(),
+        };
+        let field = match &operation.node.selection_set.node.items[0].node {
+            Selection::Field(field) => field,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            field.node.arguments[0].1.node.to_string(),
+            "12345678901234567890123"
+        );
+    }
 }
@@ -55,23 +55,159 @@ impl Type {
     /// Create a type from the type string.
     #[must_use]
     pub fn new(ty: &str) -> Option<Self> {
-        let (nullable, ty) = if let Some(rest) = ty.strip_suffix('!') {
-            (false, rest)
+        Self::parse(ty).ok()
+    }
+
+    /// Parse a type from the type string, reporting where and why it is invalid.
+    ///
+    /// Whitespace and commas between the tokens are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string is not a valid GraphQL type.
+    pub fn parse(ty: &str) -> Result<Self, TypeParseError> {
+        let mut parser = TypeParser {
+            input: ty,
+            offset: 0,
+        };
+        let parsed = parser.parse_type()?;
+        match parser.peek() {
+            Some(ch) => Err(TypeParseError::UnexpectedChar {
+                offset: parser.offset,
+                ch,
+            }),
+            None => Ok(parsed),
+        }
+    }
+}
+
+struct TypeParser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    /// Skips the ignored tokens, then returns the next character without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.input[self.offset..];
+        let trimmed = rest.trim_start_matches(|c| matches!(c, ' ' | '\t' | '\n' | '\r' | ','));
+        self.offset += rest.len() - trimmed.len();
+        trimmed.chars().next()
+    }
+
+    fn parse_type(&mut self) -> Result<Type, TypeParseError> {
+        let base = if self.peek() == Some('[') {
+            let bracket = self.offset;
+            self.offset += 1;
+            let ty = self.parse_type()?;
+            if self.peek() != Some(']') {
+                return Err(TypeParseError::UnclosedBracket { offset: bracket });
+            }
+            self.offset += 1;
+            BaseType::List(Box::new(ty))
         } else {
-            (true, ty)
+            BaseType::Named(self.parse_name()?)
         };
 
-        Some(Self {
-            base: if let Some(ty) = ty.strip_prefix('[') {
-                BaseType::List(Box::new(Self::new(ty.strip_suffix(']')?)?))
-            } else {
-                BaseType::Named(Name::new(ty))
-            },
-            nullable,
-        })
+        let nullable = if self.peek() == Some('!') {
+            self.offset += 1;
+            false
+        } else {
+            true
+        };
+        Ok(Type { base, nullable })
+    }
+
+    fn parse_name(&mut self) -> Result<Name, TypeParseError> {
+        let next = self.peek();
+        let start = self.offset;
+        match next {
+            None | Some('!') | Some(']') => {
+                return Err(TypeParseError::EmptyName { offset: start })
+            }
+            Some(ch) if !(ch == '_' || ch.is_ascii_alphabetic()) => {
+                return Err(TypeParseError::InvalidNameChar { offset: start, ch })
+            }
+            Some(_) => {}
+        }
+
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !(c == '_' || c.is_ascii_alphanumeric()))
+            .unwrap_or(rest.len());
+        self.offset += len;
+        match rest[len..].chars().next() {
+            Some(ch) if !matches!(ch, '!' | ']' | ' ' | '\t' | '\n' | '\r' | ',') => {
+                Err(TypeParseError::InvalidNameChar {
+                    offset: self.offset,
+                    ch,
+                })
+            }
+            _ => Ok(Name::new(&rest[..len])),
+        }
     }
 }
 
+/// An error parsing a type with [`Type::parse`](struct.Type.html#method.parse).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TypeParseError {
+    /// A list type was not closed with `]`.
+    UnclosedBracket {
+        /// The byte offset of the `[` that was not closed.
+        offset: usize,
+    },
+    /// A type name was expected, but none was found.
+    EmptyName {
+        /// The byte offset where the name was expected.
+        offset: usize,
+    },
+    /// A type name contains a character that is not allowed in names.
+    InvalidNameChar {
+        /// The byte offset of the character.
+        offset: usize,
+        /// The invalid character.
+        ch: char,
+    },
+    /// There is a character after the end of the type.
+    UnexpectedChar {
+        /// The byte offset of the character.
+        offset: usize,
+        /// The unexpected character.
+        ch: char,
+    },
+}
+
+impl TypeParseError {
+    /// The byte offset in the type string where the error occurred.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::UnclosedBracket { offset }
+            | Self::EmptyName { offset }
+            | Self::InvalidNameChar { offset, .. }
+            | Self::UnexpectedChar { offset, .. } => *offset,
+        }
+    }
+}
+
+impl Display for TypeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedBracket { offset } => write!(f, "unclosed bracket at {}", offset),
+            Self::EmptyName { offset } => write!(f, "expected a type name at {}", offset),
+            Self::InvalidNameChar { offset, ch } => {
+                write!(f, "invalid character {:?} in type name at {}", ch, offset)
+            }
+            Self::UnexpectedChar { offset, ch } => {
+                write!(f, "unexpected character {:?} at {}", ch, offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TypeParseError {}
+
 impl Display for Type {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         self.base.fmt(f)?;
@@ -174,3 +310,56 @@ impl Directive {
             .map(|item| &item.1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_type() {
+        assert_eq!(
+            Type::parse("[String!]!"),
+            Ok(Type {
+                base: BaseType::List(Box::new(Type {
+                    base: BaseType::Named(Name::new("String")),
+                    nullable: false,
+                })),
+                nullable: false,
+            })
+        );
+        assert_eq!(Type::parse("[ String! ] !"), Type::parse("[String!]!"));
+        assert_eq!(Type::parse("[String!]!").unwrap().to_string(), "[String!]!");
+        assert_eq!(Type::new("[[Int]!]"), Type::parse("[[Int]!]").ok());
+
+        assert_eq!(
+            Type::parse("[String!"),
+            Err(TypeParseError::UnclosedBracket { offset: 0 })
+        );
+        assert_eq!(
+            Type::parse("[[String]"),
+            Err(TypeParseError::UnclosedBracket { offset: 0 })
+        );
+        assert_eq!(
+            Type::parse("[]"),
+            Err(TypeParseError::EmptyName { offset: 1 })
+        );
+        assert_eq!(
+            Type::parse(""),
+            Err(TypeParseError::EmptyName { offset: 0 })
+        );
+        assert_eq!(
+            Type::parse("[1Int]"),
+            Err(TypeParseError::InvalidNameChar { offset: 1, ch: '1' })
+        );
+        assert_eq!(
+            Type::parse("Str-ing"),
+            Err(TypeParseError::InvalidNameChar { offset: 3, ch: '-' })
+        );
+        assert_eq!(
+            Type::parse("String]"),
+            Err(TypeParseError::UnexpectedChar { offset: 6, ch: ']' })
+        );
+        assert_eq!(Type::parse("[String!").unwrap_err().offset(), 0);
+        assert_eq!(Type::new("[String!"), None);
+    }
+}
@@ -6,8 +6,8 @@ use async_graphql_value::ConstValue;
 use crate::parser::types::Field;
 use crate::registry::Registry;
 use crate::{
-    registry, ContainerType, ContextSelectionSet, InputValueError, InputValueResult, Positioned,
-    Result, ServerResult, Value,
+    registry, ContainerType, Context, ContextSelectionSet, InputValueError, InputValueResult,
+    Positioned, Result, ServerResult, Value,
 };
 
 #[doc(hidden)]
@@ -120,6 +120,18 @@ pub trait ObjectType: ContainerType {}
 #[async_trait::async_trait]
 impl<T: ObjectType> ObjectType for &T {}
 
+/// Additional fields of a `SimpleObject` with `#[graphql(complex)]`, implemented by the
+/// `ComplexObject` macro.
+#[doc(hidden)]
+#[async_trait::async_trait]
+pub trait ComplexObject {
+    /// Returns the additional fields.
+    fn fields(registry: &mut registry::Registry) -> Vec<(String, registry::MetaField)>;
+
+    /// Resolves an additional field, returns `None` if it is not one of them.
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>>;
+}
+
 /// A GraphQL interface.
 pub trait InterfaceType: ContainerType {}
 
@@ -6,23 +6,26 @@ use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use async_graphql_value::Value as InputValue;
 use fnv::FnvHashMap;
 use http::header::{AsHeaderName, HeaderMap, IntoHeaderName};
-use serde::de::{Deserialize, Deserializer};
+use serde::de::{self, Deserialize, Deserializer};
 use serde::ser::{SerializeSeq, Serializer};
 use serde::Serialize;
 
+use crate::error::HTTP_STATUS_EXTENSION;
 use crate::extensions::Extensions;
 use crate::parser::types::{
-    Directive, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    Directive, Field, FragmentDefinition, OperationDefinition, OperationType, Selection,
+    SelectionSet,
 };
 use crate::schema::SchemaEnv;
 use crate::{
-    Error, InputType, Lookahead, Name, Pos, Positioned, Result, ServerError, ServerResult,
-    UploadValue, Value,
+    CacheControl, Error, ErrorExtensionValues, InputType, InputValueResult, Lookahead, Name, Pos,
+    Positioned, Result, ServerError, ServerResult, UploadValue, Value,
 };
 
 /// Variables of a query.
@@ -42,9 +45,15 @@ impl Display for Variables {
 
 impl<'de> Deserialize<'de> for Variables {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Ok(Self(
-            <Option<BTreeMap<Name, Value>>>::deserialize(deserializer)?.unwrap_or_default(),
-        ))
+        let variables =
+            <Option<BTreeMap<Name, Value>>>::deserialize(deserializer)?.unwrap_or_default();
+        if let Some(name) = variables.keys().find(|name| !Name::is_valid(name)) {
+            return Err(de::Error::custom(format!(
+                "\"{}\" is not a valid variable name",
+                name
+            )));
+        }
+        Ok(Self(variables))
     }
 }
 
@@ -71,12 +80,51 @@ impl Variables {
             .unwrap_or_default()
     }
 
+    /// Parse the variables from the `variables` parameter of a query string, such as the one of a
+    /// GET request, after it has been URL-decoded.
+    ///
+    /// The parameter must be a JSON object whose keys are valid GraphQL names. An empty parameter
+    /// and `null` are the same as no variables.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the parameter is not valid JSON or is not a JSON object.
+    pub fn from_query_string_param(s: &str) -> serde_json::Result<Self> {
+        if s.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_str(s)
+    }
+
+    /// Write the variables as the `variables` parameter of a query string. This is the inverse
+    /// of [`from_query_string_param`](#method.from_query_string_param); the result still has to
+    /// be URL-encoded.
+    #[must_use]
+    pub fn to_query_string_param(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
     /// Get the variables as a GraphQL value.
     #[must_use]
     pub fn into_value(self) -> Value {
         Value::Object(self.0)
     }
 
+    /// Get a variable and parse it as `T`, returns `None` if the variable does not exist.
+    pub fn get<T: InputType>(&self, name: &str) -> Option<InputValueResult<T>> {
+        self.0.get(name).map(|value| T::parse(Some(value.clone())))
+    }
+
+    /// Get a mutable reference to the value of a variable.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.0.get_mut(name)
+    }
+
+    /// Set a variable to a value, replacing the old value if there is one.
+    pub fn set<T: InputType>(&mut self, name: impl AsRef<str>, value: T) {
+        self.0.insert(Name::new(name), value.to_value());
+    }
+
     pub(crate) fn variable_path(&mut self, path: &str) -> Option<&mut Value> {
         let mut parts = path.strip_prefix("variables.")?.split('.');
 
@@ -92,6 +140,21 @@ impl Variables {
             _ => None,
         })
     }
+
+    /// Set the value at the given path, returning whether the path existed.
+    ///
+    /// The path uses the same syntax as the multipart upload map, so `variables.files.2.content`
+    /// sets the `content` field of the third element of the `files` variable. Nothing is changed
+    /// if the path does not exist.
+    pub fn set_path(&mut self, path: &str, value: Value) -> bool {
+        match self.variable_path(path) {
+            Some(current) => {
+                *current = value;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl From<Variables> for Value {
@@ -317,11 +380,16 @@ pub struct ContextBase<'a, T> {
 pub struct QueryEnvInner {
     pub extensions: Extensions,
     pub variables: Variables,
+    pub operation_name: Option<Name>,
     pub operation: Positioned<OperationDefinition>,
     pub fragments: HashMap<Name, Positioned<FragmentDefinition>>,
     pub uploads: Vec<UploadValue>,
     pub ctx_data: Arc<Data>,
     pub http_headers: spin::Mutex<HeaderMap<String>>,
+    pub response_extensions: spin::Mutex<BTreeMap<Name, Value>>,
+    pub request_cache: spin::Mutex<FnvHashMap<TypeId, Arc<dyn Any + Sync + Send>>>,
+    pub started_at: Instant,
+    pub cache_control: CacheControl,
 }
 
 #[doc(hidden)]
@@ -336,6 +404,38 @@ impl Deref for QueryEnv {
     }
 }
 
+impl QueryEnvInner {
+    pub(crate) fn var_value(&self, name: &str, pos: Pos) -> ServerResult<Value> {
+        self.operation
+            .node
+            .variable_definitions
+            .iter()
+            .find(|def| def.node.name.node == name)
+            .and_then(|def| {
+                self.variables
+                    .0
+                    .get(&def.node.name.node)
+                    .or_else(|| def.node.default_value())
+            })
+            .cloned()
+            .ok_or_else(|| ServerError::new(format!("Variable {} is not defined.", name)).at(pos))
+    }
+
+    /// Returns true if the variable is declared but has neither a value in the request nor a
+    /// default value.
+    pub(crate) fn var_is_missing(&self, name: &str) -> bool {
+        self.operation
+            .node
+            .variable_definitions
+            .iter()
+            .find(|def| def.node.name.node == name)
+            .map_or(false, |def| {
+                !self.variables.0.contains_key(&def.node.name.node)
+                    && def.node.default_value.is_none()
+            })
+    }
+}
+
 impl QueryEnv {
     #[doc(hidden)]
     pub fn new(inner: QueryEnvInner) -> QueryEnv {
@@ -444,6 +544,107 @@ impl<'a, T> ContextBase<'a, T> {
             .and_then(|d| d.downcast_ref::<D>())
     }
 
+    /// Calls `f` with a `Mutex<T>` that is shared by all the resolvers of the current request.
+    ///
+    /// If a `Mutex<T>` was added to the request data with `Request::data`, it is used, otherwise
+    /// one is created with `T::default()` the first time it is requested. It can be used to
+    /// accumulate state across fields, such as a per-request cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// #[derive(Default)]
+    /// struct Counter(i32);
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn next(&self, ctx: &Context<'_>) -> i32 {
+    ///         ctx.with_request_cache(|counter: &std::sync::Mutex<Counter>| {
+    ///             let mut counter = counter.lock().unwrap();
+    ///             counter.0 += 1;
+    ///             counter.0
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    pub fn with_request_cache<T: Default + Send + Sync + 'static, R>(
+        &self,
+        f: impl FnOnce(&Mutex<T>) -> R,
+    ) -> R {
+        if let Some(data) = self
+            .query_env
+            .ctx_data
+            .0
+            .get(&TypeId::of::<Mutex<T>>())
+            .and_then(|d| d.downcast_ref::<Mutex<T>>())
+        {
+            return f(data);
+        }
+
+        let data = self
+            .query_env
+            .request_cache
+            .lock()
+            .entry(TypeId::of::<Mutex<T>>())
+            .or_insert_with(|| Arc::new(Mutex::new(T::default())))
+            .clone();
+        f(data.downcast_ref::<Mutex<T>>().unwrap())
+    }
+
+    /// Stores a value of type `T` for the current request, replacing the previous one.
+    ///
+    /// Unlike the data added with `Request::data`, it can be set by resolvers, and read by the
+    /// resolvers that run after them with [`get_local`](#method.get_local). The fields of a query
+    /// are resolved concurrently, so a value set by one is only guaranteed to be visible in its
+    /// child fields and in the later fields of a mutation.
+    ///
+    /// The value is kept in the same per-request store as
+    /// [`with_request_cache`](#method.with_request_cache), so setting a `Mutex<T>` replaces the
+    /// one `with_request_cache` passes for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// struct CurrentUser(String);
+    ///
+    /// struct Mutation;
+    ///
+    /// #[Object]
+    /// impl Mutation {
+    ///     async fn login(&self, ctx: &Context<'_>, name: String) -> bool {
+    ///         ctx.set_local(CurrentUser(name));
+    ///         true
+    ///     }
+    ///
+    ///     async fn whoami(&self, ctx: &Context<'_>) -> Option<String> {
+    ///         ctx.get_local::<CurrentUser>().map(|user| user.0.clone())
+    ///     }
+    /// }
+    /// ```
+    pub fn set_local<T: Any + Send + Sync>(&self, value: T) {
+        self.query_env
+            .request_cache
+            .lock()
+            .insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Gets the value of type `T` stored for the current request with
+    /// [`set_local`](#method.set_local), or `None` if it has not been set.
+    pub fn get_local<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.query_env
+            .request_cache
+            .lock()
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|value| value.downcast::<T>().ok())
+    }
+
     /// Returns whether the HTTP header `key` is currently set on the response
     ///
     /// # Examples
@@ -564,22 +765,193 @@ impl<'a, T> ContextBase<'a, T> {
             .append(name, value.into())
     }
 
-    fn var_value(&self, name: &str, pos: Pos) -> ServerResult<Value> {
+    /// Creates an error that aborts the resolution with an HTTP status hint, such as `403`.
+    ///
+    /// Return it from a resolver or a guard. The hint is stored in the `httpStatus` extension of
+    /// the error, and the integrations use it as the status of the HTTP response, see
+    /// [`Response::http_status`](struct.Response.html#method.http_status).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn secret(&self, ctx: &Context<'_>) -> Result<String> {
+    ///         Err(ctx.abort_with_status(403, "Forbidden"))
+    ///     }
+    /// }
+    /// ```
+    pub fn abort_with_status(&self, status: u16, message: impl Into<String>) -> Error {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set(HTTP_STATUS_EXTENSION, status);
+        Error {
+            message: message.into(),
+            extensions: Some(extensions),
+        }
+    }
+
+    /// Adds an entry to the `extensions` of the response.
+    ///
+    /// The entry is returned even if the query succeeds. If the key was already set, the previous
+    /// value is returned, and entries produced by schema extensions with the same name take
+    /// precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn items(&self, ctx: &Context<'_>) -> Vec<i32> {
+    ///         ctx.append_extension("cost", 2);
+    ///         vec![1, 2]
+    ///     }
+    /// }
+    /// ```
+    pub fn append_extension(
+        &self,
+        name: impl AsRef<str>,
+        value: impl Into<Value>,
+    ) -> Option<Value> {
         self.query_env
-            .operation
-            .node
-            .variable_definitions
-            .iter()
-            .find(|def| def.node.name.node == name)
-            .and_then(|def| {
-                self.query_env
-                    .variables
-                    .0
-                    .get(&def.node.name.node)
-                    .or_else(|| def.node.default_value())
-            })
-            .cloned()
-            .ok_or_else(|| ServerError::new(format!("Variable {} is not defined.", name)).at(pos))
+            .response_extensions
+            .lock()
+            .insert(Name::new(name), value.into())
+    }
+
+    /// Reports that the current field is deprecated, so the client usage can be tracked.
+    ///
+    /// The report is added to the `deprecations` list of the response `extensions` as an object
+    /// with the `path` of the field and the `detail`. List indices are left out of the path, so
+    /// the field is only reported once even if it is resolved for every element of a list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     #[graphql(deprecation = "Use `name` instead.")]
+    ///     async fn username(&self, ctx: &Context<'_>) -> &str {
+    ///         ctx.report_deprecation("Use `name` instead.");
+    ///         "sunli"
+    ///     }
+    /// }
+    /// ```
+    pub fn report_deprecation(&self, detail: &str) {
+        let mut path = String::new();
+        if let Some(node) = &self.path_node {
+            node.for_each(|segment| {
+                if let QueryPathSegment::Name(name) = segment {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+            });
+        }
+
+        let mut extensions = self.query_env.response_extensions.lock();
+        let deprecations = extensions
+            .entry(Name::from_static("deprecations"))
+            .or_insert_with(|| Value::List(Vec::new()));
+        if let Value::List(deprecations) = deprecations {
+            let reported = deprecations.iter().any(|item| match item {
+                Value::Object(obj) => {
+                    matches!(obj.get("path"), Some(Value::String(s)) if *s == path)
+                }
+                _ => false,
+            });
+            if !reported {
+                let mut item = BTreeMap::new();
+                item.insert(Name::from_static("path"), Value::String(path));
+                item.insert(
+                    Name::from_static("detail"),
+                    Value::String(detail.to_string()),
+                );
+                deprecations.push(Value::Object(item));
+            }
+        }
+    }
+
+    /// Gets the operation that is being executed.
+    ///
+    /// This allows the whole query to be analyzed, not just the current field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use async_graphql::parser::types::Selection;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn a(&self, ctx: &Context<'_>) -> Vec<String> {
+    ///         ctx.operation()
+    ///             .node
+    ///             .selection_set
+    ///             .node
+    ///             .items
+    ///             .iter()
+    ///             .filter_map(|selection| match &selection.node {
+    ///                 Selection::Field(field) => Some(field.node.name.node.to_string()),
+    ///                 _ => None,
+    ///             })
+    ///             .collect()
+    ///     }
+    ///
+    ///     async fn b(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     let res = schema.execute("{ a b }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "a": ["a", "b"], "b": 10 }));
+    /// });
+    /// ```
+    pub fn operation(&self) -> &'a Positioned<OperationDefinition> {
+        &self.query_env.operation
+    }
+
+    /// Gets the name of the operation being executed, or `None` if it is anonymous.
+    pub fn operation_name(&self) -> Option<&'a str> {
+        self.query_env.operation_name.as_ref().map(Name::as_str)
+    }
+
+    /// Gets the type of the operation being executed.
+    pub fn operation_type(&self) -> OperationType {
+        self.query_env.operation.node.ty
+    }
+
+    /// Gets the fragments defined in the query document.
+    pub fn fragments(&self) -> &'a HashMap<Name, Positioned<FragmentDefinition>> {
+        &self.query_env.fragments
+    }
+
+    /// Gets the time elapsed since the schema started processing the request, including parsing
+    /// and validation.
+    ///
+    /// For a subscription, this is the time since the subscription was started.
+    pub fn elapsed(&self) -> Duration {
+        self.query_env.started_at.elapsed()
+    }
+
+    fn var_value(&self, name: &str, pos: Pos) -> ServerResult<Value> {
+        self.query_env.var_value(name, pos)
     }
 
     fn resolve_input_value(&self, value: Positioned<InputValue>) -> ServerResult<Value> {
@@ -650,7 +1022,16 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
         name: &str,
         default: Option<fn() -> T>,
     ) -> ServerResult<T> {
-        let value = self.item.node.get_argument(name).cloned();
+        // An argument bound to a variable that has no value is treated as omitted.
+        let value = self
+            .item
+            .node
+            .get_argument(name)
+            .filter(|value| match &value.node {
+                InputValue::Variable(var) => !self.query_env.var_is_missing(var),
+                _ => true,
+            })
+            .cloned();
         if value.is_none() {
             if let Some(default) = default {
                 return Ok(default());
@@ -663,6 +1044,65 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
         InputType::parse(value).map_err(|e| e.into_server_error().at(pos))
     }
 
+    /// Get all arguments of the current field, with variables substituted.
+    ///
+    /// Only the arguments that appear in the query are returned, default values are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self, ctx: &Context<'_>, a: i32, b: String) -> String {
+    ///         let args = ctx.field_arguments().unwrap();
+    ///         assert_eq!(args.get("a"), Some(&Value::from(10)));
+    ///         assert_eq!(args.get("b"), Some(&Value::from("abc")));
+    ///         format!("{}{}", a, b)
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     assert!(schema.execute(r#"{ value(a: 10, b: "abc") }"#).await.is_ok());
+    /// });
+    /// ```
+    pub fn field_arguments(&self) -> ServerResult<BTreeMap<String, Value>> {
+        self.resolve_arguments(&self.item.node.arguments)
+    }
+
+    /// Get the arguments of a directive applied to the current field, with variables substituted.
+    ///
+    /// Returns `None` if the directive is not present on the field. Custom directives must be
+    /// registered with [`SchemaBuilder::register_directive`](struct.SchemaBuilder.html#method.register_directive).
+    pub fn directive_arguments(&self, name: &str) -> ServerResult<Option<BTreeMap<String, Value>>> {
+        self.item
+            .node
+            .directives
+            .iter()
+            .find(|directive| directive.node.name.node == name)
+            .map(|directive| self.resolve_arguments(&directive.node.arguments))
+            .transpose()
+    }
+
+    fn resolve_arguments(
+        &self,
+        arguments: &[(Positioned<Name>, Positioned<InputValue>)],
+    ) -> ServerResult<BTreeMap<String, Value>> {
+        arguments
+            .iter()
+            .map(|(name, value)| {
+                Ok((
+                    name.node.to_string(),
+                    self.resolve_input_value(value.clone())?,
+                ))
+            })
+            .collect()
+    }
+
     /// Creates a uniform interface to inspect the forthcoming selections.
     ///
     /// # Examples
@@ -700,7 +1140,7 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
     /// }
     /// ```
     pub fn look_ahead(&self) -> Lookahead {
-        Lookahead::new(&self.query_env.fragments, &self.item.node)
+        Lookahead::new(self.query_env, &self.item.node)
     }
 
     /// Get the current field.
@@ -737,30 +1177,122 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
     ///
     /// ```
     pub fn field(&self) -> SelectionField<'a> {
-        SelectionField {
-            fragments: &self.query_env.fragments,
-            field: &self.item.node,
-        }
+        SelectionField::new(self.query_env, &self.item.node)
     }
 }
 
 /// Selection field.
 #[derive(Clone, Copy)]
 pub struct SelectionField<'a> {
-    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    query_env: &'a QueryEnv,
     field: &'a Field,
 }
 
 impl<'a> SelectionField<'a> {
+    pub(crate) fn new(query_env: &'a QueryEnv, field: &'a Field) -> Self {
+        Self { query_env, field }
+    }
+
     /// Get the name of this field.
     pub fn name(&self) -> &'a str {
         self.field.name.node.as_str()
     }
 
+    /// Get the alias of this field, if it is different from the name of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// #[derive(SimpleObject)]
+    /// struct MyObj {
+    ///     a: i32,
+    ///     b: i32,
+    /// }
+    ///
+    /// pub struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn obj(&self, ctx: &Context<'_>) -> MyObj {
+    ///         let aliases = ctx.field().selection_set().map(|field| field.alias()).collect::<Vec<_>>();
+    ///         assert_eq!(aliases, vec![Some("x"), None, None]);
+    ///         MyObj { a: 1, b: 2 }
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     assert!(schema.execute("{ obj { x: a b a: a }}").await.is_ok());
+    /// });
+    /// ```
+    pub fn alias(&self) -> Option<&'a str> {
+        self.field
+            .alias
+            .as_ref()
+            .map(|alias| alias.node.as_str())
+            .filter(|alias| *alias != self.name())
+    }
+
+    /// Get the arguments of this field, with variables substituted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct MyObj;
+    ///
+    /// #[Object]
+    /// impl MyObj {
+    ///     async fn value(&self, a: i32, b: String) -> String {
+    ///         format!("{}{}", a, b)
+    ///     }
+    /// }
+    ///
+    /// pub struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn obj(&self, ctx: &Context<'_>) -> MyObj {
+    ///         let field = ctx.field().selection_set().next().unwrap();
+    ///         assert_eq!(
+    ///             field.arguments().unwrap(),
+    ///             vec![(Name::new("a"), Value::from(10)), (Name::new("b"), Value::from("abc"))]
+    ///         );
+    ///         MyObj
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     let request = Request::new(r#"query($b: String!) { obj { value(a: 10, b: $b) } }"#)
+    ///         .variables(Variables::from_value(value!({ "b": "abc" })));
+    ///     assert!(schema.execute(request).await.is_ok());
+    /// });
+    /// ```
+    pub fn arguments(&self) -> ServerResult<Vec<(Name, Value)>> {
+        self.field
+            .arguments
+            .iter()
+            .map(|(name, value)| {
+                let pos = value.pos;
+                Ok((
+                    name.node.clone(),
+                    value
+                        .node
+                        .clone()
+                        .into_const_with(|var_name| self.query_env.var_value(&var_name, pos))?,
+                ))
+            })
+            .collect()
+    }
+
     /// Get all subfields of the current selection set.
     pub fn selection_set(&self) -> impl Iterator<Item = SelectionField<'a>> {
         SelectionFieldsIter {
-            fragments: self.fragments,
+            query_env: self.query_env,
             iter: vec![self.field.selection_set.node.items.iter()],
         }
     }
@@ -787,7 +1319,7 @@ impl<'a> Debug for SelectionField<'a> {
 }
 
 struct SelectionFieldsIter<'a> {
-    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    query_env: &'a QueryEnv,
     iter: Vec<std::slice::Iter<'a, Positioned<Selection>>>,
 }
 
@@ -801,13 +1333,15 @@ impl<'a> Iterator for SelectionFieldsIter<'a> {
                 Some(selection) => match &selection.node {
                     Selection::Field(field) => {
                         return Some(SelectionField {
-                            fragments: self.fragments,
+                            query_env: self.query_env,
                             field: &field.node,
                         });
                     }
                     Selection::FragmentSpread(fragment_spread) => {
-                        if let Some(fragment) =
-                            self.fragments.get(&fragment_spread.node.fragment_name.node)
+                        if let Some(fragment) = self
+                            .query_env
+                            .fragments
+                            .get(&fragment_spread.node.fragment_name.node)
                         {
                             self.iter
                                 .push(fragment.node.selection_set.node.items.iter());
@@ -825,3 +1359,36 @@ impl<'a> Iterator for SelectionFieldsIter<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_deserialize_variables() {
+        let variables: Variables = from_value(value!({ "id": "1" })).unwrap();
+        assert_eq!(variables.into_value(), value!({ "id": "1" }));
+
+        let variables: Variables = from_value(value!(null)).unwrap();
+        assert!(variables.0.is_empty());
+
+        assert!(from_value::<Variables>(value!(r#"{"id":"1"}"#)).is_err());
+        assert!(from_value::<Variables>(value!({ "1id": 1 })).is_err());
+    }
+
+    #[test]
+    fn test_variables_query_string_param() {
+        let variables = Variables::from_query_string_param(r#"{"id":"1"}"#).unwrap();
+        assert_eq!(variables.clone().into_value(), value!({ "id": "1" }));
+        assert_eq!(variables.to_query_string_param(), r#"{"id":"1"}"#);
+
+        assert!(Variables::from_query_string_param("").unwrap().0.is_empty());
+        assert!(Variables::from_query_string_param("null")
+            .unwrap()
+            .0
+            .is_empty());
+        assert!(Variables::from_query_string_param(r#"{"id":"1""#).is_err());
+        assert!(Variables::from_query_string_param("[1, 2]").is_err());
+        assert!(Variables::from_query_string_param(r#"{"1id": 1}"#).is_err());
+    }
+}
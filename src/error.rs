@@ -17,8 +17,16 @@ impl ErrorExtensionValues {
     pub fn set(&mut self, name: impl AsRef<str>, value: impl Into<Value>) {
         self.0.insert(name.as_ref().to_string(), value.into());
     }
+
+    /// Get an extension value.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<&Value> {
+        self.0.get(name.as_ref())
+    }
 }
 
+/// The name of the error extension that holds the HTTP status hint.
+pub(crate) const HTTP_STATUS_EXTENSION: &str = "httpStatus";
+
 /// An error in a GraphQL server.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ServerError {
@@ -61,6 +69,21 @@ impl ServerError {
         self.path.insert(0, path);
         self
     }
+
+    /// Returns the HTTP status hint set with
+    /// [`Context::abort_with_status`](struct.ContextBase.html#method.abort_with_status).
+    pub fn http_status(&self) -> Option<u16> {
+        match self.extensions.as_ref()?.get(HTTP_STATUS_EXTENSION)? {
+            Value::Number(status) => status.as_u64().and_then(|status| {
+                if (100..600).contains(&status) {
+                    Some(status as u16)
+                } else {
+                    None
+                }
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl Display for ServerError {
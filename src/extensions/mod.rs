@@ -99,6 +99,9 @@ pub trait Extension: Sync + Send + 'static {
     }
 
     /// Called at the prepare request
+    ///
+    /// The request is passed as it was received, the values of `secret` arguments and input
+    /// fields are not redacted yet.
     async fn prepare_request(
         &mut self,
         ctx: &ExtensionContext<'_>,
@@ -108,6 +111,11 @@ pub trait Extension: Sync + Send + 'static {
     }
 
     /// Called at the begin of the parse.
+    ///
+    /// If the schema has `secret` arguments or input fields, their values are replaced with
+    /// `"[redacted]"` in the variables, and the query source is printed from the redacted document
+    /// instead of being passed as it was received. A query that can't be parsed, or whose secrets
+    /// can't all be redacted, is replaced with `"[redacted]"`.
     fn parse_start(
         &mut self,
         ctx: &ExtensionContext<'_>,
@@ -117,6 +125,9 @@ pub trait Extension: Sync + Send + 'static {
     }
 
     /// Called at the end of the parse.
+    ///
+    /// The document is redacted like the query source, so it doesn't contain the values of
+    /// `secret` arguments and input fields either.
     fn parse_end(&mut self, ctx: &ExtensionContext<'_>, document: &ExecutableDocument) {}
 
     /// Called at the begin of the validation.
@@ -138,6 +149,9 @@ pub trait Extension: Sync + Send + 'static {
     fn resolve_end(&mut self, ctx: &ExtensionContext<'_>, info: &ResolveInfo<'_>) {}
 
     /// Called when an error occurs.
+    ///
+    /// Error messages are not redacted, so an error about the value of a `secret` argument or
+    /// input field, such as a failed type check, may contain that value.
     fn error(&mut self, ctx: &ExtensionContext<'_>, err: &ServerError) {}
 
     /// Get the results
@@ -278,10 +292,9 @@ impl Extensions {
         }
     }
 
-    pub fn result(&self, ctx: &ExtensionContext<'_>) -> Option<Value> {
+    pub fn result(&self, ctx: &ExtensionContext<'_>) -> BTreeMap<Name, Value> {
         if let Some(e) = &self.0 {
-            let value = e
-                .lock()
+            e.lock()
                 .iter_mut()
                 .filter_map(|e| {
                     if let Some(name) = e.name() {
@@ -290,14 +303,9 @@ impl Extensions {
                         None
                     }
                 })
-                .collect::<BTreeMap<_, _>>();
-            if value.is_empty() {
-                None
-            } else {
-                Some(Value::Object(value))
-            }
+                .collect()
         } else {
-            None
+            BTreeMap::new()
         }
     }
 }
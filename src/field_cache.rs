@@ -0,0 +1,234 @@
+//! Field result cache.
+//!
+//! Fields marked with `#[graphql(cached)]` consult the cache set with
+//! [`SchemaBuilder::field_cache`](../struct.SchemaBuilder.html#method.field_cache) before running
+//! their resolver, and store the result for the `max_age` of the cache control of the request,
+//! which is never longer than the `max_age` of their own `cache_control`. The result is not stored
+//! if the request is not publicly cacheable.
+//!
+//! A cached field that is not at the top level of the query must also have a `cache_key`, an
+//! expression that identifies the parent object such as `cache_key = "self.id"`, otherwise the
+//! result of one object would be returned for all of them. Resolving such a field without a
+//! `cache_key` is an error.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures_util::lock::Mutex;
+
+use crate::parser::types::{Directive, Field, Selection, SelectionSet};
+use crate::{Context, Name, Positioned, ServerError, ServerResult, Value};
+
+/// Storage for the results of cached fields.
+#[async_trait::async_trait]
+pub trait FieldCache: Send + Sync + 'static {
+    /// Load the result of a field by `key`.
+    async fn get(&self, key: &str) -> Option<Value>;
+
+    /// Save the result of a field by `key`, it must not be returned after `max_age`.
+    async fn set(&self, key: String, value: Value, max_age: Duration);
+}
+
+/// Memory-based field cache.
+///
+/// Expired entries are removed when they are read, and all of them whenever the number of
+/// entries has doubled since the last time.
+#[derive(Default)]
+pub struct MemoryFieldCache(Mutex<MemoryFieldCacheInner>);
+
+#[derive(Default)]
+struct MemoryFieldCacheInner {
+    entries: HashMap<String, (Instant, Value)>,
+    prune_at: usize,
+}
+
+impl MemoryFieldCache {
+    /// Creates a new empty cache.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl FieldCache for MemoryFieldCache {
+    async fn get(&self, key: &str) -> Option<Value> {
+        let mut cache = self.0.lock().await;
+        match cache.entries.get(key) {
+            Some((expires, value)) if *expires > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                cache.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn set(&self, key: String, value: Value, max_age: Duration) {
+        let mut cache = self.0.lock().await;
+        let now = Instant::now();
+        if cache.entries.len() >= cache.prune_at {
+            cache.entries.retain(|_, (expires, _)| *expires > now);
+            cache.prune_at = (cache.entries.len() * 2).max(16);
+        }
+        cache.entries.insert(key, (now + max_age, value));
+    }
+}
+
+/// Returns the cache key of the current field of `type_name`, on the parent object identified by
+/// `parent_key`.
+///
+/// It contains the field name, the arguments and the selection set, with the variables and the
+/// fragments substituted, so that it is equal for the same field in different requests.
+pub fn to_canonical_string(
+    ctx: &Context<'_>,
+    type_name: &str,
+    parent_key: Option<&str>,
+) -> ServerResult<String> {
+    let mut key = type_name.to_string();
+    if let Some(parent_key) = parent_key {
+        write!(
+            key,
+            "[{}]",
+            parent_key.replace('\\', "\\\\").replace(']', "\\]")
+        )
+        .ok();
+    }
+    key.push('.');
+    write_field(ctx, &ctx.item.node, &mut key)?;
+    Ok(key)
+}
+
+fn write_arguments(
+    ctx: &Context<'_>,
+    arguments: &[(Positioned<Name>, Positioned<crate::parser::types::Value>)],
+    key: &mut String,
+) -> ServerResult<()> {
+    if arguments.is_empty() {
+        return Ok(());
+    }
+    let mut arguments = arguments
+        .iter()
+        .map(|(name, value)| {
+            let pos = value.pos;
+            let value = value
+                .node
+                .clone()
+                .into_const_with(|name| ctx.query_env.var_value(&name, pos))?;
+            Ok((name.node.as_str(), value))
+        })
+        .collect::<ServerResult<Vec<_>>>()?;
+    arguments.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    key.push('(');
+    for (name, value) in arguments {
+        write!(key, "{}:{},", name, value).ok();
+    }
+    key.push(')');
+    Ok(())
+}
+
+fn write_directives(
+    ctx: &Context<'_>,
+    directives: &[Positioned<Directive>],
+    key: &mut String,
+) -> ServerResult<()> {
+    for directive in directives {
+        write!(key, "@{}", directive.node.name.node).ok();
+        write_arguments(ctx, &directive.node.arguments, key)?;
+    }
+    Ok(())
+}
+
+fn write_field(ctx: &Context<'_>, field: &Field, key: &mut String) -> ServerResult<()> {
+    key.push_str(&field.name.node);
+    write_arguments(ctx, &field.arguments, key)?;
+    write_directives(ctx, &field.directives, key)?;
+    write_selection_set(ctx, &field.selection_set.node, key)
+}
+
+fn write_selection_set(
+    ctx: &Context<'_>,
+    selection_set: &SelectionSet,
+    key: &mut String,
+) -> ServerResult<()> {
+    if selection_set.items.is_empty() {
+        return Ok(());
+    }
+
+    key.push('{');
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                write!(key, "{}:", field.node.response_key().node).ok();
+                write_field(ctx, &field.node, key)?;
+            }
+            Selection::FragmentSpread(spread) => {
+                if let Some(fragment) = ctx.query_env.fragments.get(&spread.node.fragment_name.node)
+                {
+                    write!(key, "...on {}", fragment.node.type_condition.node.on.node).ok();
+                    write_directives(ctx, &spread.node.directives, key)?;
+                    write_selection_set(ctx, &fragment.node.selection_set.node, key)?;
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                key.push_str("...");
+                if let Some(type_condition) = &fragment.node.type_condition {
+                    write!(key, "on {}", type_condition.node.on.node).ok();
+                }
+                write_directives(ctx, &fragment.node.directives, key)?;
+                write_selection_set(ctx, &fragment.node.selection_set.node, key)?;
+            }
+        }
+        key.push(',');
+    }
+    key.push('}');
+    Ok(())
+}
+
+#[doc(hidden)]
+pub async fn resolve_cached_field<F, Fut>(
+    ctx: &Context<'_>,
+    type_name: &str,
+    parent_key: Option<String>,
+    resolve: F,
+) -> ServerResult<Value>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = ServerResult<Value>>,
+{
+    let cache = match &ctx.schema_env.field_cache {
+        Some(cache) => cache,
+        None => return resolve().await,
+    };
+
+    let is_top_level = ctx
+        .path_node
+        .as_ref()
+        .map_or(true, |node| node.parent.is_none());
+    if parent_key.is_none() && !is_top_level {
+        return Err(ServerError::new(format!(
+            "The cached field \"{}.{}\" is not at the top level, so it needs a cache_key.",
+            type_name, ctx.item.node.name.node
+        ))
+        .at(ctx.item.pos));
+    }
+
+    let key = to_canonical_string(ctx, type_name, parent_key.as_deref())?;
+    if let Some(value) = cache.get(&key).await {
+        return Ok(value);
+    }
+    let value = resolve().await?;
+    let cache_control = ctx.query_env.cache_control;
+    if cache_control.public && cache_control.max_age > 0 {
+        cache
+            .set(
+                key,
+                value.clone(),
+                Duration::from_secs(cache_control.max_age as u64),
+            )
+            .await;
+    }
+    Ok(value)
+}
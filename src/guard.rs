@@ -1,5 +1,9 @@
 //! Field guards
 
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 use crate::{Context, Result};
 
 /// Field guard
@@ -49,3 +53,92 @@ impl<A: Guard + Send + Sync, B: Guard + Send + Sync> Guard for Or<A, B> {
         self.0.check(ctx).await.or(second_result)
     }
 }
+
+type RateLimitKeyFn = dyn Fn(&Context<'_>) -> String + Send + Sync;
+
+/// The counters of the [`RateLimit`](struct.RateLimit.html) guards, which must be added to the
+/// schema data.
+///
+/// The key of a check is computed from the context, for example the id of the current user or the
+/// address of the client. Guards with the same `max` and `window` share the counter of a key.
+pub struct RateLimitState {
+    key: Box<RateLimitKeyFn>,
+    counters: Mutex<HashMap<(String, usize, Duration), (Instant, usize)>>,
+}
+
+impl RateLimitState {
+    /// Create the counters, with the function that computes the key of a check.
+    pub fn new(key: impl Fn(&Context<'_>) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            key: Box::new(key),
+            counters: Default::default(),
+        }
+    }
+}
+
+/// A guard that allows at most `max` checks per key within a time window.
+///
+/// The counters are kept in the [`RateLimitState`](struct.RateLimitState.html) of the schema data,
+/// so they are shared by all requests. The check fails if there is no `RateLimitState`.
+///
+/// The window is fixed: the counter of a key is reset when `window` has elapsed since its first
+/// check.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use async_graphql::*;
+/// use async_graphql::guard::{RateLimit, RateLimitState};
+///
+/// struct ClientId(String);
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     #[graphql(guard(RateLimit(max = "100usize", window = "Duration::from_secs(60)")))]
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .data(RateLimitState::new(|ctx| {
+///         ctx.data_unchecked::<ClientId>().0.clone()
+///     }))
+///     .finish();
+/// ```
+pub struct RateLimit {
+    /// The number of checks allowed per key within the window.
+    pub max: usize,
+
+    /// The length of the window.
+    pub window: Duration,
+}
+
+#[async_trait::async_trait]
+impl Guard for RateLimit {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let state = ctx.data::<RateLimitState>()?;
+        let key = ((state.key)(ctx), self.max, self.window);
+        let now = Instant::now();
+        let mut counters = state.counters.lock().unwrap();
+
+        if !counters.contains_key(&key) {
+            // forget the keys whose window has elapsed, so that the map doesn't grow forever
+            counters.retain(|(_, _, window), (start, _)| now.duration_since(*start) < *window);
+        }
+        let (start, count) = counters.entry(key).or_insert((now, 0));
+        if now.duration_since(*start) >= self.window {
+            *start = now;
+            *count = 0;
+        }
+        if *count >= self.max {
+            return Err("Too many requests".into());
+        }
+        *count += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::Deserialize;
+
+use crate::{ParseRequestError, Request, Variables};
+
+/// The parameters of a GraphQL GET request, deserialized from its query string.
+///
+/// Unlike in the body of a POST request, the variables are a JSON string, which is parsed by
+/// [`into_request`](#method.into_request).
+#[derive(Debug, Deserialize)]
+pub struct GetRequest {
+    /// The query source of the request.
+    #[serde(default)]
+    pub query: String,
+
+    /// The operation name of the request.
+    #[serde(default, rename = "operationName")]
+    pub operation_name: Option<String>,
+
+    /// The variables of the request as a JSON string.
+    #[serde(default)]
+    pub variables: Option<String>,
+}
+
+impl GetRequest {
+    /// Convert the parameters into a request, parsing the variables with
+    /// [`Variables::from_query_string_param`](../struct.Variables.html#method.from_query_string_param).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the variables are not a JSON object whose keys are valid GraphQL names.
+    pub fn into_request(self) -> Result<Request, ParseRequestError> {
+        let variables = match &self.variables {
+            Some(variables) => Variables::from_query_string_param(variables)
+                .map_err(ParseRequestError::InvalidRequest)?,
+            None => Variables::default(),
+        };
+        let mut request = Request::new(self.query).variables(variables);
+        request.operation_name = self.operation_name;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value;
+
+    #[test]
+    fn test_get_request() {
+        let request: GetRequest = serde_json::from_value(serde_json::json!({
+            "query": "query($id: ID!) { user(id: $id) }",
+            "variables": r#"{"id":"1"}"#
+        }))
+        .unwrap();
+        let request = request.into_request().unwrap();
+        assert_eq!(request.query, "query($id: ID!) { user(id: $id) }");
+        assert!(request.operation_name.is_none());
+        assert_eq!(request.variables.into_value(), value!({ "id": "1" }));
+
+        let request: GetRequest = serde_json::from_value(serde_json::json!({
+            "query": "{ a }",
+            "variables": "[1, 2]"
+        }))
+        .unwrap();
+        assert!(matches!(
+            request.into_request(),
+            Err(ParseRequestError::InvalidRequest(_))
+        ));
+    }
+}
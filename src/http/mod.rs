@@ -1,5 +1,6 @@
 //! A helper module that supports HTTP
 
+mod get_request;
 mod graphiql_source;
 #[cfg(feature = "multipart")]
 mod multipart;
@@ -10,6 +11,7 @@ use futures_util::io::{AsyncRead, AsyncReadExt};
 
 use crate::{BatchRequest, ParseRequestError, Request};
 
+pub use get_request::GetRequest;
 pub use graphiql_source::graphiql_source;
 #[cfg(feature = "multipart")]
 pub use multipart::MultipartOptions;
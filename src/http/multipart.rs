@@ -87,7 +87,7 @@ pub(super) async fn receive_batch_multipart(
                         let content_type = field.content_type().map(ToString::to_string);
                         let mut file = tempfile::tempfile().map_err(ParseRequestError::Io)?;
                         while let Some(chunk) = field.chunk().await.unwrap() {
-                            file.write(&chunk).map_err(ParseRequestError::Io)?;
+                            file.write_all(&chunk).map_err(ParseRequestError::Io)?;
                         }
                         file.seek(SeekFrom::Start(0))?;
                         files.push((name, filename, content_type, file));
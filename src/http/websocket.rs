@@ -12,7 +12,9 @@ use futures_util::FutureExt;
 use pin_project_lite::pin_project;
 use serde::{Deserialize, Serialize};
 
-use crate::{Data, Error, ObjectType, Request, Response, Result, Schema, SubscriptionType};
+use crate::{
+    Data, Error, ObjectType, Request, Response, Result, Schema, ServerError, SubscriptionType,
+};
 
 /// An enum representing the various forms of a WebSocket message.
 #[derive(Clone, Debug)]
@@ -174,6 +176,22 @@ where
                         id,
                         payload: request,
                     } => {
+                        if let Some(max_subscriptions) = this.schema.max_subscriptions {
+                            if !this.streams.contains_key(&id)
+                                && this.streams.len() >= max_subscriptions
+                            {
+                                return Poll::Ready(Some(WsMessage::Text(
+                                    serde_json::to_string(&ServerMessage::Error {
+                                        id: &id,
+                                        payload: vec![ServerError::new(format!(
+                                            "Too many subscriptions, the limit is {}.",
+                                            max_subscriptions
+                                        ))],
+                                    })
+                                    .unwrap(),
+                                )));
+                            }
+                        }
                         this.streams.insert(
                             id,
                             Box::pin(
@@ -329,11 +347,10 @@ enum ServerMessage<'a> {
         id: &'a str,
         payload: Box<Response>,
     },
-    // Not used by this library, as it's not necessary to send
-    // Error {
-    //     id: &'a str,
-    //     payload: serde_json::Value,
-    // },
+    Error {
+        id: &'a str,
+        payload: Vec<ServerError>,
+    },
     Complete {
         id: &'a str,
     },
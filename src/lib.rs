@@ -74,10 +74,14 @@
 //! - `bson`: Integrate with the [`bson` crate](https://crates.io/crates/bson).
 //! - `chrono`: Integrate with the [`chrono` crate](https://crates.io/crates/chrono).
 //! - `chrono-tz`: Integrate with the [`chrono-tz` crate](https://crates.io/crates/chrono-tz).
+//! - `smol_str`: Integrate with the [`smol_str` crate](https://crates.io/crates/smol_str).
+//! - `smallvec`: Integrate with the [`smallvec` crate](https://crates.io/crates/smallvec).
 //! - `url`: Integrate with the [`url` crate](https://crates.io/crates/url).
 //! - `uuid`: Integrate with the [`uuid` crate](https://crates.io/crates/uuid).
 //! - `string_number`: Enable the [StringNumber](types/struct.StringNumber.html).
 //! - `dataloader`: Support [DataLoader](dataloader/struct.DataLoader.html).
+//! - `unicode-segmentation`: Enable the [StringGraphemes](validators/struct.StringGraphemes.html) validator.
+//! - `arbitrary_precision`: Keep the exact digits of numbers that don't fit in an `i64`, `u64` or `f64`.
 //!
 //! ## Integrations
 //!
@@ -181,6 +185,7 @@ pub mod context;
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "dataloader")))]
 pub mod dataloader;
 pub mod extensions;
+pub mod field_cache;
 pub mod guard;
 pub mod http;
 pub mod resolver_utils;
@@ -208,17 +213,18 @@ pub use subscription::SubscriptionType;
 pub use async_graphql_parser as parser;
 pub use async_graphql_value::{
     from_value, to_value, value, ConstValue as Value, DeserializerError, Name, Number,
-    SerializerError,
+    RawJsonValue, SerializerError,
 };
 pub use base::{
-    Description, InputObjectType, InputType, InterfaceType, ObjectType, OutputType, Type, UnionType,
+    ComplexObject, Description, InputObjectType, InputType, InterfaceType, ObjectType, OutputType,
+    Type, UnionType,
 };
 pub use error::{
     Error, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
     ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult,
 };
 pub use look_ahead::Lookahead;
-pub use registry::CacheControl;
+pub use registry::{CacheControl, SchemaChange};
 pub use request::{BatchRequest, Request};
 #[doc(no_inline)]
 pub use resolver_utils::{ContainerType, EnumType, ScalarType};
@@ -267,6 +273,8 @@ pub type FieldResult<T> = Result<T>;
 /// | desc          | Field description         | string   | Y        |
 /// | deprecation   | Field deprecation reason  | string   | Y        |
 /// | cache_control | Field cache control       | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | cached        | Store the result in the [field cache](field_cache/index.html) for the `max_age` of the cache control of the request. | bool | Y |
+/// | cache_key     | Expression that identifies the parent object of a cached field, required when the field is not at the top level of the query. | string | Y |
 /// | external      | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
 /// | provides      | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
@@ -284,11 +292,13 @@ pub type FieldResult<T> = Result<T>;
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | validator_async | Async input value validator, it can access the context | [`AsyncInputValueValidator`](validators/trait.AsyncInputValueValidator.html) | Y        |
 /// | complexity   | Custom field complexity. *[See also the Book](https://async-graphql.github.io/async-graphql/en/depth_and_complexity.html).*                 | bool        | Y        |
 /// | complexity   | Custom field complexity.                 | string      | Y        |
 /// | visible      | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible      | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
 /// | key          | Is entity key(for Federation)            | bool        | Y        |
+/// | secret       | The value is replaced by `"[redacted]"` in the query and the variables passed to extensions such as `Logger` and `Tracing`, but not in error messages. | bool | Y |
 ///
 /// # Valid field return types
 ///
@@ -416,6 +426,36 @@ pub type FieldResult<T> = Result<T>;
 ///     }));
 /// });
 /// ```
+///
+/// Names beginning with `__` are reserved for introspection, so using one for a type, a field or
+/// an argument is a compile error, even when it is given with `name`:
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[graphql(name = "__value")]
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn value(&self, #[graphql(name = "__n")] n: i32) -> i32 {
+///         n
+///     }
+/// }
+/// ```
 pub use async_graphql_derive::Object;
 
 /// Define a GraphQL object with fields
@@ -434,6 +474,7 @@ pub use async_graphql_derive::Object;
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
 /// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+/// | complex       | Add the fields defined in a [`ComplexObject`](attr.ComplexObject.html) impl | bool | Y |
 ///
 /// # Field parameters
 ///
@@ -450,6 +491,8 @@ pub use async_graphql_derive::Object;
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
 /// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+/// | complexity    | Custom field complexity, the expression can only use `child_complexity`. *[See also the Book](https://async-graphql.github.io/async-graphql/en/depth_and_complexity.html).* | int or string | Y |
+/// | flatten       | Add the fields of another `SimpleObject` or `Object` to this object in place of this field, instead of adding a field of that type. The fields of the other type are only known when the schema is built, so a name collision with them panics then, while two fields of this struct with the same name are a compile error. | bool | Y |
 ///
 /// # Examples
 ///
@@ -469,8 +512,96 @@ pub use async_graphql_derive::Object;
 ///     }));
 /// });
 /// ```
+///
+/// Two fields with the same name are a compile error:
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct QueryRoot {
+///     value: i32,
+///     #[graphql(name = "value")]
+///     other_value: i32,
+/// }
+/// ```
 pub use async_graphql_derive::SimpleObject;
 
+/// Define additional resolver fields for a `SimpleObject`
+///
+/// *[See also the Book](https://async-graphql.github.io/async-graphql/en/define_simple_object.html).*
+///
+/// The `SimpleObject` must be marked with `#[graphql(complex)]`. The fields are defined like those of
+/// an [`Object`](attr.Object.html), and are resolved before the fields of the struct. A field with
+/// the same name as a field of the struct panics when the schema is built, while two methods with
+/// the same name are a compile error.
+///
+/// # Macro parameters
+///
+/// | Attribute     | description               | Type     | Optional |
+/// |---------------|---------------------------|----------|----------|
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+///
+/// # Field parameters
+///
+/// The same as the field parameters of [`Object`](attr.Object.html), except `entity`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// #[graphql(complex)]
+/// struct QueryRoot {
+///     a: i32,
+///     b: i32,
+/// }
+///
+/// #[ComplexObject]
+/// impl QueryRoot {
+///     async fn sum(&self) -> i32 {
+///         self.a + self.b
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot{ a: 10, b: 20 }, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ a b sum }").await.into_result().unwrap().data;
+///     assert_eq!(res, value!({
+///         "a": 10,
+///         "b": 20,
+///         "sum": 30,
+///     }));
+/// });
+/// ```
+///
+/// Two fields with the same name are a compile error:
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// #[graphql(complex)]
+/// struct QueryRoot {
+///     a: i32,
+/// }
+///
+/// #[ComplexObject]
+/// impl QueryRoot {
+///     async fn sum(&self) -> i32 {
+///         self.a
+///     }
+///
+///     #[graphql(name = "sum")]
+///     async fn other_sum(&self) -> i32 {
+///         self.a
+///     }
+/// }
+/// ```
+pub use async_graphql_derive::ComplexObject;
+
 /// Define a GraphQL enum
 ///
 /// *[See also the Book](https://async-graphql.github.io/async-graphql/en/define_enum.html).*
@@ -543,6 +674,8 @@ pub use async_graphql_derive::Enum;
 /// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+/// | one_of        | Exactly one field must be set to a non-null value, all the fields must be nullable. | bool | Y |
+/// | validator     | Function that validates the whole input object after all the fields are parsed, with the signature `fn(&Self) -> Result<(), String>`. | string | Y |
 ///
 /// # Field parameters
 ///
@@ -552,11 +685,14 @@ pub use async_graphql_derive::Enum;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | from_fields | The `default_with` expression refers to other fields of the input object. It is used when the field is missing or `null`, and the field is nullable and has no default value in the schema. | bool | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
 /// | flatten      | Similar to serde (flatten)               | boolean     | Y        |
 /// | skip         | Skip this field, use `Default::default` to get a default value for this field. | bool     | Y        |
 /// | visible      | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible      | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+/// | alias        | Another name the field is also accepted under, it will not be displayed in introspection. Can be specified multiple times. | string | Y |
+/// | secret       | The value is replaced by `"[redacted]"` in the query and the variables passed to extensions such as `Logger` and `Tracing`, but not in error messages. | bool | Y |
 ///
 /// # Examples
 ///
@@ -634,6 +770,7 @@ pub use async_graphql_derive::InputObject;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
 /// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
 ///
@@ -732,6 +869,100 @@ pub use async_graphql_derive::InputObject;
 /// ```
 pub use async_graphql_derive::Interface;
 
+/// Define a GraphQL interface whose concrete type is computed at runtime
+///
+/// Unlike [`Interface`](derive.Interface.html), the implementations don't have to be wrapped in
+/// an enum. The macro is applied to an impl block of the type holding the values, which must
+/// define the discriminator `fn type_name(&self) -> &str`, returning the name of the concrete
+/// object type of the value. The holder then implements [`ContainerType`](trait.ContainerType.html)
+/// itself, resolving the fields of the interface as well as the fields of the concrete types that
+/// are selected with fragments.
+///
+/// Because the inherent `type_name` takes precedence, use `<T as Type>::type_name()` to get the
+/// name of the interface itself.
+///
+/// # Macro parameters
+///
+/// | Attribute     | description               | Type     | Optional |
+/// |---------------|---------------------------|----------|----------|
+/// | name          | Object name               | string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | field         | Fields of this Interface, with the same parameters as for [`Interface`](derive.Interface.html) | [InterfaceField] | N |
+/// | possible_type | An object type implementing this interface. Can be specified multiple times. | string | Y |
+/// | extends       | Add fields to an entity that's defined in another service | bool | Y |
+/// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
+/// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct Circle {
+///     area: f32,
+///     radius: f32,
+/// }
+///
+/// #[derive(SimpleObject)]
+/// struct Square {
+///     area: f32,
+///     width: f32,
+/// }
+///
+/// struct Shape {
+///     kind: &'static str,
+///     values: BTreeMap<&'static str, f32>,
+/// }
+///
+/// #[InterfaceImpl(
+///     field(name = "area", type = "f32"),
+///     possible_type = "Circle",
+///     possible_type = "Square",
+/// )]
+/// impl Shape {
+///     fn type_name(&self) -> &str {
+///         self.kind
+///     }
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl ContainerType for Shape {
+///     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+///         Ok(self.values.get(ctx.item.node.name.node.as_str()).map(|value| Value::from(*value)))
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn shape(&self) -> Shape {
+///         let mut values = BTreeMap::new();
+///         values.insert("area", 4.0);
+///         values.insert("width", 2.0);
+///         Shape { kind: "Square", values }
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema
+///         .execute("{ shape { __typename area ... on Square { width } } }")
+///         .await
+///         .into_result()
+///         .unwrap()
+///         .data;
+///     assert_eq!(res, value!({
+///         "shape": { "__typename": "Square", "area": 4.0, "width": 2.0 }
+///     }));
+/// });
+/// ```
+pub use async_graphql_derive::InterfaceImpl;
+
 /// Define a GraphQL union
 ///
 /// *[See also the Book](https://async-graphql.github.io/async-graphql/en/define_union.html).*
@@ -843,8 +1074,10 @@ pub use async_graphql_derive::Union;
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | validator_async | Async input value validator, it can access the context | [`AsyncInputValueValidator`](validators/trait.AsyncInputValueValidator.html) | Y        |
 /// | visible       | If `false`, it will not be displayed in introspection. *[See also the Book](https://async-graphql.github.io/async-graphql/en/visibility.html).* | bool | Y |
 /// | visible       | Call the specified function. If the return value is `false`, it will not be displayed in introspection. | string | Y |
+/// | secret        | The value is replaced by `"[redacted]"` in the query and the variables passed to extensions such as `Logger` and `Tracing`, but not in error messages. | bool | Y |
 ///
 /// # Examples
 ///
@@ -871,7 +1104,30 @@ pub use async_graphql_derive::Subscription;
 /// | Attribute   | description               | Type     | Optional |
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Scalar name               | string   | Y        |
+/// | specified_by_url | URL of the specification of this scalar, exposed as `specifiedByURL` in introspection | string | Y |
+/// | accept      | The kinds of input values the scalar accepts, any of `String`, `Int`, `Float`, `Boolean`, `Enum`, `List` and `Object`. Other values are rejected before `ScalarType::parse` is called, and during validation, where `ScalarType::is_valid` must also accept them | list | Y |
 ///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Code(String);
+///
+/// #[Scalar(accept(String, Int))]
+/// impl ScalarType for Code {
+///     fn parse(value: Value) -> InputValueResult<Self> {
+///         match value {
+///             Value::String(s) => Ok(Code(s)),
+///             value => Ok(Code(value.to_string())),
+///         }
+///     }
+///
+///     fn to_value(&self) -> Value {
+///         Value::String(self.0.clone())
+///     }
+/// }
+/// ```
 pub use async_graphql_derive::Scalar;
 
 /// Define a NewType Scalar
@@ -1,21 +1,18 @@
 use std::collections::HashMap;
 
 use crate::parser::types::{Field, FragmentDefinition, Selection, SelectionSet};
-use crate::{Name, Positioned};
+use crate::{Name, Positioned, QueryEnv, SelectionField, Value};
 
 /// A selection performed by a query.
 pub struct Lookahead<'a> {
-    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+    query_env: &'a QueryEnv,
     field: Option<&'a Field>,
 }
 
 impl<'a> Lookahead<'a> {
-    pub(crate) fn new(
-        fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
-        field: &'a Field,
-    ) -> Self {
+    pub(crate) fn new(query_env: &'a QueryEnv, field: &'a Field) -> Self {
         Self {
-            fragments,
+            query_env,
             field: Some(field),
         }
     }
@@ -27,18 +24,51 @@ impl<'a> Lookahead<'a> {
     /// represents `{ b }`.
     pub fn field(&self, name: &str) -> Self {
         Self {
-            fragments: self.fragments,
+            query_env: self.query_env,
             field: self
                 .field
-                .and_then(|field| find(self.fragments, &field.selection_set.node, name)),
+                .and_then(|field| find(&self.query_env.fragments, &field.selection_set.node, name)),
         }
     }
 
+    /// Get the value of an argument of the selected field, with the variables resolved.
+    ///
+    /// Returns `None` if the field does not exist, or the argument was not passed in the query. The
+    /// default value of the argument is not taken into account.
+    ///
+    /// For example, calling `.field("items").argument("first")` on `{ items(first: 10) { id } }`
+    /// will return `10`.
+    pub fn argument(&self, name: &str) -> Option<Value> {
+        let value = self.field?.get_argument(name)?;
+        let pos = value.pos;
+        value
+            .node
+            .clone()
+            .into_const_with(|var_name| self.query_env.var_value(&var_name, pos))
+            .ok()
+    }
+
     /// Returns true if field exists otherwise return false.
     #[inline]
     pub fn exists(&self) -> bool {
         self.field.is_some()
     }
+
+    /// Get the fields selected on this field, flattening the fragments.
+    ///
+    /// Each [`SelectionField`](struct.SelectionField.html) exposes its name, alias, arguments and
+    /// its own selected fields, so the whole selection can be walked to build a single query for
+    /// a data source. It is empty if the field does not exist.
+    ///
+    /// For example, calling `.field("obj").selection_fields()` on `{ obj { a ... { b } } }` will
+    /// return the fields `a` and `b`.
+    pub fn selection_fields(&self) -> impl Iterator<Item = SelectionField<'a>> {
+        let query_env = self.query_env;
+        self.field
+            .map(move |field| SelectionField::new(query_env, field).selection_set())
+            .into_iter()
+            .flatten()
+    }
 }
 
 fn find<'a>(
@@ -68,6 +98,8 @@ fn find<'a>(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use crate::*;
 
     #[async_std::test]
@@ -218,4 +250,150 @@ mod tests {
             .await
             .is_ok());
     }
+
+    #[async_std::test]
+    async fn test_look_ahead_selection_fields() {
+        fn tree<'a>(fields: impl Iterator<Item = SelectionField<'a>>) -> Value {
+            Value::Object(
+                fields
+                    .map(|field| {
+                        let mut node = BTreeMap::new();
+                        if let Some(alias) = field.alias() {
+                            node.insert(Name::from_static("alias"), Value::from(alias));
+                        }
+                        let arguments = field.arguments().unwrap();
+                        if !arguments.is_empty() {
+                            node.insert(
+                                Name::from_static("arguments"),
+                                Value::Object(arguments.into_iter().collect()),
+                            );
+                        }
+                        node.insert(Name::from_static("fields"), tree(field.selection_set()));
+                        (Name::new(field.name()), Value::Object(node))
+                    })
+                    .collect(),
+            )
+        }
+
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Detail {
+            d: i32,
+        }
+
+        struct MyObj;
+
+        #[Object(internal)]
+        impl MyObj {
+            async fn a(&self) -> i32 {
+                1
+            }
+
+            async fn b(&self, n: i32) -> i32 {
+                n
+            }
+
+            async fn c(&self) -> Detail {
+                Detail { d: 2 }
+            }
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn obj(&self, ctx: &Context<'_>) -> MyObj {
+                assert_eq!(
+                    ctx.look_ahead().field("missing").selection_fields().count(),
+                    0
+                );
+                assert_eq!(
+                    tree(ctx.look_ahead().selection_fields()),
+                    value!({
+                        "a": { "fields": {} },
+                        "b": { "alias": "x", "arguments": { "n": 5 }, "fields": {} },
+                        "c": { "fields": { "d": { "fields": {} } } },
+                    })
+                );
+                assert_eq!(
+                    tree(ctx.look_ahead().field("c").selection_fields()),
+                    value!({ "d": { "fields": {} } })
+                );
+                MyObj
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let request = Request::new(
+            "query($n: Int!) { obj { a x: b(n: $n) ... C } } fragment C on MyObj { c { d } }",
+        )
+        .variables(Variables::from_value(value!({ "n": 5 })));
+        assert_eq!(
+            schema.execute(request).await.into_result().unwrap().data,
+            value!({ "obj": { "a": 1, "x": 5, "c": { "d": 2 } } })
+        );
+    }
+
+    #[async_std::test]
+    async fn test_look_ahead_argument() {
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Item {
+            id: i32,
+        }
+
+        struct MyObj {
+            prefetched: usize,
+        }
+
+        #[Object(internal)]
+        impl MyObj {
+            async fn items(&self, first: usize) -> Vec<Item> {
+                assert_eq!(self.prefetched, first);
+                (0..first as i32).map(|id| Item { id }).collect()
+            }
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn obj(&self, ctx: &Context<'_>) -> MyObj {
+                let first = ctx.look_ahead().field("items").argument("first");
+                assert_eq!(ctx.look_ahead().field("items").argument("last"), None);
+                assert_eq!(ctx.look_ahead().field("missing").argument("first"), None);
+                MyObj {
+                    prefetched: match first {
+                        Some(Value::Number(n)) => n.as_u64().unwrap() as usize,
+                        _ => 0,
+                    },
+                }
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute("{ obj { items(first: 3) { id } } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "obj": { "items": [{ "id": 0 }, { "id": 1 }, { "id": 2 }] } })
+        );
+
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new("query($n: Int!) { obj { ... { items(first: $n) { id } } } }")
+                        .variables(Variables::from_value(value!({ "n": 2 }))),
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "obj": { "items": [{ "id": 0 }, { "id": 1 }] } })
+        );
+    }
 }
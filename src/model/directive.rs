@@ -1,5 +1,5 @@
 use crate::model::__InputValue;
-use crate::{registry, Enum, Object};
+use crate::{registry, Context, Enum, Object};
 
 /// A Directive can be adjacent to many parts of the GraphQL language, a __DirectiveLocation describes one such possible adjacencies.
 #[derive(Debug, Enum, Copy, Clone, Eq, PartialEq)]
@@ -86,10 +86,14 @@ impl<'a> __Directive<'a> {
         &self.directive.locations
     }
 
-    async fn args(&self) -> Vec<__InputValue<'a>> {
+    async fn args(&self, ctx: &Context<'_>) -> Vec<__InputValue<'a>> {
         self.directive
             .args
             .values()
+            .filter(|input_value| match &input_value.visible {
+                Some(f) => f(ctx),
+                None => true,
+            })
             .map(|input_value| __InputValue {
                 registry: self.registry,
                 input_value,
@@ -36,6 +36,19 @@ impl<'a> __Type<'a> {
             },
         }
     }
+
+    fn visible_types<'b>(
+        &self,
+        ctx: &Context<'_>,
+        names: impl IntoIterator<Item = &'b String>,
+    ) -> Vec<__Type<'a>> {
+        names
+            .into_iter()
+            .filter_map(|name| self.registry.types.get(name))
+            .filter(|ty| ty.is_visible(ctx))
+            .map(|ty| __Type::new_simple(self.registry, ty))
+            .collect()
+    }
 }
 
 /// The fundamental unit of any GraphQL Schema is the type. There are many kinds of types in GraphQL as represented by the `__TypeKind` enum.
@@ -109,43 +122,24 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn interfaces(&self) -> Option<Vec<__Type<'a>>> {
+    async fn interfaces(&self, ctx: &Context<'_>) -> Option<Vec<__Type<'a>>> {
         if let TypeDetail::Named(registry::MetaType::Object { name, .. }) = &self.detail {
-            Some(
-                self.registry
-                    .implements
-                    .get(name)
-                    .unwrap_or(&Default::default())
-                    .iter()
-                    .map(|ty| __Type::new(self.registry, ty))
-                    .collect(),
-            )
+            Some(self.visible_types(
+                ctx,
+                self.registry.implements.get(name).into_iter().flatten(),
+            ))
         } else {
             None
         }
     }
 
-    async fn possible_types(&self) -> Option<Vec<__Type<'a>>> {
-        if let TypeDetail::Named(registry::MetaType::Interface { possible_types, .. }) =
-            &self.detail
-        {
-            Some(
-                possible_types
-                    .iter()
-                    .map(|ty| __Type::new(self.registry, ty))
-                    .collect(),
-            )
-        } else if let TypeDetail::Named(registry::MetaType::Union { possible_types, .. }) =
-            &self.detail
-        {
-            Some(
-                possible_types
-                    .iter()
-                    .map(|ty| __Type::new(self.registry, ty))
-                    .collect(),
-            )
-        } else {
-            None
+    async fn possible_types(&self, ctx: &Context<'_>) -> Option<Vec<__Type<'a>>> {
+        match &self.detail {
+            TypeDetail::Named(registry::MetaType::Interface { possible_types, .. })
+            | TypeDetail::Named(registry::MetaType::Union { possible_types, .. }) => {
+                Some(self.visible_types(ctx, possible_types))
+            }
+            _ => None,
         }
     }
 
@@ -196,6 +190,18 @@ impl<'a> __Type<'a> {
         }
     }
 
+    #[graphql(name = "specifiedByURL")]
+    async fn specified_by_url(&self) -> Option<&'a str> {
+        if let TypeDetail::Named(registry::MetaType::Scalar {
+            specified_by_url, ..
+        }) = &self.detail
+        {
+            *specified_by_url
+        } else {
+            None
+        }
+    }
+
     async fn of_type(&self) -> Option<__Type<'a>> {
         if let TypeDetail::List(ty) = &self.detail {
             Some(__Type::new(self.registry, &ty))
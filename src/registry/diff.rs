@@ -0,0 +1,615 @@
+use std::fmt::{self, Display, Formatter};
+
+use indexmap::map::IndexMap;
+
+use crate::registry::{MetaField, MetaInputValue, MetaType, MetaTypeName, Registry};
+
+/// A change between two schemas, see [`Schema::diff`](struct.Schema.html#method.diff).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChange {
+    /// A type was added.
+    TypeAdded {
+        /// The name of the type.
+        name: String,
+    },
+    /// A type was removed.
+    TypeRemoved {
+        /// The name of the type.
+        name: String,
+    },
+    /// A type changed its kind, for example from an object to an interface.
+    TypeKindChanged {
+        /// The name of the type.
+        name: String,
+    },
+    /// A field was added to an object or interface.
+    FieldAdded {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+    },
+    /// A field was removed from an object or interface.
+    FieldRemoved {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+    },
+    /// The type of a field of an object or interface changed.
+    FieldTypeChanged {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The previous type of the field.
+        old_type: String,
+        /// The new type of the field.
+        new_type: String,
+    },
+    /// An argument was added to a field.
+    ArgumentAdded {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The name of the argument.
+        argument: String,
+        /// Whether the argument is non-null without a default value.
+        required: bool,
+    },
+    /// An argument was removed from a field.
+    ArgumentRemoved {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The name of the argument.
+        argument: String,
+    },
+    /// The type of an argument of a field changed.
+    ArgumentTypeChanged {
+        /// The name of the object or interface.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The name of the argument.
+        argument: String,
+        /// The previous type of the argument.
+        old_type: String,
+        /// The new type of the argument.
+        new_type: String,
+    },
+    /// A field was added to an input object.
+    InputFieldAdded {
+        /// The name of the input object.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// Whether the field is non-null without a default value.
+        required: bool,
+    },
+    /// A field was removed from an input object.
+    InputFieldRemoved {
+        /// The name of the input object.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+    },
+    /// The type of a field of an input object changed.
+    InputFieldTypeChanged {
+        /// The name of the input object.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The previous type of the field.
+        old_type: String,
+        /// The new type of the field.
+        new_type: String,
+    },
+    /// A value was added to an enum.
+    EnumValueAdded {
+        /// The name of the enum.
+        type_name: String,
+        /// The enum value.
+        value: String,
+    },
+    /// A value was removed from an enum.
+    EnumValueRemoved {
+        /// The name of the enum.
+        type_name: String,
+        /// The enum value.
+        value: String,
+    },
+    /// An object was added to a union.
+    PossibleTypeAdded {
+        /// The name of the union.
+        type_name: String,
+        /// The name of the object.
+        possible_type: String,
+    },
+    /// An object was removed from a union.
+    PossibleTypeRemoved {
+        /// The name of the union.
+        type_name: String,
+        /// The name of the object.
+        possible_type: String,
+    },
+}
+
+impl SchemaChange {
+    /// Returns true if the change can break existing clients.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            SchemaChange::TypeAdded { .. }
+            | SchemaChange::FieldAdded { .. }
+            | SchemaChange::EnumValueAdded { .. }
+            | SchemaChange::PossibleTypeAdded { .. } => false,
+            SchemaChange::TypeRemoved { .. }
+            | SchemaChange::TypeKindChanged { .. }
+            | SchemaChange::FieldRemoved { .. }
+            | SchemaChange::ArgumentRemoved { .. }
+            | SchemaChange::InputFieldRemoved { .. }
+            | SchemaChange::EnumValueRemoved { .. }
+            | SchemaChange::PossibleTypeRemoved { .. } => true,
+            SchemaChange::ArgumentAdded { required, .. }
+            | SchemaChange::InputFieldAdded { required, .. } => *required,
+            // An output type can only become stricter.
+            SchemaChange::FieldTypeChanged {
+                old_type, new_type, ..
+            } => !MetaTypeName::create(old_type).is_subtype(&MetaTypeName::create(new_type)),
+            // An input type can only become looser.
+            SchemaChange::ArgumentTypeChanged {
+                old_type, new_type, ..
+            }
+            | SchemaChange::InputFieldTypeChanged {
+                old_type, new_type, ..
+            } => !MetaTypeName::create(new_type).is_subtype(&MetaTypeName::create(old_type)),
+        }
+    }
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaChange::TypeAdded { name } => write!(f, "Type \"{}\" was added", name),
+            SchemaChange::TypeRemoved { name } => write!(f, "Type \"{}\" was removed", name),
+            SchemaChange::TypeKindChanged { name } => {
+                write!(f, "Type \"{}\" changed its kind", name)
+            }
+            SchemaChange::FieldAdded { type_name, field } => {
+                write!(f, "Field \"{}.{}\" was added", type_name, field)
+            }
+            SchemaChange::FieldRemoved { type_name, field } => {
+                write!(f, "Field \"{}.{}\" was removed", type_name, field)
+            }
+            SchemaChange::FieldTypeChanged {
+                type_name,
+                field,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "Field \"{}.{}\" changed type from \"{}\" to \"{}\"",
+                type_name, field, old_type, new_type
+            ),
+            SchemaChange::ArgumentAdded {
+                type_name,
+                field,
+                argument,
+                ..
+            } => write!(
+                f,
+                "Argument \"{}\" was added to field \"{}.{}\"",
+                argument, type_name, field
+            ),
+            SchemaChange::ArgumentRemoved {
+                type_name,
+                field,
+                argument,
+            } => write!(
+                f,
+                "Argument \"{}\" was removed from field \"{}.{}\"",
+                argument, type_name, field
+            ),
+            SchemaChange::ArgumentTypeChanged {
+                type_name,
+                field,
+                argument,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "Argument \"{}\" of field \"{}.{}\" changed type from \"{}\" to \"{}\"",
+                argument, type_name, field, old_type, new_type
+            ),
+            SchemaChange::InputFieldAdded {
+                type_name, field, ..
+            } => write!(f, "Input field \"{}.{}\" was added", type_name, field),
+            SchemaChange::InputFieldRemoved { type_name, field } => {
+                write!(f, "Input field \"{}.{}\" was removed", type_name, field)
+            }
+            SchemaChange::InputFieldTypeChanged {
+                type_name,
+                field,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "Input field \"{}.{}\" changed type from \"{}\" to \"{}\"",
+                type_name, field, old_type, new_type
+            ),
+            SchemaChange::EnumValueAdded { type_name, value } => {
+                write!(f, "Enum value \"{}.{}\" was added", type_name, value)
+            }
+            SchemaChange::EnumValueRemoved { type_name, value } => {
+                write!(f, "Enum value \"{}.{}\" was removed", type_name, value)
+            }
+            SchemaChange::PossibleTypeAdded {
+                type_name,
+                possible_type,
+            } => write!(
+                f,
+                "Type \"{}\" was added to union \"{}\"",
+                possible_type, type_name
+            ),
+            SchemaChange::PossibleTypeRemoved {
+                type_name,
+                possible_type,
+            } => write!(
+                f,
+                "Type \"{}\" was removed from union \"{}\"",
+                possible_type, type_name
+            ),
+        }
+    }
+}
+
+fn is_required(input_value: &MetaInputValue) -> bool {
+    MetaTypeName::create(&input_value.ty).is_non_null() && input_value.default_value.is_none()
+}
+
+impl Registry {
+    /// Compares this registry with a newer version of it, and returns the changes.
+    pub fn diff(&self, other: &Registry) -> Vec<SchemaChange> {
+        let mut changes = Vec::new();
+
+        for (name, old_ty) in &self.types {
+            match other.types.get(name) {
+                Some(new_ty) => diff_type(name, old_ty, new_ty, &mut changes),
+                None => changes.push(SchemaChange::TypeRemoved { name: name.clone() }),
+            }
+        }
+        for name in other.types.keys() {
+            if !self.types.contains_key(name) {
+                changes.push(SchemaChange::TypeAdded { name: name.clone() });
+            }
+        }
+
+        changes
+    }
+}
+
+fn diff_type(name: &str, old_ty: &MetaType, new_ty: &MetaType, changes: &mut Vec<SchemaChange>) {
+    match (old_ty, new_ty) {
+        (MetaType::Scalar { .. }, MetaType::Scalar { .. }) => {}
+        (
+            MetaType::Object {
+                fields: old_fields, ..
+            },
+            MetaType::Object {
+                fields: new_fields, ..
+            },
+        )
+        | (
+            MetaType::Interface {
+                fields: old_fields, ..
+            },
+            MetaType::Interface {
+                fields: new_fields, ..
+            },
+        ) => diff_fields(name, old_fields, new_fields, changes),
+        (
+            MetaType::Union {
+                possible_types: old_types,
+                ..
+            },
+            MetaType::Union {
+                possible_types: new_types,
+                ..
+            },
+        ) => {
+            for possible_type in old_types.difference(new_types) {
+                changes.push(SchemaChange::PossibleTypeRemoved {
+                    type_name: name.to_string(),
+                    possible_type: possible_type.clone(),
+                });
+            }
+            for possible_type in new_types.difference(old_types) {
+                changes.push(SchemaChange::PossibleTypeAdded {
+                    type_name: name.to_string(),
+                    possible_type: possible_type.clone(),
+                });
+            }
+        }
+        (
+            MetaType::Enum {
+                enum_values: old_values,
+                ..
+            },
+            MetaType::Enum {
+                enum_values: new_values,
+                ..
+            },
+        ) => {
+            for value in old_values.keys() {
+                if !new_values.contains_key(value) {
+                    changes.push(SchemaChange::EnumValueRemoved {
+                        type_name: name.to_string(),
+                        value: value.to_string(),
+                    });
+                }
+            }
+            for value in new_values.keys() {
+                if !old_values.contains_key(value) {
+                    changes.push(SchemaChange::EnumValueAdded {
+                        type_name: name.to_string(),
+                        value: value.to_string(),
+                    });
+                }
+            }
+        }
+        (
+            MetaType::InputObject {
+                input_fields: old_fields,
+                ..
+            },
+            MetaType::InputObject {
+                input_fields: new_fields,
+                ..
+            },
+        ) => {
+            for (field, old_field) in old_fields {
+                match new_fields.get(field) {
+                    Some(new_field) if new_field.ty != old_field.ty => {
+                        changes.push(SchemaChange::InputFieldTypeChanged {
+                            type_name: name.to_string(),
+                            field: field.clone(),
+                            old_type: old_field.ty.clone(),
+                            new_type: new_field.ty.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                    None => changes.push(SchemaChange::InputFieldRemoved {
+                        type_name: name.to_string(),
+                        field: field.clone(),
+                    }),
+                }
+            }
+            for (field, new_field) in new_fields {
+                if !old_fields.contains_key(field) {
+                    changes.push(SchemaChange::InputFieldAdded {
+                        type_name: name.to_string(),
+                        field: field.clone(),
+                        required: is_required(new_field),
+                    });
+                }
+            }
+        }
+        _ => changes.push(SchemaChange::TypeKindChanged {
+            name: name.to_string(),
+        }),
+    }
+}
+
+fn diff_fields(
+    type_name: &str,
+    old_fields: &IndexMap<String, MetaField>,
+    new_fields: &IndexMap<String, MetaField>,
+    changes: &mut Vec<SchemaChange>,
+) {
+    for (field, old_field) in old_fields {
+        let new_field = match new_fields.get(field) {
+            Some(new_field) => new_field,
+            None => {
+                changes.push(SchemaChange::FieldRemoved {
+                    type_name: type_name.to_string(),
+                    field: field.clone(),
+                });
+                continue;
+            }
+        };
+
+        if new_field.ty != old_field.ty {
+            changes.push(SchemaChange::FieldTypeChanged {
+                type_name: type_name.to_string(),
+                field: field.clone(),
+                old_type: old_field.ty.clone(),
+                new_type: new_field.ty.clone(),
+            });
+        }
+
+        for (argument, old_arg) in &old_field.args {
+            match new_field.args.get(argument) {
+                Some(new_arg) if new_arg.ty != old_arg.ty => {
+                    changes.push(SchemaChange::ArgumentTypeChanged {
+                        type_name: type_name.to_string(),
+                        field: field.clone(),
+                        argument: argument.to_string(),
+                        old_type: old_arg.ty.clone(),
+                        new_type: new_arg.ty.clone(),
+                    })
+                }
+                Some(_) => {}
+                None => changes.push(SchemaChange::ArgumentRemoved {
+                    type_name: type_name.to_string(),
+                    field: field.clone(),
+                    argument: argument.to_string(),
+                }),
+            }
+        }
+        for (argument, new_arg) in &new_field.args {
+            if !old_field.args.contains_key(argument) {
+                changes.push(SchemaChange::ArgumentAdded {
+                    type_name: type_name.to_string(),
+                    field: field.clone(),
+                    argument: argument.to_string(),
+                    required: is_required(new_arg),
+                });
+            }
+        }
+    }
+
+    for field in new_fields.keys() {
+        if !old_fields.contains_key(field) {
+            changes.push(SchemaChange::FieldAdded {
+                type_name: type_name.to_string(),
+                field: field.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::SchemaChange;
+    use crate::*;
+
+    #[test]
+    fn test_diff() {
+        mod v1 {
+            use crate::*;
+
+            #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+            #[graphql(internal)]
+            pub enum Color {
+                Red,
+                Green,
+            }
+
+            pub struct Query;
+
+            #[Object(internal)]
+            #[allow(unreachable_code)]
+            impl Query {
+                async fn users(&self) -> Vec<i32> {
+                    todo!()
+                }
+
+                async fn name(&self) -> String {
+                    todo!()
+                }
+
+                async fn color(&self, _id: i32) -> Color {
+                    todo!()
+                }
+
+                async fn value(&self, _a: i32) -> Option<i32> {
+                    todo!()
+                }
+            }
+        }
+
+        mod v2 {
+            use crate::*;
+
+            #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+            #[graphql(internal)]
+            pub enum Color {
+                Red,
+                Blue,
+            }
+
+            pub struct Query;
+
+            #[Object(internal)]
+            #[allow(unreachable_code)]
+            impl Query {
+                async fn users(&self, _limit: Option<i32>, _offset: i32) -> Vec<i32> {
+                    todo!()
+                }
+
+                async fn color(&self, _id: Option<i32>) -> Color {
+                    todo!()
+                }
+
+                async fn value(&self, _a: String) -> i32 {
+                    todo!()
+                }
+
+                async fn count(&self) -> i32 {
+                    todo!()
+                }
+            }
+        }
+
+        let old = Schema::new(v1::Query, EmptyMutation, EmptySubscription);
+        let new = Schema::new(v2::Query, EmptyMutation, EmptySubscription);
+        let changes = old.diff(&new);
+
+        assert_eq!(
+            changes,
+            vec![
+                SchemaChange::ArgumentAdded {
+                    type_name: "Query".to_string(),
+                    field: "users".to_string(),
+                    argument: "limit".to_string(),
+                    required: false,
+                },
+                SchemaChange::ArgumentAdded {
+                    type_name: "Query".to_string(),
+                    field: "users".to_string(),
+                    argument: "offset".to_string(),
+                    required: true,
+                },
+                SchemaChange::FieldRemoved {
+                    type_name: "Query".to_string(),
+                    field: "name".to_string(),
+                },
+                SchemaChange::ArgumentTypeChanged {
+                    type_name: "Query".to_string(),
+                    field: "color".to_string(),
+                    argument: "id".to_string(),
+                    old_type: "Int!".to_string(),
+                    new_type: "Int".to_string(),
+                },
+                SchemaChange::FieldTypeChanged {
+                    type_name: "Query".to_string(),
+                    field: "value".to_string(),
+                    old_type: "Int".to_string(),
+                    new_type: "Int!".to_string(),
+                },
+                SchemaChange::ArgumentTypeChanged {
+                    type_name: "Query".to_string(),
+                    field: "value".to_string(),
+                    argument: "a".to_string(),
+                    old_type: "Int!".to_string(),
+                    new_type: "String!".to_string(),
+                },
+                SchemaChange::FieldAdded {
+                    type_name: "Query".to_string(),
+                    field: "count".to_string(),
+                },
+                SchemaChange::EnumValueRemoved {
+                    type_name: "Color".to_string(),
+                    value: "GREEN".to_string(),
+                },
+                SchemaChange::EnumValueAdded {
+                    type_name: "Color".to_string(),
+                    value: "BLUE".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            changes
+                .iter()
+                .map(SchemaChange::is_breaking)
+                .collect::<Vec<_>>(),
+            vec![false, true, true, false, false, true, false, true, false]
+        );
+        assert_eq!(changes[2].to_string(), r#"Field "Query.name" was removed"#);
+
+        assert!(old.diff(&old).is_empty());
+    }
+}
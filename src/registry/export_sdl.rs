@@ -1,12 +1,16 @@
 use std::fmt::Write;
 
 use crate::registry::{MetaField, MetaInputValue, MetaType, Registry};
+use crate::Value;
 
 impl Registry {
     pub fn export_sdl(&self, federation: bool) -> String {
         let mut sdl = String::new();
 
-        for ty in self.types.values() {
+        let mut types = self.types.values().collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name().cmp(b.name()));
+
+        for ty in types {
             if ty.name().starts_with("__") {
                 continue;
             }
@@ -22,6 +26,8 @@ impl Registry {
         }
 
         if !federation {
+            self.export_directives(&mut sdl);
+
             writeln!(sdl, "schema {{").ok();
             writeln!(sdl, "\tquery: {}", self.query_type).ok();
             if let Some(mutation_type) = self.mutation_type.as_deref() {
@@ -48,15 +54,19 @@ impl Registry {
                 continue;
             }
 
-            if field.description.is_some() && !federation {
-                writeln!(
-                    sdl,
-                    "\t\"\"\"\n\t{}\n\t\"\"\"",
-                    field.description.unwrap().replace("\n", "\n\t")
-                )
-                .ok();
+            if let (Some(description), false) = (field.description, federation) {
+                export_description(sdl, description, "\t");
             }
-            if !field.args.is_empty() {
+            if !federation && field.args.values().any(|arg| arg.description.is_some()) {
+                writeln!(sdl, "\t{}(", field.name).ok();
+                for arg in field.args.values() {
+                    if let Some(description) = arg.description {
+                        export_description(sdl, description, "\t\t");
+                    }
+                    writeln!(sdl, "\t\t{}", export_input_value(arg)).ok();
+                }
+                write!(sdl, "\t): {}", field.ty).ok();
+            } else if !field.args.is_empty() {
                 write!(sdl, "\t{}(", field.name).ok();
                 for (i, arg) in field.args.values().enumerate() {
                     if i != 0 {
@@ -68,6 +78,7 @@ impl Registry {
             } else {
                 write!(sdl, "\t{}: {}", field.name, field.ty).ok();
             }
+            export_deprecation(sdl, field.deprecation);
 
             if federation {
                 if field.external {
@@ -88,7 +99,10 @@ impl Registry {
     fn export_type(&self, ty: &MetaType, sdl: &mut String, federation: bool) {
         match ty {
             MetaType::Scalar {
-                name, description, ..
+                name,
+                description,
+                specified_by_url,
+                ..
             } => {
                 const SYSTEM_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
                 const FEDERATION_SCALARS: &[&str] = &["Any"];
@@ -98,9 +112,18 @@ impl Registry {
                 }
                 if export_scalar {
                     if description.is_some() && !federation {
-                        writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                        export_description(sdl, description.unwrap(), "");
                     }
-                    writeln!(sdl, "scalar {}", name).ok();
+                    write!(sdl, "scalar {}", name).ok();
+                    if let Some(url) = specified_by_url {
+                        write!(
+                            sdl,
+                            " @specifiedBy(url: {})",
+                            Value::String(url.to_string())
+                        )
+                        .ok();
+                    }
+                    writeln!(sdl).ok();
                 }
             }
             MetaType::Object {
@@ -123,7 +146,7 @@ impl Registry {
                 }
 
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    export_description(sdl, description.unwrap(), "");
                 }
                 if federation && *extends {
                     write!(sdl, "extend ").ok();
@@ -152,7 +175,7 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    export_description(sdl, description.unwrap(), "");
                 }
                 if federation && *extends {
                     write!(sdl, "extend ").ok();
@@ -178,12 +201,17 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    export_description(sdl, description.unwrap(), "");
                 }
                 write!(sdl, "enum {} ", name).ok();
                 writeln!(sdl, "{{").ok();
                 for value in enum_values.values() {
-                    writeln!(sdl, "\t{}", value.name).ok();
+                    if let (Some(description), false) = (value.description, federation) {
+                        export_description(sdl, description, "\t");
+                    }
+                    write!(sdl, "\t{}", value.name).ok();
+                    export_deprecation(sdl, value.deprecation);
+                    writeln!(sdl).ok();
                 }
                 writeln!(sdl, "}}").ok();
             }
@@ -191,16 +219,20 @@ impl Registry {
                 name,
                 input_fields,
                 description,
+                one_of,
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    export_description(sdl, description.unwrap(), "");
                 }
                 write!(sdl, "input {} ", name).ok();
+                if *one_of {
+                    write!(sdl, "@oneOf ").ok();
+                }
                 writeln!(sdl, "{{").ok();
                 for field in input_fields.values() {
-                    if let Some(description) = field.description {
-                        writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description).ok();
+                    if let (Some(description), false) = (field.description, federation) {
+                        export_description(sdl, description, "");
                     }
                     writeln!(sdl, "{}", export_input_value(&field)).ok();
                 }
@@ -213,7 +245,7 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    export_description(sdl, description.unwrap(), "");
                 }
                 write!(sdl, "union {} =", name).ok();
                 for ty in possible_types {
@@ -227,21 +259,70 @@ impl Registry {
     fn write_implements(&self, sdl: &mut String, name: &str) {
         if let Some(implements) = self.implements.get(name) {
             if !implements.is_empty() {
-                write!(
-                    sdl,
-                    "implements {} ",
-                    implements
-                        .iter()
-                        .map(AsRef::as_ref)
-                        .collect::<Vec<&str>>()
-                        .join(" & ")
-                )
-                .ok();
+                let mut implements = implements.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+                implements.sort_unstable();
+                write!(sdl, "implements {} ", implements.join(" & ")).ok();
+            }
+        }
+    }
+
+    fn export_directives(&self, sdl: &mut String) {
+        const SYSTEM_DIRECTIVES: &[&str] = &["include", "skip", "deprecated", "specifiedBy"];
+
+        let mut directives = self
+            .directives
+            .values()
+            .filter(|directive| !SYSTEM_DIRECTIVES.contains(&directive.name))
+            .collect::<Vec<_>>();
+        directives.sort_by_key(|directive| directive.name);
+
+        for directive in directives {
+            if let Some(description) = directive.description {
+                export_description(sdl, description, "");
+            }
+            write!(sdl, "directive @{}", directive.name).ok();
+            if !directive.args.is_empty() {
+                let args = directive
+                    .args
+                    .values()
+                    .map(export_input_value)
+                    .collect::<Vec<_>>();
+                write!(sdl, "({})", args.join(", ")).ok();
             }
+            let locations = directive
+                .locations
+                .iter()
+                .map(|location| format!("{:?}", location))
+                .collect::<Vec<_>>();
+            writeln!(sdl, " on {}", locations.join(" | ")).ok();
         }
     }
 }
 
+/// Writes a description as a block string, with each line prefixed with `indent`.
+fn export_description(sdl: &mut String, description: &str, indent: &str) {
+    writeln!(sdl, "{}\"\"\"", indent).ok();
+    for line in description.replace("\"\"\"", "\\\"\"\"").lines() {
+        if line.is_empty() {
+            writeln!(sdl).ok();
+        } else {
+            writeln!(sdl, "{}{}", indent, line).ok();
+        }
+    }
+    writeln!(sdl, "{}\"\"\"", indent).ok();
+}
+
+fn export_deprecation(sdl: &mut String, deprecation: Option<&str>) {
+    if let Some(reason) = deprecation {
+        write!(
+            sdl,
+            " @deprecated(reason: {})",
+            Value::String(reason.to_string())
+        )
+        .ok();
+    }
+}
+
 fn export_input_value(input_value: &MetaInputValue) -> String {
     if let Some(default_value) = &input_value.default_value {
         format!(
@@ -1,4 +1,5 @@
 mod cache_control;
+mod diff;
 mod export_sdl;
 
 use std::collections::{HashMap, HashSet};
@@ -13,7 +14,9 @@ use crate::parser::types::{
 use crate::validators::InputValueValidator;
 use crate::{model, Any, Context, Positioned, ServerResult, Type, Value, VisitorContext};
 
+pub use crate::model::__DirectiveLocation;
 pub use cache_control::CacheControl;
+pub use diff::SchemaChange;
 
 fn strip_brackets(type_name: &str) -> Option<&str> {
     if let Some(rest) = type_name.strip_prefix('[') {
@@ -23,6 +26,24 @@ fn strip_brackets(type_name: &str) -> Option<&str> {
     }
 }
 
+/// Returns the reason why a `one_of` input object with the given non-null fields is invalid, or
+/// `None` if exactly one field is set.
+#[doc(hidden)]
+pub fn check_one_of_fields(fields: &[&str]) -> Option<String> {
+    match fields {
+        [_] => None,
+        [] => Some("exactly one field must be set, but none was".to_string()),
+        _ => Some(format!(
+            "exactly one field must be set, but {} were",
+            fields
+                .iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum MetaTypeName<'a> {
     List(&'a str),
@@ -71,16 +92,30 @@ impl<'a> MetaTypeName<'a> {
     }
 
     pub fn is_subtype(&self, sub: &MetaTypeName<'_>) -> bool {
+        self.is_subtype_with(sub, &|super_type, sub_type| super_type == sub_type)
+    }
+
+    /// Checks whether `sub` can be used where `self` is expected, following the rules of
+    /// `IsValidImplementationFieldType` in the spec. `is_named_subtype` decides whether one named
+    /// type is a subtype of another.
+    pub(crate) fn is_subtype_with<F>(&self, sub: &MetaTypeName<'_>, is_named_subtype: &F) -> bool
+    where
+        F: Fn(&str, &str) -> bool,
+    {
         match (self, sub) {
-            (MetaTypeName::NonNull(super_type), MetaTypeName::NonNull(sub_type))
-            | (MetaTypeName::Named(super_type), MetaTypeName::NonNull(sub_type)) => {
-                MetaTypeName::create(super_type).is_subtype(&MetaTypeName::create(sub_type))
+            (MetaTypeName::NonNull(super_type), MetaTypeName::NonNull(sub_type)) => {
+                MetaTypeName::create(super_type)
+                    .is_subtype_with(&MetaTypeName::create(sub_type), is_named_subtype)
             }
-            (MetaTypeName::Named(super_type), MetaTypeName::Named(sub_type)) => {
-                super_type == sub_type
+            (_, MetaTypeName::NonNull(sub_type)) => {
+                self.is_subtype_with(&MetaTypeName::create(sub_type), is_named_subtype)
             }
             (MetaTypeName::List(super_type), MetaTypeName::List(sub_type)) => {
-                MetaTypeName::create(super_type).is_subtype(&MetaTypeName::create(sub_type))
+                MetaTypeName::create(super_type)
+                    .is_subtype_with(&MetaTypeName::create(sub_type), is_named_subtype)
+            }
+            (MetaTypeName::Named(super_type), MetaTypeName::Named(sub_type)) => {
+                is_named_subtype(super_type, sub_type)
             }
             _ => false,
         }
@@ -103,6 +138,36 @@ pub struct MetaInputValue {
     pub default_value: Option<String>,
     pub validator: Option<Arc<dyn InputValueValidator>>,
     pub visible: Option<MetaVisibleFn>,
+    pub aliases: &'static [&'static str],
+    pub is_secret: bool,
+}
+
+impl MetaInputValue {
+    /// Creates an input value without a description, default value, validator or aliases.
+    ///
+    /// Use it with the struct update syntax to set only the fields that matter, so that the code
+    /// keeps compiling when fields are added:
+    ///
+    /// ```
+    /// use async_graphql::registry::MetaInputValue;
+    ///
+    /// let arg = MetaInputValue {
+    ///     description: Some("The number of items."),
+    ///     ..MetaInputValue::new("count", "Int!")
+    /// };
+    /// ```
+    pub fn new(name: &'static str, ty: impl Into<String>) -> Self {
+        Self {
+            name,
+            description: None,
+            ty: ty.into(),
+            default_value: None,
+            validator: None,
+            visible: None,
+            aliases: &[],
+            is_secret: false,
+        }
+    }
 }
 
 type ComputeComplexityFn = fn(
@@ -149,6 +214,7 @@ pub enum MetaType {
         description: Option<&'static str>,
         is_valid: fn(value: &Value) -> bool,
         visible: Option<MetaVisibleFn>,
+        specified_by_url: Option<&'static str>,
     },
     Object {
         name: String,
@@ -185,6 +251,8 @@ pub enum MetaType {
         description: Option<&'static str>,
         input_fields: IndexMap<String, MetaInputValue>,
         visible: Option<MetaVisibleFn>,
+        /// Exactly one field must be provided, and it must not be null.
+        one_of: bool,
     },
 }
 
@@ -302,6 +370,7 @@ pub struct Registry {
     pub subscription_type: Option<String>,
     pub disable_introspection: bool,
     pub enable_federation: bool,
+    pub typename_alias: Option<String>,
 }
 
 impl Registry {
@@ -505,14 +574,7 @@ impl Registry {
                         let mut args = IndexMap::new();
                         args.insert(
                             "representations",
-                            MetaInputValue {
-                                name: "representations",
-                                description: None,
-                                ty: "[_Any!]!".to_string(),
-                                default_value: None,
-                                validator: None,
-                                visible: None,
-                            },
+                            MetaInputValue::new("representations", "[_Any!]!"),
                         );
                         args
                     },
@@ -529,6 +591,23 @@ impl Registry {
         }
     }
 
+    /// Returns `true` if any argument or input field is marked as secret.
+    pub(crate) fn has_secrets(&self) -> bool {
+        let has_secret_args =
+            |args: &IndexMap<&'static str, MetaInputValue>| args.values().any(|arg| arg.is_secret);
+
+        self.directives.values().any(|d| has_secret_args(&d.args))
+            || self.types.values().any(|ty| match ty {
+                MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                    fields.values().any(|field| has_secret_args(&field.args))
+                }
+                MetaType::InputObject { input_fields, .. } => {
+                    input_fields.values().any(|field| field.is_secret)
+                }
+                _ => false,
+            })
+    }
+
     pub fn names(&self) -> Vec<String> {
         let mut names = HashSet::new();
 
@@ -573,6 +652,90 @@ impl Registry {
         names.into_iter().collect()
     }
 
+    /// Check that every object implements all the fields of its interfaces, with compatible
+    /// types, all the arguments of the interface fields with the same types, and no additional
+    /// required arguments.
+    pub(crate) fn check_interface_implementations(&self) -> Result<(), String> {
+        for ty in self.types.values() {
+            if let MetaType::Interface {
+                name: interface_name,
+                fields: interface_fields,
+                possible_types,
+                ..
+            } = ty
+            {
+                for object_name in possible_types {
+                    let object_fields = match self.types.get(object_name) {
+                        Some(MetaType::Object { fields, .. }) => fields,
+                        _ => continue,
+                    };
+                    for interface_field in interface_fields.values() {
+                        let object_field = object_fields.get(&interface_field.name).ok_or_else(|| {
+                            format!(
+                                "Object \"{}\" does not implement the field \"{}\" of interface \"{}\".",
+                                object_name, interface_field.name, interface_name
+                            )
+                        })?;
+                        if !self.is_subtype(&interface_field.ty, &object_field.ty) {
+                            return Err(format!(
+                                "The field \"{}\" of object \"{}\" has the type \"{}\", which is not compatible with the type \"{}\" of the field in interface \"{}\".",
+                                interface_field.name,
+                                object_name,
+                                object_field.ty,
+                                interface_field.ty,
+                                interface_name
+                            ));
+                        }
+                        for interface_arg in interface_field.args.values() {
+                            let object_arg = object_field.args.get(interface_arg.name).ok_or_else(|| {
+                                format!(
+                                    "The field \"{}\" of object \"{}\" does not have the argument \"{}\" of the field in interface \"{}\".",
+                                    interface_field.name, object_name, interface_arg.name, interface_name
+                                )
+                            })?;
+                            if object_arg.ty != interface_arg.ty {
+                                return Err(format!(
+                                    "The argument \"{}\" of the field \"{}\" of object \"{}\" has the type \"{}\", which is not the type \"{}\" of the argument in interface \"{}\".",
+                                    interface_arg.name,
+                                    interface_field.name,
+                                    object_name,
+                                    object_arg.ty,
+                                    interface_arg.ty,
+                                    interface_name
+                                ));
+                            }
+                        }
+                        for object_arg in object_field.args.values() {
+                            if !interface_field.args.contains_key(object_arg.name)
+                                && MetaTypeName::create(&object_arg.ty).is_non_null()
+                                && object_arg.default_value.is_none()
+                            {
+                                return Err(format!(
+                                    "The argument \"{}\" of the field \"{}\" of object \"{}\" is required, but it is not an argument of the field in interface \"{}\".",
+                                    object_arg.name, interface_field.name, object_name, interface_name
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_subtype(&self, super_type: &str, sub_type: &str) -> bool {
+        MetaTypeName::create(super_type).is_subtype_with(
+            &MetaTypeName::create(sub_type),
+            &|super_type, sub_type| {
+                super_type == sub_type
+                    || self
+                        .types
+                        .get(super_type)
+                        .map_or(false, |ty| ty.is_possible_type(sub_type))
+            },
+        )
+    }
+
     pub fn set_description<T: Type>(&mut self, desc: &'static str) {
         match self.types.get_mut(&*T::type_name()) {
             Some(MetaType::Scalar { description, .. }) => *description = Some(desc),
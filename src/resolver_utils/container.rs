@@ -1,7 +1,11 @@
+use std::any::Any;
 use std::collections::BTreeMap;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 
+use futures_util::FutureExt;
+
 use crate::extensions::{ErrorLogger, ExtensionContext, ResolveInfo};
 use crate::parser::types::Selection;
 use crate::registry::MetaType;
@@ -146,7 +150,9 @@ impl<'a> Fields<'a> {
 
             match &selection.node {
                 Selection::Field(field) => {
-                    if field.node.name.node == "__typename" {
+                    if field.node.name.node == "__typename"
+                        || is_typename_alias::<T>(ctx, &field.node.name.node)
+                    {
                         // Get the typename
                         let ctx_field = ctx.with_field(field);
                         let field_name = ctx_field.item.node.response_key().node.clone();
@@ -176,7 +182,7 @@ impl<'a> Fields<'a> {
                             let field_name = ctx_field.item.node.response_key().node.clone();
 
                             let res = if ctx_field.query_env.extensions.is_empty() {
-                                match root.resolve_field(&ctx_field).await {
+                                match resolve_field(root, &ctx_field).await {
                                     Ok(value) => Ok((field_name, value.unwrap_or_default())),
                                     Err(e) => {
                                         Err(e.path(PathSegment::Field(field_name.to_string())))
@@ -189,30 +195,42 @@ impl<'a> Fields<'a> {
                                 };
 
                                 let type_name = T::type_name();
-                                let resolve_info = ResolveInfo {
-                                    resolve_id: ctx_field.resolve_id,
-                                    path_node: ctx_field.path_node.as_ref().unwrap(),
-                                    parent_type: &type_name,
-                                    return_type: match ctx_field
+                                let field_type = |type_name: &str| {
+                                    ctx_field
                                         .schema_env
                                         .registry
                                         .types
-                                        .get(type_name.as_ref())
+                                        .get(type_name)
                                         .and_then(|ty| {
                                             ty.field_by_name(field.node.name.node.as_str())
                                         })
                                         .map(|field| &field.ty)
-                                    {
-                                        Some(ty) => &ty,
-                                        None => {
-                                            return Err(ServerError::new(format!(
-                                                r#"Cannot query field "{}" on type "{}"."#,
-                                                field_name, type_name
-                                            ))
-                                            .at(ctx_field.item.pos)
-                                            .path(PathSegment::Field(field_name.to_string())));
+                                };
+                                // A container that resolves the fields of its concrete types
+                                // itself, such as an `InterfaceImpl`, is not the parent of them.
+                                let concrete_type_name;
+                                let (parent_type, return_type) = match field_type(&type_name) {
+                                    Some(ty) => (&type_name, ty),
+                                    None => {
+                                        concrete_type_name = root.introspection_type_name();
+                                        match field_type(&concrete_type_name) {
+                                            Some(ty) => (&concrete_type_name, ty),
+                                            None => {
+                                                return Err(ServerError::new(format!(
+                                                    r#"Cannot query field "{}" on type "{}"."#,
+                                                    field_name, type_name
+                                                ))
+                                                .at(ctx_field.item.pos)
+                                                .path(PathSegment::Field(field_name.to_string())));
+                                            }
                                         }
-                                    },
+                                    }
+                                };
+                                let resolve_info = ResolveInfo {
+                                    resolve_id: ctx_field.resolve_id,
+                                    path_node: ctx_field.path_node.as_ref().unwrap(),
+                                    parent_type,
+                                    return_type,
                                 };
 
                                 ctx_field
@@ -220,7 +238,7 @@ impl<'a> Fields<'a> {
                                     .extensions
                                     .resolve_start(&ctx_extension, &resolve_info);
 
-                                let res = match root.resolve_field(&ctx_field).await {
+                                let res = match resolve_field(root, &ctx_field).await {
                                     Ok(value) => Ok((field_name, value.unwrap_or_default())),
                                     Err(e) => {
                                         Err(e.path(PathSegment::Field(field_name.to_string())))
@@ -306,3 +324,47 @@ impl<'a> Fields<'a> {
         Ok(())
     }
 }
+
+/// Resolves a field of the container, converting a panic into an error if
+/// `SchemaBuilder::catch_panics` is set.
+async fn resolve_field<T: ContainerType + ?Sized>(
+    root: &T,
+    ctx: &Context<'_>,
+) -> ServerResult<Option<Value>> {
+    if !ctx.schema_env.catch_panics {
+        return root.resolve_field(ctx).await;
+    }
+
+    match AssertUnwindSafe(root.resolve_field(ctx))
+        .catch_unwind()
+        .await
+    {
+        Ok(res) => res,
+        Err(payload) => Err(ServerError::new(format!(
+            "The resolver panicked: {}",
+            panic_message(&*payload)
+        ))
+        .at(ctx.item.pos)),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Returns true if the field is the typename alias configured with
+/// `SchemaBuilder::typename_alias`, and the container doesn't have a field with the same name.
+fn is_typename_alias<T: ContainerType + ?Sized>(ctx: &ContextSelectionSet<'_>, name: &str) -> bool {
+    let registry = &ctx.schema_env.registry;
+    registry.typename_alias.as_deref() == Some(name)
+        && !matches!(
+            registry.types.get(T::type_name().as_ref()),
+            Some(MetaType::Object { fields, .. }) if fields.contains_key(name)
+        )
+}
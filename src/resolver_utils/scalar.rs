@@ -122,6 +122,7 @@ macro_rules! scalar_internal {
                     description: $desc,
                     is_valid: |value| <$ty as $crate::ScalarType>::is_valid(value),
                     visible: ::std::option::Option::None,
+                    specified_by_url: ::std::option::Option::None,
                 })
             }
         }
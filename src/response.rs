@@ -2,12 +2,15 @@ use http::header::HeaderMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{CacheControl, Result, ServerError, Value};
+use crate::{CacheControl, PathSegment, Result, ServerError, Value};
 
 /// Query response
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Response {
     /// Data of query result
+    ///
+    /// The keys of objects are always sorted alphabetically, regardless of the order in which
+    /// the fields were selected, so the serialized response is deterministic.
     #[serde(default)]
     pub data: Value,
 
@@ -26,6 +29,18 @@ pub struct Response {
     /// HTTP headers
     #[serde(skip)]
     pub http_headers: HeaderMap<String>,
+
+    /// The path of the data, only set on the subsequent payloads of `@defer`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    path: Option<Vec<PathSegment>>,
+
+    /// The label of the `@defer` directive that this payload belongs to
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    label: Option<String>,
+
+    /// Whether more payloads will follow, only set on the payloads of a query with `@defer`
+    #[serde(rename = "hasNext", skip_serializing_if = "Option::is_none", default)]
+    has_next: Option<bool>,
 }
 
 impl Response {
@@ -71,6 +86,43 @@ impl Response {
         }
     }
 
+    /// Set the path and the label of a subsequent payload of `@defer`.
+    #[must_use]
+    pub(crate) fn deferred_payload(self, path: Vec<PathSegment>, label: Option<String>) -> Self {
+        Self {
+            path: Some(path),
+            label,
+            ..self
+        }
+    }
+
+    /// Set whether more payloads of a query with `@defer` will follow this one.
+    #[must_use]
+    pub(crate) fn has_next_payload(self, has_next: bool) -> Self {
+        Self {
+            has_next: Some(has_next),
+            ..self
+        }
+    }
+
+    /// The path of the data, only set on the subsequent payloads of `@defer`.
+    #[inline]
+    pub fn path(&self) -> Option<&[PathSegment]> {
+        self.path.as_deref()
+    }
+
+    /// The label of the `@defer` directive that this payload belongs to.
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Whether more payloads will follow, only set on the payloads of a query with `@defer`.
+    #[inline]
+    pub fn has_next(&self) -> Option<bool> {
+        self.has_next
+    }
+
     /// Returns `true` if the response is ok.
     #[inline]
     pub fn is_ok(&self) -> bool {
@@ -93,6 +145,16 @@ impl Response {
             Ok(self)
         }
     }
+
+    /// Returns the HTTP status hint of the first error that has one.
+    ///
+    /// An error in any field fails the whole response, so its data is `null` and every error is
+    /// a top-level error.
+    ///
+    /// See [`Context::abort_with_status`](struct.ContextBase.html#method.abort_with_status).
+    pub fn http_status(&self) -> Option<u16> {
+        self.errors.iter().find_map(ServerError::http_status)
+    }
 }
 
 /// Response for batchable queries
@@ -125,6 +187,14 @@ impl BatchResponse {
         }
     }
 
+    /// Returns the HTTP status hint of the response. Batch responses never have one.
+    pub fn http_status(&self) -> Option<u16> {
+        match self {
+            BatchResponse::Single(resp) => resp.http_status(),
+            BatchResponse::Batch(_) => None,
+        }
+    }
+
     /// Provides an iterator over all of the HTTP headers set on the response
     pub fn http_headers(&self) -> impl Iterator<Item = (&str, &str)> {
         let it: Box<dyn Iterator<Item = (&str, &str)>> = match self {
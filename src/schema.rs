@@ -3,38 +3,53 @@ use std::collections::BTreeMap;
 use std::ops::Deref;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Instant;
 
+use async_graphql_value::Value as InputValue;
 use futures_util::stream::{self, Stream, StreamExt};
 use indexmap::map::IndexMap;
 
 use crate::context::{Data, QueryEnvInner, ResolveId};
 use crate::extensions::{ErrorLogger, ExtensionContext, ExtensionFactory, Extensions};
+use crate::field_cache::FieldCache;
 use crate::model::__DirectiveLocation;
 use crate::parser::parse_query;
-use crate::parser::types::{DocumentOperations, OperationType};
-use crate::registry::{MetaDirective, MetaInputValue, Registry};
+use crate::parser::types::{
+    DocumentOperations, ExecutableDocument, OperationType, Selection, SelectionSet,
+};
+use crate::registry::{MetaDirective, MetaInputValue, MetaType, MetaTypeName, Registry};
 use crate::resolver_utils::{resolve_container, resolve_container_serial};
 use crate::subscription::collect_subscription_streams;
-use crate::types::QueryRoot;
-use crate::validation::{check_rules, ValidationMode};
+use crate::types::{DynamicMergedObject, QueryRoot};
+use crate::validation::{check_rules, redact_secrets, ValidationMode, REDACTED};
 use crate::{
-    BatchRequest, BatchResponse, CacheControl, ContextBase, ObjectType, QueryEnv, Request,
-    Response, ServerError, SubscriptionType, Type, Value, ID,
+    BatchRequest, BatchResponse, CacheControl, ContextBase, InputType, Name, ObjectType,
+    Positioned, QueryEnv, Request, Response, SchemaChange, ServerError, ServerResult,
+    SubscriptionType, Type, Value, Variables, ID,
 };
 
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
     validation_mode: ValidationMode,
-    query: QueryRoot<Query>,
-    mutation: Mutation,
+    query: QueryRoot<DynamicMergedObject<Query>>,
+    mutation: DynamicMergedObject<Mutation>,
     subscription: Subscription,
     registry: Registry,
     data: Data,
     complexity: Option<usize>,
     depth: Option<usize>,
+    max_subscriptions: Option<usize>,
+    max_query_length: Option<usize>,
+    batch_concurrency: usize,
+    lenient_numbers: bool,
     extensions: Vec<Box<dyn ExtensionFactory>>,
+    field_cache: Option<Box<dyn FieldCache>>,
+    catch_panics: bool,
+    error_hook: Option<ErrorHook>,
 }
 
+type ErrorHook = Box<dyn Fn(&mut ServerError) + Send + Sync>;
+
 impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription> {
     /// Manually register a type in the schema.
     ///
@@ -44,6 +59,117 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Merge the fields of another object into the query root.
+    ///
+    /// Unlike `MergedObject`, the objects to merge can be chosen at runtime, for example when
+    /// the schema is assembled from plugins. The fields are resolved by the query root first,
+    /// then by the merged objects in the order they were added. A field that already exists on
+    /// the query root is not replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn a(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// struct PluginQuery;
+    ///
+    /// #[Object]
+    /// impl PluginQuery {
+    ///     async fn b(&self) -> i32 {
+    ///         20
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///         .merge_query(PluginQuery)
+    ///         .finish();
+    ///     let res = schema.execute("{ a b }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "a": 10, "b": 20 }));
+    /// });
+    /// ```
+    pub fn merge_query<T: ObjectType + 'static>(mut self, obj: T) -> Self {
+        let root = self.registry.query_type.clone();
+        self.query.inner.merge(&mut self.registry, &root, obj);
+        self
+    }
+
+    /// Merge the fields of another object into the mutation root.
+    ///
+    /// See [`merge_query`](#method.merge_query) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutation root is `EmptyMutation`.
+    pub fn merge_mutation<T: ObjectType + 'static>(mut self, obj: T) -> Self {
+        let root = self
+            .registry
+            .mutation_type
+            .clone()
+            .expect("Cannot merge an object into an empty mutation root.");
+        self.mutation.merge(&mut self.registry, &root, obj);
+        self
+    }
+
+    /// Register a custom directive that can be used in queries.
+    ///
+    /// Resolvers can read the arguments of a field directive with
+    /// [`Context::directive_arguments`](struct.ContextBase.html#method.directive_arguments).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use async_graphql::indexmap::IndexMap;
+    /// use async_graphql::registry::{MetaDirective, MetaInputValue, __DirectiveLocation};
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self, ctx: &Context<'_>) -> i32 {
+    ///         match ctx.directive_arguments("multiply").unwrap() {
+    ///             Some(args) => match &args["factor"] {
+    ///                 Value::Number(n) => 10 * n.as_i64().unwrap() as i32,
+    ///                 _ => unreachable!(),
+    ///             },
+    ///             None => 10,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .register_directive(MetaDirective {
+    ///         name: "multiply",
+    ///         description: None,
+    ///         locations: vec![__DirectiveLocation::FIELD],
+    ///         args: {
+    ///             let mut args = IndexMap::new();
+    ///             args.insert("factor", MetaInputValue::new("factor", "Int!"));
+    ///             args
+    ///         },
+    ///     })
+    ///     .finish();
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let res = schema.execute("{ value @multiply(factor: 3) }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "value": 30 }));
+    /// });
+    /// ```
+    pub fn register_directive(mut self, directive: MetaDirective) -> Self {
+        self.registry.add_directive(directive);
+        self
+    }
+
     /// Disable introspection queries.
     pub fn disable_introspection(mut self) -> Self {
         self.registry.disable_introspection = true;
@@ -62,6 +188,71 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum number of subscriptions that a single WebSocket connection can run at the
+    /// same time. By default, there is no limit.
+    ///
+    /// A subscription started past the limit is rejected with an `error` message.
+    pub fn limit_subscriptions(mut self, count: usize) -> Self {
+        self.max_subscriptions = Some(count);
+        self
+    }
+
+    /// Set the maximum length in bytes of a query string. By default, there is no limit.
+    ///
+    /// A longer query is rejected before it is parsed.
+    pub fn limit_query_length(mut self, length: usize) -> Self {
+        self.max_query_length = Some(length);
+        self
+    }
+
+    /// Set the number of requests of a batch that are executed at the same time. By default, they
+    /// are executed one by one.
+    ///
+    /// The responses are always in the same order as the requests.
+    pub fn batch_concurrency(mut self, count: usize) -> Self {
+        self.batch_concurrency = count.max(1);
+        self
+    }
+
+    /// Accept numeric strings such as `"42"` or `"1.5"` in variables of type `Int` or `Float`,
+    /// including inside lists and input objects. By default, only JSON numbers are accepted.
+    ///
+    /// This is meant for clients that cannot send numbers without losing precision, such as
+    /// JavaScript clients sending 64-bit integers. Strings that are not numbers are still
+    /// rejected.
+    pub fn lenient_numbers(mut self) -> Self {
+        self.lenient_numbers = true;
+        self
+    }
+
+    /// Set the cache for the results of the fields marked with `#[graphql(cached)]`. By default,
+    /// these fields are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use async_graphql::field_cache::MemoryFieldCache;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     #[graphql(cached, cache_control(max_age = 60))]
+    ///     async fn value(&self) -> i32 {
+    ///         100
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///     .field_cache(MemoryFieldCache::new())
+    ///     .finish();
+    /// ```
+    pub fn field_cache(mut self, cache: impl FieldCache) -> Self {
+        self.field_cache = Some(Box::new(cache));
+        self
+    }
+
     /// Add an extension to the schema.
     ///
     /// # Examples
@@ -87,6 +278,58 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Add the [Apollo tracing extension](extensions/struct.ApolloTracing.html), which includes
+    /// the timing of every resolver in the `tracing` extension of the response.
+    #[cfg(feature = "apollo_tracing")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "apollo_tracing")))]
+    pub fn enable_apollo_tracing(self) -> Self {
+        self.extension(crate::extensions::ApolloTracing)
+    }
+
+    /// Convert panics in field resolvers into errors of the fields, with the panic message and the
+    /// path of the field. Like any other field error, this fails the whole response, so its data
+    /// is null. By default, a panic in a resolver is not caught.
+    ///
+    /// This wraps every resolver in `catch_unwind`, which has a small cost. The state of the
+    /// objects a resolver was using when it panicked is not checked, so it is up to you to make
+    /// sure they are still usable afterwards.
+    pub fn catch_panics(mut self) -> Self {
+        self.catch_panics = true;
+        self
+    }
+
+    /// Set a function that is called with every error of every response before it is returned,
+    /// so that messages can be rewritten or extensions added in one place.
+    ///
+    /// This is useful in production to hide the details of internal errors from clients.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> Result<i32> {
+    ///         Err("connection refused: 10.0.0.1:5432".into())
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///         .error_hook(|err| err.message = "Internal server error".to_string())
+    ///         .finish();
+    ///     let res = schema.execute("{ value }").await;
+    ///     assert_eq!(res.errors[0].message, "Internal server error");
+    /// });
+    /// ```
+    pub fn error_hook(mut self, hook: impl Fn(&mut ServerError) + Send + Sync + 'static) -> Self {
+        self.error_hook = Some(Box::new(hook));
+        self
+    }
+
     /// Add a global data that can be accessed in the `Schema`. You access it with `Context::data`.
     pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
         self.data.insert(data);
@@ -105,6 +348,48 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Add a field with the specified name to all unions and interfaces, which returns the name of
+    /// the concrete type just like `__typename`.
+    ///
+    /// If the concrete object defines a field with the same name, that field is resolved instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// #[derive(SimpleObject)]
+    /// struct A {
+    ///     value: i32,
+    /// }
+    ///
+    /// #[derive(Union)]
+    /// enum MyUnion {
+    ///     A(A),
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> MyUnion {
+    ///         A { value: 10 }.into()
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+    ///         .typename_alias("kind")
+    ///         .finish();
+    ///     let res = schema.execute("{ value { kind } }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "value": { "kind": "A" } }));
+    /// });
+    /// ```
+    pub fn typename_alias(mut self, name: impl Into<String>) -> Self {
+        self.registry.typename_alias = Some(name.into());
+        self
+    }
+
     /// Override the description of the specified type.
     pub fn override_description<T: Type>(mut self, desc: &'static str) -> Self {
         self.registry.set_description::<T>(desc);
@@ -112,12 +397,21 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
     }
 
     /// Build schema.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object does not implement all the fields of its interfaces with compatible
+    /// types.
     pub fn finish(mut self) -> Schema<Query, Mutation, Subscription> {
         // federation
         if self.registry.enable_federation || self.registry.has_entities() {
             self.registry.create_federation_types();
         }
 
+        if let Err(err) = self.registry.check_interface_implementations() {
+            panic!("{}", err);
+        }
+
         Schema(Arc::new(SchemaInner {
             validation_mode: self.validation_mode,
             query: self.query,
@@ -125,10 +419,18 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
             subscription: self.subscription,
             complexity: self.complexity,
             depth: self.depth,
+            max_subscriptions: self.max_subscriptions,
+            max_query_length: self.max_query_length,
+            batch_concurrency: self.batch_concurrency,
+            lenient_numbers: self.lenient_numbers,
+            has_secrets: self.registry.has_secrets(),
             extensions: self.extensions,
+            error_hook: self.error_hook,
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry: self.registry,
                 data: self.data,
+                field_cache: self.field_cache,
+                catch_panics: self.catch_panics,
             })),
         }))
     }
@@ -138,6 +440,8 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
 pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
+    pub field_cache: Option<Box<dyn FieldCache>>,
+    pub catch_panics: bool,
 }
 
 #[doc(hidden)]
@@ -155,12 +459,18 @@ impl Deref for SchemaEnv {
 #[doc(hidden)]
 pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) validation_mode: ValidationMode,
-    pub(crate) query: QueryRoot<Query>,
-    pub(crate) mutation: Mutation,
+    pub(crate) query: QueryRoot<DynamicMergedObject<Query>>,
+    pub(crate) mutation: DynamicMergedObject<Mutation>,
     pub(crate) subscription: Subscription,
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
+    pub(crate) max_subscriptions: Option<usize>,
+    pub(crate) max_query_length: Option<usize>,
+    pub(crate) batch_concurrency: usize,
+    pub(crate) lenient_numbers: bool,
+    pub(crate) has_secrets: bool,
     pub(crate) extensions: Vec<Box<dyn ExtensionFactory>>,
+    pub(crate) error_hook: Option<ErrorHook>,
     pub(crate) env: SchemaEnv,
 }
 
@@ -216,14 +526,23 @@ where
     ) -> SchemaBuilder<Query, Mutation, Subscription> {
         SchemaBuilder {
             validation_mode: ValidationMode::Strict,
-            query: QueryRoot { inner: query },
-            mutation,
+            query: QueryRoot {
+                inner: DynamicMergedObject::new(query),
+            },
+            mutation: DynamicMergedObject::new(mutation),
             subscription,
             registry: Self::create_registry(),
             data: Default::default(),
             complexity: None,
             depth: None,
+            max_subscriptions: None,
+            max_query_length: None,
+            batch_concurrency: 1,
+            lenient_numbers: false,
             extensions: Default::default(),
+            field_cache: None,
+            catch_panics: false,
+            error_hook: None,
         }
     }
 
@@ -245,6 +564,7 @@ where
             },
             disable_introspection: false,
             enable_federation: false,
+            typename_alias: None,
         };
 
         registry.add_directive(MetaDirective {
@@ -258,12 +578,8 @@ where
             args: {
                 let mut args = IndexMap::new();
                 args.insert("if", MetaInputValue {
-                    name: "if",
                     description: Some("Included when true."),
-                    ty: "Boolean!".to_string(),
-                    default_value: None,
-                    validator: None,
-                    visible: None,
+                    ..MetaInputValue::new("if", "Boolean!")
                 });
                 args
             }
@@ -280,12 +596,65 @@ where
             args: {
                 let mut args = IndexMap::new();
                 args.insert("if", MetaInputValue {
-                    name: "if",
                     description: Some("Skipped when true."),
-                    ty: "Boolean!".to_string(),
-                    default_value: None,
-                    validator: None,
-                    visible: None,
+                    ..MetaInputValue::new("if", "Boolean!")
+                });
+                args
+            }
+        });
+
+        registry.add_directive(MetaDirective {
+            name: "deprecated",
+            description: Some("Marks an element of a GraphQL schema as no longer supported."),
+            locations: vec![
+                __DirectiveLocation::FIELD_DEFINITION,
+                __DirectiveLocation::ENUM_VALUE
+            ],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("reason", MetaInputValue {
+                    description: Some("Explains why this element was deprecated, usually also including a suggestion for how to access supported similar data."),
+                    default_value: Some("\"No longer supported\"".to_string()),
+                    ..MetaInputValue::new("reason", "String")
+                });
+                args
+            }
+        });
+
+        registry.add_directive(MetaDirective {
+            name: "specifiedBy",
+            description: Some("Exposes a URL that specifies the behaviour of this scalar."),
+            locations: vec![__DirectiveLocation::SCALAR],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert(
+                    "url",
+                    MetaInputValue {
+                        description: Some("The URL that specifies the behaviour of this scalar."),
+                        ..MetaInputValue::new("url", "String!")
+                    },
+                );
+                args
+            },
+        });
+
+        registry.add_directive(MetaDirective {
+            name: "defer",
+            description: Some("Directs the executor to deliver this fragment in a subsequent payload of `Schema::execute_stream`, it only applies to the fragments on the query root."),
+            locations: vec![
+                __DirectiveLocation::FRAGMENT_SPREAD,
+                __DirectiveLocation::INLINE_FRAGMENT
+            ],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("if", MetaInputValue {
+                    description: Some("Deferred when true."),
+                    default_value: Some("true".to_string()),
+                    ..MetaInputValue::new("if", "Boolean")
+                });
+                args.insert("label", MetaInputValue {
+                    description: Some("Identifies the subsequent payload of this fragment."),
+                    ..MetaInputValue::new("label", "String")
                 });
                 args
             }
@@ -298,6 +667,15 @@ where
             args: Default::default(),
         });
 
+        registry.add_directive(MetaDirective {
+            name: "oneOf",
+            description: Some(
+                "Indicates that exactly one field of this input object must be set and non-null.",
+            ),
+            locations: vec![__DirectiveLocation::INPUT_OBJECT],
+            args: Default::default(),
+        });
+
         // register scalars
         bool::create_type_info(&mut registry);
         i32::create_type_info(&mut registry);
@@ -344,10 +722,18 @@ where
         self.0.env.registry.names()
     }
 
+    /// Compares this schema with a newer version of it, and returns the changes, which can be
+    /// used to detect breaking changes.
+    pub fn diff<Q, M, S>(&self, other: &Schema<Q, M, S>) -> Vec<SchemaChange> {
+        self.0.env.registry.diff(&other.0.env.registry)
+    }
+
     async fn prepare_request(
         &self,
         request: Request,
     ) -> Result<(QueryEnvInner, CacheControl), Vec<ServerError>> {
+        let started_at = Instant::now();
+
         // create extension instances
         let mut extensions: Extensions = self
             .0
@@ -364,13 +750,51 @@ where
             query_data: &data,
         };
 
-        let request = extensions.prepare_request(&ctx_extension, request).await?;
+        let mut request = extensions.prepare_request(&ctx_extension, request).await?;
+
+        if let Some(max_query_length) = self.max_query_length {
+            if request.query.len() > max_query_length {
+                return Err(vec![ServerError::new("Query is too long.")])
+                    .log_error(&ctx_extension, &extensions);
+            }
+        }
+
+        let mut redacted_document = None;
+        let document = if self.has_secrets {
+            // the query must be parsed to find the secrets before extensions see it
+            let document = parse_query(&request.query);
+            match &document {
+                Ok(document) => {
+                    let (query, document, variables) =
+                        redact_secrets(&self.env.registry, document, &request.variables);
+                    extensions.parse_start(&ctx_extension, &query, &variables);
+                    redacted_document = Some(document);
+                }
+                Err(_) => {
+                    // the secrets can't be found in a query that doesn't parse
+                    extensions.parse_start(&ctx_extension, REDACTED, &Variables::default())
+                }
+            }
+            document
+        } else {
+            extensions.parse_start(&ctx_extension, &request.query, &request.variables);
+            parse_query(&request.query)
+        }
+        .map_err(Into::<ServerError>::into)
+        .log_error(&ctx_extension, &extensions)?;
+        extensions.parse_end(
+            &ctx_extension,
+            redacted_document.as_ref().unwrap_or(&document),
+        );
 
-        extensions.parse_start(&ctx_extension, &request.query, &request.variables);
-        let document = parse_query(&request.query)
-            .map_err(Into::<ServerError>::into)
-            .log_error(&ctx_extension, &extensions)?;
-        extensions.parse_end(&ctx_extension, &document);
+        if self.lenient_numbers {
+            coerce_numeric_variables(
+                &self.env.registry,
+                &document,
+                request.operation_name.as_deref(),
+                &mut request.variables,
+            );
+        }
 
         // check rules
         extensions.validation_start(&ctx_extension);
@@ -378,6 +802,7 @@ where
             &self.env.registry,
             &document,
             Some(&request.variables),
+            request.operation_name.as_deref(),
             self.validation_mode,
         )
         .log_error(&ctx_extension, &extensions)?;
@@ -386,40 +811,43 @@ where
         // check limit
         if let Some(limit_complexity) = self.complexity {
             if validation_result.complexity > limit_complexity {
-                return Err(vec![ServerError::new("Query is too complex.")])
-                    .log_error(&ctx_extension, &extensions);
+                let mut err = ServerError::new("Query is too complex.");
+                err.locations.extend(validation_result.complexity_pos);
+                return Err(vec![err]).log_error(&ctx_extension, &extensions);
             }
         }
 
         if let Some(limit_depth) = self.depth {
             if validation_result.depth > limit_depth {
-                return Err(vec![ServerError::new("Query is nested too deep.")])
-                    .log_error(&ctx_extension, &extensions);
+                let mut err = ServerError::new("Query is nested too deep.");
+                err.locations.extend(validation_result.depth_pos);
+                return Err(vec![err]).log_error(&ctx_extension, &extensions);
             }
         }
 
         let operation = if let Some(operation_name) = &request.operation_name {
             match document.operations {
                 DocumentOperations::Single(_) => None,
-                DocumentOperations::Multiple(mut operations) => {
-                    operations.remove(operation_name.as_str())
-                }
+                DocumentOperations::Multiple(mut operations) => operations
+                    .remove_entry(operation_name.as_str())
+                    .map(|(name, operation)| (Some(name), operation)),
             }
             .ok_or_else(|| {
                 ServerError::new(format!(r#"Unknown operation named "{}""#, operation_name))
             })
         } else {
             match document.operations {
-                DocumentOperations::Single(operation) => Ok(operation),
+                DocumentOperations::Single(operation) => Ok((None, operation)),
                 DocumentOperations::Multiple(map) if map.len() == 1 => {
-                    Ok(map.into_iter().next().unwrap().1)
+                    let (name, operation) = map.into_iter().next().unwrap();
+                    Ok((Some(name), operation))
                 }
                 DocumentOperations::Multiple(_) => {
                     Err(ServerError::new("Operation name required in request."))
                 }
             }
         };
-        let operation = match operation {
+        let (operation_name, operation) = match operation {
             Ok(operation) => operation,
             Err(e) => {
                 extensions.error(&ctx_extension, &e);
@@ -430,11 +858,16 @@ where
         let env = QueryEnvInner {
             extensions,
             variables: request.variables,
+            operation_name,
             operation,
             fragments: document.fragments,
             uploads: request.uploads,
             ctx_data: Arc::new(data),
             http_headers: Default::default(),
+            response_extensions: Default::default(),
+            request_cache: Default::default(),
+            started_at,
+            cache_control: validation_result.cache_control,
         };
         Ok((env, validation_result.cache_control))
     }
@@ -468,7 +901,7 @@ where
         };
 
         env.extensions.execution_end(&ctx_extension);
-        let extensions = env.extensions.result(&ctx_extension);
+        let extensions = response_extensions(&env, &ctx_extension);
 
         match data {
             Ok(data) => Response::new(data),
@@ -478,25 +911,58 @@ where
         .http_headers(std::mem::take(&mut *env.http_headers.lock()))
     }
 
+    async fn execute_deferred(
+        &self,
+        env: &QueryEnv,
+        selection_set: &Positioned<SelectionSet>,
+    ) -> Response {
+        let inc_resolve_id = AtomicUsize::default();
+        let ctx = ContextBase {
+            path_node: None,
+            resolve_id: ResolveId::root(),
+            inc_resolve_id: &inc_resolve_id,
+            item: selection_set,
+            schema_env: &self.env,
+            query_env: env,
+        };
+
+        match resolve_container(&ctx, &self.query).await {
+            Ok(data) => Response::new(data),
+            Err(e) => Response::from_errors(vec![e]),
+        }
+    }
+
+    fn apply_error_hook(&self, mut resp: Response) -> Response {
+        if let Some(error_hook) = &self.error_hook {
+            resp.errors.iter_mut().for_each(|err| error_hook(err));
+        }
+        resp
+    }
+
     /// Execute a GraphQL query.
     pub async fn execute(&self, request: impl Into<Request>) -> Response {
         let request = request.into();
-        match self.prepare_request(request).await {
+        let resp = match self.prepare_request(request).await {
             Ok((env, cache_control)) => self
                 .execute_once(QueryEnv::new(env))
                 .await
                 .cache_control(cache_control),
             Err(errors) => Response::from_errors(errors),
-        }
+        };
+        self.apply_error_hook(resp)
     }
 
     /// Execute a GraphQL batch query.
+    ///
+    /// Up to [`batch_concurrency`](struct.SchemaBuilder.html#method.batch_concurrency) requests
+    /// are executed at the same time, the responses are in the same order as the requests.
     pub async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
         match batch_request {
             BatchRequest::Single(request) => BatchResponse::Single(self.execute(request).await),
             BatchRequest::Batch(requests) => BatchResponse::Batch(
                 futures_util::stream::iter(requests.into_iter())
-                    .then(|request| self.execute(request))
+                    .map(|request| self.execute(request))
+                    .buffered(self.batch_concurrency)
                     .collect()
                     .await,
             ),
@@ -509,6 +975,7 @@ where
         ctx_data: Arc<Data>,
     ) -> impl Stream<Item = Response> + Send {
         let schema = self.clone();
+        let hook_schema = self.clone();
 
         async_stream::stream! {
             let request = request.into();
@@ -520,13 +987,35 @@ where
                 }
             };
             env.ctx_data = ctx_data;
+            let deferred = match take_deferred_fragments(&mut env) {
+                Ok(deferred) => deferred,
+                Err(e) => {
+                    yield Response::from_errors(vec![e]);
+                    return;
+                }
+            };
             let env = QueryEnv::new(env);
 
             if env.operation.node.ty != OperationType::Subscription {
-                yield schema
-                    .execute_once(env)
+                let resp = schema
+                    .execute_once(env.clone())
                     .await
                     .cache_control(cache_control);
+                if deferred.is_empty() || resp.is_err() {
+                    yield resp;
+                    return;
+                }
+
+                yield resp.has_next_payload(true);
+
+                let count = deferred.len();
+                for (idx, (label, selection_set)) in deferred.into_iter().enumerate() {
+                    yield schema
+                        .execute_deferred(&env, &selection_set)
+                        .await
+                        .deferred_payload(Vec::new(), label)
+                        .has_next_payload(idx + 1 < count);
+                }
                 return;
             }
 
@@ -557,7 +1046,7 @@ where
             let mut stream = stream::select_all(streams);
             while let Some(data) = stream.next().await {
                 let is_err = data.is_err();
-                let extensions = env.extensions.result(&ctx_extension);
+                let extensions = response_extensions(&env, &ctx_extension);
                 yield match data {
                     Ok((name, value)) => {
                         let mut map = BTreeMap::new();
@@ -571,9 +1060,14 @@ where
                 }
             }
         }
+        .map(move |resp| hook_schema.apply_error_hook(resp))
     }
 
-    /// Execute a GraphQL subscription.
+    /// Execute a GraphQL query, subscription or mutation.
+    ///
+    /// A subscription yields a response for each event. The fragments on the root of a query that
+    /// are marked with `@defer` are resolved after the others, each of them is yielded as a
+    /// subsequent payload with a `path` and the `label` of the directive.
     pub fn execute_stream(
         &self,
         request: impl Into<Request>,
@@ -583,3 +1077,140 @@ where
         self.execute_stream_with_ctx_data(request, Arc::new(ctx_data))
     }
 }
+
+/// Removes the fragments marked with `@defer` from the root selection set of a query, returning
+/// them with their labels.
+fn take_deferred_fragments(
+    env: &mut QueryEnvInner,
+) -> ServerResult<Vec<(Option<String>, Positioned<SelectionSet>)>> {
+    if env.operation.node.ty != OperationType::Query {
+        return Ok(Vec::new());
+    }
+
+    let selections = std::mem::take(&mut env.operation.node.selection_set.node.items);
+    let resolve_arg = |value: &Positioned<InputValue>| {
+        let pos = value.pos;
+        value
+            .node
+            .clone()
+            .into_const_with(|name| env.var_value(&name, pos))
+    };
+
+    let mut deferred = Vec::new();
+    let mut items = Vec::new();
+    for selection in selections {
+        let directive = match selection.node {
+            Selection::Field(_) => None,
+            _ => selection
+                .node
+                .directives()
+                .iter()
+                .find(|directive| directive.node.name.node == "defer"),
+        };
+        let directive = match directive {
+            Some(directive) => directive,
+            None => {
+                items.push(selection);
+                continue;
+            }
+        };
+
+        let enabled = match directive.node.get_argument("if") {
+            Some(value) => <Option<bool> as InputType>::parse(Some(resolve_arg(value)?))
+                .map_err(|e| e.into_server_error().at(value.pos))?
+                .unwrap_or(true),
+            None => true,
+        };
+        if !enabled {
+            items.push(selection);
+            continue;
+        }
+
+        let label = match directive.node.get_argument("label") {
+            Some(value) => <Option<String> as InputType>::parse(Some(resolve_arg(value)?))
+                .map_err(|e| e.into_server_error().at(value.pos))?,
+            None => None,
+        };
+        let pos = selection.pos;
+        deferred.push((
+            label,
+            Positioned::new(
+                SelectionSet {
+                    items: vec![selection],
+                },
+                pos,
+            ),
+        ));
+    }
+    env.operation.node.selection_set.node.items = items;
+
+    Ok(deferred)
+}
+
+/// Collects the `extensions` of a response from the schema extensions and the resolvers, returning
+/// `None` if neither contributed anything.
+fn response_extensions(env: &QueryEnv, ctx: &ExtensionContext<'_>) -> Option<Value> {
+    let mut map = std::mem::take(&mut *env.response_extensions.lock());
+    map.extend(env.extensions.result(ctx));
+    if map.is_empty() {
+        None
+    } else {
+        Some(Value::Object(map))
+    }
+}
+
+/// Coerces the variables of the operation that will be executed, the other operations may
+/// declare the same variables with other types.
+fn coerce_numeric_variables(
+    registry: &Registry,
+    document: &ExecutableDocument,
+    operation_name: Option<&str>,
+    variables: &mut Variables,
+) {
+    let mut operations = document.operations.iter();
+    let operation = match operation_name {
+        Some(operation_name) => operations
+            .find(|(name, _)| name.map(Name::as_str) == Some(operation_name))
+            .map(|(_, operation)| operation),
+        None if operations.len() == 1 => operations.next().map(|(_, operation)| operation),
+        None => None,
+    };
+    let operation = match operation {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    for def in &operation.node.variable_definitions {
+        if let Some(value) = variables.get_mut(&def.node.name.node) {
+            coerce_numeric_value(registry, &def.node.var_type.node.to_string(), value);
+        }
+    }
+}
+
+fn coerce_numeric_value(registry: &Registry, type_name: &str, value: &mut Value) {
+    match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => coerce_numeric_value(registry, type_name, value),
+        MetaTypeName::List(type_name) => match value {
+            Value::List(items) => {
+                for item in items {
+                    coerce_numeric_value(registry, type_name, item);
+                }
+            }
+            value => coerce_numeric_value(registry, type_name, value),
+        },
+        MetaTypeName::Named("Int") | MetaTypeName::Named("Float") => {
+            *value = std::mem::take(value).coerce_numeric_string();
+        }
+        MetaTypeName::Named(type_name) => {
+            if let (Some(MetaType::InputObject { input_fields, .. }), Value::Object(fields)) =
+                (registry.types.get(type_name), value)
+            {
+                for (name, field) in input_fields {
+                    if let Some(value) = fields.get_mut(name.as_str()) {
+                        coerce_numeric_value(registry, &field.ty, value);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::resolver_utils::resolve_container;
+use crate::{
+    ContainerType, Context, ContextSelectionSet, ObjectType, OutputType, Positioned, ServerResult,
+    Type, Value,
+};
+
+/// An object that can be merged into a root object at runtime.
+#[async_trait::async_trait]
+pub(crate) trait DynamicObject: Send + Sync {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>>;
+
+    async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>>;
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType> DynamicObject for T {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        ContainerType::resolve_field(self, ctx).await
+    }
+
+    async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
+        ContainerType::find_entity(self, ctx, params).await
+    }
+}
+
+/// A root object with the objects merged into it by `SchemaBuilder::merge_query` and
+/// `SchemaBuilder::merge_mutation`.
+///
+/// Fields are resolved by the root object first, then by each merged object in the order they
+/// were added.
+pub(crate) struct DynamicMergedObject<T> {
+    pub(crate) inner: T,
+    pub(crate) merged: Vec<Box<dyn DynamicObject>>,
+}
+
+impl<T> DynamicMergedObject<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self {
+            inner,
+            merged: Vec::new(),
+        }
+    }
+
+    /// Adds the fields of `T2` to the root type `root` in the registry and resolves them with `obj`.
+    ///
+    /// Fields that the root type already has are not overwritten.
+    pub(crate) fn merge<T2: ObjectType + 'static>(
+        &mut self,
+        registry: &mut Registry,
+        root: &str,
+        obj: T2,
+    ) {
+        if let MetaType::Object {
+            fields,
+            cache_control,
+            ..
+        } = registry.create_dummy_type::<T2>()
+        {
+            if let Some(MetaType::Object {
+                fields: root_fields,
+                cache_control: root_cc,
+                ..
+            }) = registry.types.get_mut(root)
+            {
+                for (name, field) in fields {
+                    root_fields.entry(name).or_insert(field);
+                }
+                *root_cc = root_cc.merge(&cache_control);
+            }
+        }
+        self.merged.push(Box::new(obj));
+    }
+}
+
+impl<T: Type> Type for DynamicMergedObject<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType> ContainerType for DynamicMergedObject<T> {
+    fn is_empty() -> bool {
+        T::is_empty()
+    }
+
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        if let Some(value) = ContainerType::resolve_field(&self.inner, ctx).await? {
+            return Ok(Some(value));
+        }
+        for obj in &self.merged {
+            if let Some(value) = obj.resolve_field(ctx).await? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
+        if let Some(value) = ContainerType::find_entity(&self.inner, ctx, params).await? {
+            return Ok(Some(value));
+        }
+        for obj in &self.merged {
+            if let Some(value) = obj.find_entity(ctx, params).await? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: ObjectType> OutputType for DynamicMergedObject<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<T: ObjectType> ObjectType for DynamicMergedObject<T> {}
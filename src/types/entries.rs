@@ -0,0 +1,383 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::iter::FromIterator;
+
+use indexmap::map::IndexMap;
+
+use crate::parser::types::Field;
+use crate::registry::MetaTypeName;
+use crate::resolver_utils::{resolve_container, resolve_list, ContainerType};
+use crate::{
+    registry, Context, ContextSelectionSet, ObjectType, OutputType, Positioned, ServerResult, Type,
+    Value,
+};
+
+/// A map that is output as a list of `{ key, value }` objects.
+///
+/// Unlike a map used as a scalar, the types of the keys and the values are part of the schema,
+/// so the values can be objects. The entry type is named after the key and value types, for
+/// example `Entries<String, i32>` is output as `[StringIntEntry!]!`. A `List` suffix is added for
+/// a list type and a `Nullable` suffix for a nullable type, so `Entries<String, Vec<Option<i32>>>`
+/// is output as `[StringIntNullableListEntry!]!`. Building a schema panics if two entry types get
+/// the same name, such as those of `Entries<A, BC>` and `Entries<AB, C>`.
+///
+/// The entries are output in the order they are stored in, which for a `HashMap` is arbitrary.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn scores(&self) -> Entries<String, i32> {
+///         let mut scores = BTreeMap::new();
+///         scores.insert("a".to_string(), 1);
+///         scores.insert("b".to_string(), 2);
+///         scores.into()
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ scores { key value } }").await.into_result().unwrap().data;
+///     assert_eq!(res, value!({
+///         "scores": [
+///             { "key": "a", "value": 1 },
+///             { "key": "b", "value": 2 },
+///         ],
+///     }));
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Entries<K, V>(pub Vec<(K, V)>);
+
+impl<K, V> From<Vec<(K, V)>> for Entries<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        Self(entries)
+    }
+}
+
+impl<K, V> From<BTreeMap<K, V>> for Entries<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for Entries<K, V> {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Entries<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<K: OutputType, V: OutputType> Type for Entries<K, V> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", Entry::<K, V>::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", Entry::<K, V>::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        Entry::<K, V>::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+}
+
+#[async_trait::async_trait]
+impl<K: OutputType, V: OutputType> OutputType for Entries<K, V> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_list(
+            ctx,
+            field,
+            self.0.iter().map(|(key, value)| Entry { key, value }),
+            Some(self.0.len()),
+        )
+        .await
+    }
+}
+
+struct Entry<'a, K, V> {
+    key: &'a K,
+    value: &'a V,
+}
+
+impl<'a, K: OutputType, V: OutputType> Type for Entry<'a, K, V> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{}{}Entry",
+            name_segment(&K::qualified_type_name()),
+            name_segment(&V::qualified_type_name())
+        ))
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        // Different key and value types can give the same name, such as `A` and `BC`, and `AB`
+        // and `C`. A placeholder of a type that is being registered has no fields.
+        if let Some(registry::MetaType::Object { fields, .. }) =
+            registry.types.get(&*Self::type_name())
+        {
+            let has_type =
+                |name: &str, ty: String| fields.get(name).map(|field| &field.ty) == Some(&ty);
+            if !fields.is_empty()
+                && !(has_type("key", K::qualified_type_name())
+                    && has_type("value", V::qualified_type_name()))
+            {
+                panic!(
+                    "Type \"{}\" is already registered with different fields, so it can't be the entry type of `{}` keys and `{}` values.",
+                    Self::type_name(),
+                    K::qualified_type_name(),
+                    V::qualified_type_name(),
+                );
+            }
+        }
+
+        registry.create_type::<Self, _>(|registry| registry::MetaType::Object {
+            name: Self::type_name().to_string(),
+            description: Some("An entry of a map."),
+            fields: {
+                let mut fields = IndexMap::new();
+
+                fields.insert(
+                    "key".to_string(),
+                    registry::MetaField {
+                        name: "key".to_string(),
+                        description: None,
+                        args: Default::default(),
+                        ty: K::create_type_info(registry),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        compute_complexity: None,
+                    },
+                );
+
+                fields.insert(
+                    "value".to_string(),
+                    registry::MetaField {
+                        name: "value".to_string(),
+                        description: None,
+                        args: Default::default(),
+                        ty: V::create_type_info(registry),
+                        deprecation: None,
+                        cache_control: Default::default(),
+                        external: false,
+                        requires: None,
+                        provides: None,
+                        visible: None,
+                        compute_complexity: None,
+                    },
+                );
+
+                fields
+            },
+            cache_control: Default::default(),
+            extends: false,
+            keys: None,
+            visible: None,
+        })
+    }
+}
+
+/// Converts a qualified type name such as `[Int]!` into a part of a type name such as
+/// `IntNullableList`.
+fn name_segment(type_name: &str) -> String {
+    match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => match MetaTypeName::create(type_name) {
+            MetaTypeName::List(type_name) => format!("{}List", name_segment(type_name)),
+            _ => type_name.to_string(),
+        },
+        _ => format!("{}Nullable", name_segment(&format!("{}!", type_name))),
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, K: OutputType, V: OutputType> ContainerType for Entry<'a, K, V> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        if ctx.item.node.name.node == "key" {
+            return OutputType::resolve(self.key, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        } else if ctx.item.node.name.node == "value" {
+            return OutputType::resolve(self.value, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, K: OutputType, V: OutputType> OutputType for Entry<'a, K, V> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<'a, K: OutputType, V: OutputType> ObjectType for Entry<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_entries_type() {
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Score {
+            value: i32,
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn counts(&self) -> Entries<String, i32> {
+                vec![("b".to_string(), 2), ("a".to_string(), 1)].into()
+            }
+
+            async fn scores(&self) -> Entries<i32, Score> {
+                let mut scores = BTreeMap::new();
+                scores.insert(2, Score { value: 20 });
+                scores.insert(1, Score { value: 10 });
+                scores.into()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute("{ counts { key value } scores { key value { value } } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "counts": [
+                    { "key": "b", "value": 2 },
+                    { "key": "a", "value": 1 },
+                ],
+                "scores": [
+                    { "key": 1, "value": { "value": 10 } },
+                    { "key": 2, "value": { "value": 20 } },
+                ],
+            })
+        );
+
+        let sdl = schema.sdl();
+        assert!(sdl.contains("counts: [StringIntEntry!]!"));
+        assert!(sdl.contains("type StringIntEntry {\n\tkey: String!\n\tvalue: Int!\n}"));
+        assert!(sdl.contains("scores: [IntScoreEntry!]!"));
+    }
+
+    #[async_std::test]
+    async fn test_entries_type_names() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn counts(&self) -> Entries<String, i32> {
+                vec![("a".to_string(), 1)].into()
+            }
+
+            async fn optional_counts(&self) -> Entries<String, Option<i32>> {
+                vec![("a".to_string(), None)].into()
+            }
+
+            async fn lists(&self) -> Entries<String, Vec<i32>> {
+                vec![("a".to_string(), vec![1, 2])].into()
+            }
+
+            async fn optional_lists(&self) -> Entries<String, Option<Vec<Option<i32>>>> {
+                vec![("a".to_string(), Some(vec![Some(1), None]))].into()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute("{ counts { value } optionalCounts { value } lists { value } optionalLists { value } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "counts": [{ "value": 1 }],
+                "optionalCounts": [{ "value": null }],
+                "lists": [{ "value": [1, 2] }],
+                "optionalLists": [{ "value": [1, null] }],
+            })
+        );
+
+        let sdl = schema.sdl();
+        assert!(sdl.contains("type StringIntEntry {\n\tkey: String!\n\tvalue: Int!\n}"));
+        assert!(sdl.contains("optionalCounts: [StringIntNullableEntry!]!"));
+        assert!(sdl.contains("type StringIntNullableEntry {\n\tkey: String!\n\tvalue: Int\n}"));
+        assert!(sdl.contains("lists: [StringIntListEntry!]!"));
+        assert!(sdl.contains("type StringIntListEntry {\n\tkey: String!\n\tvalue: [Int!]!\n}"));
+        assert!(sdl.contains("optionalLists: [StringIntNullableListNullableEntry!]!"));
+        assert!(sdl.contains(
+            "type StringIntNullableListNullableEntry {\n\tkey: String!\n\tvalue: [Int]\n}"
+        ));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Type \"ABCEntry\" is already registered with different fields, so it can't be the entry type of `AB!` keys and `C!` values."
+    )]
+    fn test_entries_type_name_conflict() {
+        macro_rules! object {
+            ($name:ident) => {
+                #[derive(SimpleObject)]
+                #[graphql(internal)]
+                struct $name {
+                    value: i32,
+                }
+            };
+        }
+        object!(A);
+        object!(BC);
+        object!(AB);
+        object!(C);
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn first(&self) -> Entries<A, BC> {
+                vec![(A { value: 1 }, BC { value: 2 })].into()
+            }
+
+            async fn second(&self) -> Entries<AB, C> {
+                vec![(AB { value: 1 }, C { value: 2 })].into()
+            }
+        }
+
+        Schema::new(Query, EmptyMutation, EmptySubscription);
+    }
+}
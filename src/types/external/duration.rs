@@ -0,0 +1,76 @@
+use chrono::Duration;
+
+use crate::types::iso_duration::{format_duration, parse_duration};
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A signed duration in the ISO 8601 format `PnDTnHnMnS`, such as `PT1H30M` or `-P1DT0.5S`.
+///
+/// Weeks (`PnW`) are also accepted as input. Years and months are rejected because their length
+/// is not fixed. The output is normalized, so `PT90M` is output as `PT1H30M`; use
+/// [`IsoDuration`](types/struct.IsoDuration.html) to output the text that was received.
+#[Scalar(internal, name = "Duration")]
+impl ScalarType for Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => parse_duration(&s)
+                .ok_or_else(|| InputValueError::custom(format!("invalid duration \"{}\"", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format_duration(*self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_duration_scalar() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, duration: Duration) -> Duration {
+                duration
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute(r#"{ value(duration: "PT1H30M") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": "PT1H30M" })
+        );
+
+        // The output is normalized.
+        assert_eq!(
+            schema
+                .execute(r#"{ value(duration: "PT90M") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": "PT1H30M" })
+        );
+
+        assert!(schema
+            .execute(r#"{ value(duration: "1 hour") }"#)
+            .await
+            .into_result()
+            .is_err());
+    }
+}
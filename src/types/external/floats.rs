@@ -1,45 +1,147 @@
-use crate::{InputValueError, InputValueResult, Number, Scalar, ScalarType, Value};
-
-/// The `Float` scalar type represents signed double-precision fractional values as specified by [IEEE 754](https://en.wikipedia.org/wiki/IEEE_floating_point).
-#[Scalar(internal, name = "Float")]
-impl ScalarType for f32 {
-    fn parse(value: Value) -> InputValueResult<Self> {
-        match value {
-            Value::Number(n) => Ok(n
-                .as_f64()
-                .ok_or_else(|| InputValueError::from("Invalid number"))?
-                as Self),
-            _ => Err(InputValueError::expected_type(value)),
-        }
-    }
+use std::borrow::Cow;
 
-    fn is_valid(value: &Value) -> bool {
-        matches!(value, Value::Number(_))
-    }
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::{
+    ContextSelectionSet, InputType, InputValueError, InputValueResult, Number, OutputType,
+    Positioned, ScalarType, ServerError, ServerResult, Type, Value,
+};
 
-    fn to_value(&self) -> Value {
-        Value::Number(Number::from_f64(*self as f64).unwrap())
-    }
+macro_rules! float_scalar {
+    ($($ty:ty),*) => {
+        $(
+            /// The `Float` scalar type represents signed double-precision fractional values as specified by [IEEE 754](https://en.wikipedia.org/wiki/IEEE_floating_point).
+            impl ScalarType for $ty {
+                fn parse(value: Value) -> InputValueResult<Self> {
+                    match value {
+                        Value::Number(n) => Ok(n
+                            .as_f64()
+                            .ok_or_else(|| InputValueError::from("Invalid number"))?
+                            as Self),
+                        _ => Err(InputValueError::expected_type(value)),
+                    }
+                }
+
+                fn is_valid(value: &Value) -> bool {
+                    matches!(value, Value::Number(_))
+                }
+
+                fn to_value(&self) -> Value {
+                    Number::from_f64(*self as f64).map_or(Value::Null, Value::Number)
+                }
+            }
+
+            impl Type for $ty {
+                fn type_name() -> Cow<'static, str> {
+                    Cow::Borrowed("Float")
+                }
+
+                fn create_type_info(registry: &mut Registry) -> String {
+                    registry.create_type::<$ty, _>(|_| MetaType::Scalar {
+                        name: "Float".to_string(),
+                        description: Some("The `Float` scalar type represents signed double-precision fractional values as specified by [IEEE 754](https://en.wikipedia.org/wiki/IEEE_floating_point)."),
+                        is_valid: |value| <$ty as ScalarType>::is_valid(value),
+                        visible: None,
+                        specified_by_url: None,
+                    })
+                }
+            }
+
+            impl InputType for $ty {
+                fn parse(value: Option<Value>) -> InputValueResult<Self> {
+                    <$ty as ScalarType>::parse(value.unwrap_or_default())
+                }
+
+                fn to_value(&self) -> Value {
+                    <$ty as ScalarType>::to_value(self)
+                }
+            }
+
+            #[async_trait::async_trait]
+            impl OutputType for $ty {
+                async fn resolve(
+                    &self,
+                    _: &ContextSelectionSet<'_>,
+                    field: &Positioned<Field>,
+                ) -> ServerResult<Value> {
+                    // JSON cannot represent `NaN` or infinities.
+                    match Number::from_f64(*self as f64) {
+                        Some(n) => Ok(Value::Number(n)),
+                        None => Err(ServerError::new(format!(
+                            "Float cannot represent non-finite value: {}",
+                            self
+                        ))
+                        .at(field.pos)),
+                    }
+                }
+            }
+        )*
+    };
 }
 
-/// The `Float` scalar type represents signed double-precision fractional values as specified by [IEEE 754](https://en.wikipedia.org/wiki/IEEE_floating_point).
-#[Scalar(internal, name = "Float")]
-impl ScalarType for f64 {
-    fn parse(value: Value) -> InputValueResult<Self> {
-        match value {
-            Value::Number(n) => Ok(n
-                .as_f64()
-                .ok_or_else(|| InputValueError::from("Invalid number"))?
-                as Self),
-            _ => Err(InputValueError::expected_type(value)),
+float_scalar!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_non_finite_float() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self) -> f64 {
+                1.5
+            }
+
+            async fn nan(&self) -> f64 {
+                f64::NAN
+            }
+
+            async fn infinity(&self) -> f32 {
+                f32::INFINITY
+            }
         }
-    }
 
-    fn is_valid(value: &Value) -> bool {
-        matches!(value, Value::Number(_))
-    }
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute("{ value }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 1.5 })
+        );
+
+        assert_eq!(
+            schema
+                .execute("{ value nan }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "Float cannot represent non-finite value: NaN".to_string(),
+                locations: vec![Pos { line: 1, column: 9 }],
+                path: vec![PathSegment::Field("nan".to_owned())],
+                extensions: None,
+            }]
+        );
 
-    fn to_value(&self) -> Value {
-        Value::Number(Number::from_f64(*self as f64).unwrap())
+        assert_eq!(
+            schema
+                .execute("{ value infinity }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: "Float cannot represent non-finite value: inf".to_string(),
+                locations: vec![Pos { line: 1, column: 9 }],
+                path: vec![PathSegment::Field("infinity".to_owned())],
+                extensions: None,
+            }]
+        );
     }
 }
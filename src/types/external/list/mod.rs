@@ -2,5 +2,8 @@ mod btree_set;
 mod hash_set;
 mod linked_list;
 mod slice;
+#[cfg(feature = "smallvec")]
+mod small_vec;
+mod tuple;
 mod vec;
 mod vec_deque;
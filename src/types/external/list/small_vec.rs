@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use smallvec::{Array, SmallVec};
+
+use crate::parser::types::Field;
+use crate::resolver_utils::resolve_list;
+use crate::{
+    registry, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Type, Value,
+};
+
+impl<T: Type, A: Array<Item = T>> Type for SmallVec<A> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", T::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", T::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+}
+
+impl<T: InputType, A: Array<Item = T> + Send + Sync> InputType for SmallVec<A> {
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        match value.unwrap_or_default() {
+            Value::List(values) => values
+                .into_iter()
+                .map(|value| InputType::parse(Some(value)))
+                .collect::<Result<_, _>>()
+                .map_err(InputValueError::propagate),
+            value => Ok({
+                let mut result = Self::new();
+                result.push(InputType::parse(Some(value)).map_err(InputValueError::propagate)?);
+                result
+            }),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(InputType::to_value).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: OutputType, A: Array<Item = T> + Send + Sync> OutputType for SmallVec<A> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_list(ctx, field, self, Some(self.len())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use smallvec::{smallvec, SmallVec};
+
+    #[async_std::test]
+    async fn test_small_vec_type() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn values(&self, input: SmallVec<[i32; 4]>) -> SmallVec<[i32; 4]> {
+                input.into_iter().map(|n| n * 10).collect()
+            }
+
+            async fn spilled(&self) -> SmallVec<[String; 2]> {
+                smallvec!["a".to_string(), "b".to_string(), "c".to_string()]
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute("{ values(input: [1, 2, 3]) single: values(input: 4) spilled }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "values": [10, 20, 30],
+                "single": [40],
+                "spilled": ["a", "b", "c"],
+            })
+        );
+        assert!(schema.sdl().contains("values(input: [Int!]!): [Int!]!"));
+    }
+}
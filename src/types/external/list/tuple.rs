@@ -0,0 +1,65 @@
+use std::borrow::Cow;
+
+use crate::parser::types::Field;
+use crate::resolver_utils::resolve_list;
+use crate::{
+    registry, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Type, Value,
+};
+
+macro_rules! impl_tuple {
+    ($len:literal, $($idx:tt => $ty:ident),*) => {
+        impl<T: Type> Type for ($($ty,)*) {
+            fn type_name() -> Cow<'static, str> {
+                Cow::Owned(format!("[{}]", T::qualified_type_name()))
+            }
+
+            fn qualified_type_name() -> String {
+                format!("[{}]!", T::qualified_type_name())
+            }
+
+            fn create_type_info(registry: &mut registry::Registry) -> String {
+                T::create_type_info(registry);
+                Self::qualified_type_name()
+            }
+        }
+
+        impl<T: InputType> InputType for ($($ty,)*) {
+            fn parse(value: Option<Value>) -> InputValueResult<Self> {
+                match value.unwrap_or_default() {
+                    Value::List(values) if values.len() == $len => {
+                        let mut values = values.into_iter();
+                        Ok(($(
+                            <$ty as InputType>::parse(values.next())
+                                .map_err(InputValueError::propagate)?,
+                        )*))
+                    }
+                    Value::List(values) => Err(InputValueError::custom(format!(
+                        "expected a list of {} items, found {} items",
+                        $len,
+                        values.len()
+                    ))),
+                    value => Err(InputValueError::expected_type(value)),
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                Value::List(vec![$(InputType::to_value(&self.$idx)),*])
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl<T: OutputType> OutputType for ($($ty,)*) {
+            async fn resolve(
+                &self,
+                ctx: &ContextSelectionSet<'_>,
+                field: &Positioned<Field>,
+            ) -> ServerResult<Value> {
+                resolve_list(ctx, field, vec![$(&self.$idx),*], Some($len)).await
+            }
+        }
+    };
+}
+
+impl_tuple!(2, 0 => T, 1 => T);
+impl_tuple!(3, 0 => T, 1 => T, 2 => T);
@@ -7,6 +7,7 @@ mod floats;
 mod integers;
 mod json_object;
 mod list;
+mod net;
 mod non_zero_integers;
 mod optional;
 mod string;
@@ -18,7 +19,11 @@ mod chrono_tz;
 #[cfg(feature = "chrono")]
 mod datetime;
 #[cfg(feature = "chrono")]
+mod duration;
+#[cfg(feature = "chrono")]
 mod naive_time;
+#[cfg(feature = "smol_str")]
+mod smol_str;
 #[cfg(feature = "url")]
 mod url;
 #[cfg(feature = "uuid")]
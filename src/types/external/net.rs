@@ -0,0 +1,114 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+macro_rules! net_scalar {
+    ($($ty:ty, $desc:literal),*) => {
+        $(
+        #[doc = $desc]
+        #[Scalar(internal)]
+        impl ScalarType for $ty {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                match value {
+                    Value::String(s) => Ok(s.parse()?),
+                    _ => Err(InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::String(_))
+            }
+
+            fn to_value(&self) -> Value {
+                Value::String(self.to_string())
+            }
+        }
+        )*
+    };
+}
+
+net_scalar!(
+    IpAddr,
+    "An IPv4 or IPv6 address, such as `127.0.0.1` or `::1`.",
+    Ipv4Addr,
+    "An IPv4 address, such as `127.0.0.1`.",
+    Ipv6Addr,
+    "An IPv6 address, such as `::1`.",
+    SocketAddr,
+    "An IPv4 or IPv6 socket address, such as `127.0.0.1:8080` or `[::1]:8080`.",
+    SocketAddrV4,
+    "An IPv4 socket address, such as `127.0.0.1:8080`.",
+    SocketAddrV6,
+    "An IPv6 socket address, such as `[::1]:8080`."
+);
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    use crate::{ScalarType, Value};
+
+    #[test]
+    fn test_ip_addr() {
+        for s in &["127.0.0.1", "::1", "2001:db8::ff00:42:8329"] {
+            let addr = IpAddr::parse(Value::String(s.to_string())).unwrap();
+            assert_eq!(addr.to_value(), Value::String(s.to_string()));
+        }
+
+        let addr = Ipv4Addr::parse(Value::String("10.0.0.1".to_string())).unwrap();
+        assert_eq!(addr, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(addr.to_value(), Value::String("10.0.0.1".to_string()));
+
+        let addr = Ipv6Addr::parse(Value::String("::1".to_string())).unwrap();
+        assert_eq!(addr, Ipv6Addr::LOCALHOST);
+        assert_eq!(addr.to_value(), Value::String("::1".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_ip_addr() {
+        assert_eq!(
+            IpAddr::parse(Value::String("256.0.0.1".to_string()))
+                .unwrap_err()
+                .into_server_error()
+                .message,
+            r#"Failed to parse "IpAddr": invalid IP address syntax"#
+        );
+        assert!(IpAddr::parse(Value::String("localhost".to_string())).is_err());
+        assert!(IpAddr::parse(Value::Number(1.into())).is_err());
+        assert!(Ipv4Addr::parse(Value::String("::1".to_string())).is_err());
+        assert!(Ipv6Addr::parse(Value::String("127.0.0.1".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_socket_addr() {
+        for s in &["127.0.0.1:8080", "[::1]:8080", "[fe80::1%2]:443"] {
+            let addr = SocketAddr::parse(Value::String(s.to_string())).unwrap();
+            assert_eq!(addr.to_value(), Value::String(s.to_string()));
+        }
+
+        let addr = SocketAddrV4::parse(Value::String("10.0.0.1:53".to_string())).unwrap();
+        assert_eq!(addr.to_value(), Value::String("10.0.0.1:53".to_string()));
+
+        let addr = SocketAddrV6::parse(Value::String("[2001:db8::1]:53".to_string())).unwrap();
+        assert_eq!(
+            addr.to_value(),
+            Value::String("[2001:db8::1]:53".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_socket_addr() {
+        for s in &[
+            "127.0.0.1",
+            "127.0.0.1:",
+            "256.0.0.1:80",
+            "::1:8080",
+            "localhost:80",
+        ] {
+            assert!(SocketAddr::parse(Value::String(s.to_string())).is_err());
+        }
+        assert!(SocketAddr::parse(Value::Number(8080.into())).is_err());
+        assert!(SocketAddrV4::parse(Value::String("[::1]:8080".to_string())).is_err());
+        assert!(SocketAddrV6::parse(Value::String("127.0.0.1:8080".to_string())).is_err());
+    }
+}
@@ -13,9 +13,12 @@ impl ScalarType for NonZeroI8 {
                 let n = n
                     .as_i64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n < i8::MIN as i64 || n > i8::MAX as i64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n < i8::MIN as i64 || n > i8::MAX as i64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         i8::MIN,
                         i8::MAX
                     )));
@@ -44,9 +47,12 @@ impl ScalarType for NonZeroI16 {
                 let n = n
                     .as_i64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n < i16::MIN as i64 || n > i16::MAX as i64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n < i16::MIN as i64 || n > i16::MAX as i64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         i16::MIN,
                         i16::MAX
                     )));
@@ -75,9 +81,12 @@ impl ScalarType for NonZeroI32 {
                 let n = n
                     .as_i64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n < i32::MIN as i64 || n > i32::MAX as i64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n < i32::MIN as i64 || n > i32::MAX as i64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         i32::MIN,
                         i32::MAX
                     )));
@@ -106,9 +115,12 @@ impl ScalarType for NonZeroI64 {
                 let n = n
                     .as_i64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n < i64::MIN as i64 || n > i64::MAX as i64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n < i64::MIN as i64 || n > i64::MAX as i64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         i64::MIN,
                         i64::MAX
                     )));
@@ -137,9 +149,12 @@ impl ScalarType for NonZeroU8 {
                 let n = n
                     .as_u64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n > u8::MAX as u64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n > u8::MAX as u64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         1,
                         u8::MAX
                     )));
@@ -168,9 +183,12 @@ impl ScalarType for NonZeroU16 {
                 let n = n
                     .as_u64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n > u16::MAX as u64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n > u16::MAX as u64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         1,
                         u16::MAX
                     )));
@@ -199,9 +217,12 @@ impl ScalarType for NonZeroU32 {
                 let n = n
                     .as_u64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n > u32::MAX as u64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n > u32::MAX as u64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         1,
                         u32::MAX
                     )));
@@ -230,9 +251,12 @@ impl ScalarType for NonZeroU64 {
                 let n = n
                     .as_u64()
                     .ok_or_else(|| InputValueError::from("Invalid number"))?;
-                if n > u64::MAX as u64 || n == 0 {
+                if n == 0 {
+                    return Err(InputValueError::from("The value must be non-zero."));
+                }
+                if n > u64::MAX as u64 {
                     return Err(InputValueError::from(format!(
-                        "Only integers from {} to {} or non zero are accepted.",
+                        "Only integers from {} to {} are accepted.",
                         1,
                         u64::MAX
                     )));
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use smol_str::SmolStr;
+
+use crate::parser::types::Field;
+use crate::{
+    registry, ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType,
+    Positioned, ServerResult, Type, Value,
+};
+
+impl Type for SmolStr {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("String")
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        <String as Type>::create_type_info(registry)
+    }
+}
+
+impl InputType for SmolStr {
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        match value.unwrap_or_default() {
+            Value::String(s) => Ok(SmolStr::new(s)),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[async_trait::async_trait]
+impl OutputType for SmolStr {
+    async fn resolve(
+        &self,
+        _: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        Ok(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smol_str::SmolStr;
+
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_smol_str() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, input: SmolStr) -> SmolStr {
+                input
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let long = "a".repeat(100);
+
+        for s in &["abc", long.as_str()] {
+            assert_eq!(
+                schema
+                    .execute(format!(r#"{{ value(input: "{}") }}"#, s))
+                    .await
+                    .into_result()
+                    .unwrap()
+                    .data,
+                value!({ "value": *s })
+            );
+        }
+
+        assert!(schema
+            .execute("{ value(input: 1) }")
+            .await
+            .into_result()
+            .is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use thiserror::Error;
+use url::Url;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A URL scalar that only accepts the `https` scheme.
+///
+/// The input is a string, and the output is a string.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::HttpsUrl;
+///
+/// let url = HttpsUrl::parse("https://example.com/a").unwrap();
+/// assert_eq!(url.host_str(), Some("example.com"));
+/// assert!(HttpsUrl::parse("http://example.com/a").is_err());
+/// ```
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "url")))]
+pub struct HttpsUrl(Url);
+
+/// An error creating an [`HttpsUrl`](struct.HttpsUrl.html).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "url")))]
+pub enum HttpsUrlError {
+    /// The input is not a URL.
+    #[error("{0}")]
+    Parse(#[from] url::ParseError),
+
+    /// The URL has a scheme other than `https`.
+    #[error(r#"expected scheme "https", found "{0}""#)]
+    Scheme(String),
+}
+
+impl HttpsUrl {
+    /// Parse an absolute URL that uses the `https` scheme.
+    pub fn parse(input: &str) -> Result<Self, HttpsUrlError> {
+        Self::try_from(Url::parse(input)?)
+    }
+
+    /// Returns the inner URL.
+    pub fn into_inner(self) -> Url {
+        self.0
+    }
+}
+
+impl TryFrom<Url> for HttpsUrl {
+    type Error = HttpsUrlError;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        if url.scheme() != "https" {
+            return Err(HttpsUrlError::Scheme(url.scheme().to_string()));
+        }
+        Ok(HttpsUrl(url))
+    }
+}
+
+impl Deref for HttpsUrl {
+    type Target = Url;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<HttpsUrl> for Url {
+    fn from(url: HttpsUrl) -> Self {
+        url.0
+    }
+}
+
+#[Scalar(internal)]
+impl ScalarType for HttpsUrl {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Ok(HttpsUrl::parse(&s)?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_https_url() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, url: HttpsUrl) -> HttpsUrl {
+                url
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(r#"{ value(url: "https://example.com/a") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "value": "https://example.com/a",
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute(r#"{ value(url: "http://example.com/a") }"#)
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: r#"Failed to parse "HttpsUrl": expected scheme "https", found "http""#
+                    .to_string(),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 14
+                }],
+                path: vec![PathSegment::Field("value".to_owned())],
+                extensions: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_https_url_try_from() {
+        use std::convert::TryFrom;
+
+        let url = url::Url::parse("https://example.com/a").unwrap();
+        assert_eq!(HttpsUrl::try_from(url.clone()).unwrap().into_inner(), url);
+        assert_eq!(
+            HttpsUrl::try_from(url::Url::parse("ftp://example.com/a").unwrap()),
+            Err(HttpsUrlError::Scheme("ftp".to_string()))
+        );
+        assert!(matches!(
+            HttpsUrl::parse("example.com"),
+            Err(HttpsUrlError::Parse(_))
+        ));
+    }
+}
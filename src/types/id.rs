@@ -81,7 +81,7 @@ impl PartialEq<&str> for ID {
     }
 }
 
-#[Scalar(internal, name = "ID")]
+#[Scalar(internal, name = "ID", accept(String, Int))]
 impl ScalarType for ID {
     fn parse(value: Value) -> InputValueResult<Self> {
         match value {
@@ -91,14 +91,6 @@ impl ScalarType for ID {
         }
     }
 
-    fn is_valid(value: &Value) -> bool {
-        match value {
-            Value::Number(n) if n.is_i64() => true,
-            Value::String(_) => true,
-            _ => false,
-        }
-    }
-
     fn to_value(&self) -> Value {
         Value::String(self.0.clone())
     }
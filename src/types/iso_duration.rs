@@ -0,0 +1,278 @@
+use std::fmt::Write;
+
+use chrono::Duration;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A `chrono::Duration` that is output as the ISO 8601 text it was parsed from.
+///
+/// The `chrono::Duration` scalar normalizes its output, so `PT90M` is output as `PT1H30M`. This
+/// keeps the received text instead, so a value is output exactly as the client sent it. A value
+/// created from a `chrono::Duration` is formatted like the `chrono::Duration` scalar.
+///
+/// Both are the same `Duration` scalar in the schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IsoDuration {
+    duration: Duration,
+    text: String,
+}
+
+impl IsoDuration {
+    /// Get the duration.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Get the ISO 8601 text of the duration.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<Duration> for IsoDuration {
+    fn from(duration: Duration) -> Self {
+        Self {
+            duration,
+            text: format_duration(duration),
+        }
+    }
+}
+
+impl From<IsoDuration> for Duration {
+    fn from(duration: IsoDuration) -> Self {
+        duration.duration
+    }
+}
+
+#[Scalar(internal, name = "Duration")]
+impl ScalarType for IsoDuration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(text) => match parse_duration(&text) {
+                Some(duration) => Ok(IsoDuration { duration, text }),
+                None => Err(InputValueError::custom(format!(
+                    "invalid duration \"{}\"",
+                    text
+                ))),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.text.clone())
+    }
+}
+
+pub(super) fn parse_duration(s: &str) -> Option<Duration> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let mut rest = s.strip_prefix('P')?;
+    let mut in_time = false;
+    let mut last_rank = 0;
+    let mut secs: i64 = 0;
+    let mut nanos: i64 = 0;
+
+    while !rest.is_empty() {
+        if rest.starts_with('T') {
+            if in_time || rest.len() == 1 {
+                return None;
+            }
+            in_time = true;
+            rest = &rest[1..];
+            continue;
+        }
+
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+            .unwrap_or_else(|| rest.len());
+        let (number, tail) = rest.split_at(end);
+        let mut chars = tail.chars();
+        let unit = chars.next()?;
+        rest = chars.as_str();
+
+        // Each unit can appear at most once, from the largest to the smallest.
+        let (rank, unit_secs) = match (in_time, unit) {
+            (false, 'W') => (1, 604_800),
+            (false, 'D') => (2, 86_400),
+            (true, 'H') => (3, 3_600),
+            (true, 'M') => (4, 60),
+            (true, 'S') => (5, 1),
+            _ => return None,
+        };
+        if rank <= last_rank {
+            return None;
+        }
+        last_rank = rank;
+
+        let (int, frac) = match number.find(|c: char| c == '.' || c == ',') {
+            Some(idx) => (&number[..idx], Some(&number[idx + 1..])),
+            None => (number, None),
+        };
+        if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        secs = secs.checked_add(int.parse::<i64>().ok()?.checked_mul(unit_secs)?)?;
+
+        if let Some(frac) = frac {
+            // Only the seconds can have a fraction, with at most nanosecond precision.
+            if unit != 'S'
+                || frac.is_empty()
+                || frac.len() > 9
+                || !frac.bytes().all(|b| b.is_ascii_digit())
+            {
+                return None;
+            }
+            nanos = frac.parse::<i64>().ok()? * 10i64.pow(9 - frac.len() as u32);
+        }
+    }
+
+    if last_rank == 0 || secs > i64::MAX / 1000 {
+        return None;
+    }
+    let duration = Duration::seconds(secs).checked_add(&Duration::nanoseconds(nanos))?;
+    Some(if negative { -duration } else { duration })
+}
+
+pub(super) fn format_duration(duration: Duration) -> String {
+    let mut s = String::new();
+    let duration = if duration < Duration::zero() {
+        s.push('-');
+        -duration
+    } else {
+        duration
+    };
+
+    let secs = duration.num_seconds();
+    let nanos = (duration - Duration::seconds(secs))
+        .num_nanoseconds()
+        .unwrap_or_default();
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+
+    s.push('P');
+    if days > 0 {
+        let _ = write!(s, "{}D", days);
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 || days == 0 {
+        s.push('T');
+        if hours > 0 {
+            let _ = write!(s, "{}H", hours);
+        }
+        if minutes > 0 {
+            let _ = write!(s, "{}M", minutes);
+        }
+        if seconds > 0 || nanos > 0 || (days == 0 && hours == 0 && minutes == 0) {
+            let _ = write!(s, "{}", seconds);
+            if nanos > 0 {
+                let frac = format!("{:09}", nanos);
+                s.push('.');
+                s.push_str(frac.trim_end_matches('0'));
+            }
+            s.push('S');
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::{format_duration, parse_duration};
+    use crate::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("PT90M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(
+            parse_duration("-P1DT0.5S"),
+            Some(-(Duration::days(1) + Duration::milliseconds(500)))
+        );
+        assert_eq!(parse_duration("PT0,25S"), Some(Duration::milliseconds(250)));
+        assert_eq!(parse_duration("PT0S"), Some(Duration::zero()));
+
+        for s in &[
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1H",
+            "PT1",
+            "P1M",
+            "P1Y",
+            "PT1M1H",
+            "PT1H1H",
+            "P1H",
+            "PT1.5H",
+            "PT.5S",
+            "PT1.S",
+            "PT1.0000000001S",
+            "P1D2W",
+            "PT-1S",
+            "P99999999999999999999D",
+        ] {
+            assert_eq!(parse_duration(s), None, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::zero()), "PT0S");
+        assert_eq!(format_duration(Duration::minutes(90)), "PT1H30M");
+        assert_eq!(format_duration(Duration::days(3)), "P3D");
+        assert_eq!(
+            format_duration(-(Duration::days(1) + Duration::milliseconds(500))),
+            "-P1DT0.5S"
+        );
+        assert_eq!(
+            format_duration(Duration::seconds(61) + Duration::nanoseconds(1)),
+            "PT1M1.000000001S"
+        );
+    }
+
+    #[async_std::test]
+    async fn test_iso_duration_scalar() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, duration: IsoDuration) -> IsoDuration {
+                duration
+            }
+
+            async fn minutes(&self, duration: IsoDuration) -> i64 {
+                duration.duration().num_minutes()
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute(r#"{ value(duration: "PT90M") minutes(duration: "PT90M") }"#)
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": "PT90M", "minutes": 90 })
+        );
+        assert_eq!(IsoDuration::from(Duration::minutes(90)).as_str(), "PT1H30M");
+
+        assert!(schema
+            .execute(r#"{ value(duration: "1 hour") }"#)
+            .await
+            .into_result()
+            .is_err());
+    }
+}
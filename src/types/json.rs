@@ -85,6 +85,7 @@ impl<T> Type for OutputJson<T> {
             description: None,
             is_valid: |_| true,
             visible: None,
+            specified_by_url: None,
         })
     }
 }
@@ -138,6 +139,77 @@ mod test {
         );
     }
 
+    #[async_std::test]
+    async fn test_json_type_nested() {
+        #[derive(Serialize, Deserialize)]
+        struct Inner {
+            name: String,
+            values: Vec<i32>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct MyStruct {
+            inner: Inner,
+            extra: Option<Box<MyStruct>>,
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn obj(&self, input: Json<MyStruct>) -> Json<MyStruct> {
+                input
+            }
+
+            async fn total(&self, input: Json<MyStruct>) -> i32 {
+                input.inner.values.iter().sum::<i32>()
+                    + input
+                        .extra
+                        .as_ref()
+                        .map(|extra| extra.inner.values.iter().sum::<i32>())
+                        .unwrap_or_default()
+            }
+
+            async fn output(&self) -> OutputJson<Vec<i32>> {
+                vec![1, 2].into()
+            }
+        }
+
+        let input = value!({
+            "inner": { "name": "a", "values": [1, 2] },
+            "extra": {
+                "inner": { "name": "b", "values": [3] },
+                "extra": null,
+            },
+        });
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let query = r#"query($input: JSON!) {
+            obj(input: $input)
+            total(input: $input)
+            output
+            __type(name: "JSON") { kind }
+            outputType: __type(name: "Json") { kind }
+        }"#;
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new(query)
+                        .variables(Variables::from_value(value!({ "input": input.clone() })))
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "obj": input,
+                "total": 6,
+                "output": [1, 2],
+                "__type": { "kind": "SCALAR" },
+                "outputType": { "kind": "SCALAR" },
+            })
+        );
+    }
+
     #[async_std::test]
     async fn test_output_json_type() {
         #[derive(Serialize)]
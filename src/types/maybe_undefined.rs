@@ -8,6 +8,12 @@ use crate::{registry, InputType, InputValueError, InputValueResult, Type, Value}
 ///
 /// **Reference:** <https://spec.graphql.org/June2018/#sec-Null-Value>
 ///
+/// This is also useful for list arguments, where `Option<Vec<T>>` cannot distinguish an omitted
+/// argument from an explicit `null`. With `MaybeUndefined<Vec<T>>`, an omitted argument is
+/// `Undefined`, `null` is `Null`, and a list, including an empty list, is `Value`. An argument
+/// bound to a variable that is missing from the request variables and has no default value is
+/// also `Undefined`.
+///
 /// # Examples
 ///
 /// ```rust
@@ -97,6 +103,38 @@ impl<T> MaybeUndefined<T> {
             _ => None,
         }
     }
+
+    /// Converts from `&MaybeUndefined<T>` to `MaybeUndefined<&T>`.
+    #[inline]
+    pub fn as_ref(&self) -> MaybeUndefined<&T> {
+        match self {
+            MaybeUndefined::Undefined => MaybeUndefined::Undefined,
+            MaybeUndefined::Null => MaybeUndefined::Null,
+            MaybeUndefined::Value(value) => MaybeUndefined::Value(value),
+        }
+    }
+
+    /// Maps a `MaybeUndefined<T>` to `MaybeUndefined<U>` by applying a function to the contained
+    /// value, `undefined` and `null` are returned unchanged.
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> MaybeUndefined<U> {
+        match self {
+            MaybeUndefined::Undefined => MaybeUndefined::Undefined,
+            MaybeUndefined::Null => MaybeUndefined::Null,
+            MaybeUndefined::Value(value) => MaybeUndefined::Value(f(value)),
+        }
+    }
+
+    /// Calls `f` with the contained value and returns the result, `undefined` and `null` are
+    /// returned unchanged.
+    #[inline]
+    pub fn and_then<U, F: FnOnce(T) -> MaybeUndefined<U>>(self, f: F) -> MaybeUndefined<U> {
+        match self {
+            MaybeUndefined::Undefined => MaybeUndefined::Undefined,
+            MaybeUndefined::Null => MaybeUndefined::Null,
+            MaybeUndefined::Value(value) => f(value),
+        }
+    }
 }
 
 impl<T: Type> Type for MaybeUndefined<T> {
@@ -170,6 +208,60 @@ mod tests {
         assert_eq!(&MaybeUndefined::<i32>::qualified_type_name(), "Int");
     }
 
+    #[test]
+    fn test_maybe_undefined_map() {
+        assert_eq!(
+            MaybeUndefined::<i32>::Undefined.map(|n| n * 2),
+            MaybeUndefined::Undefined
+        );
+        assert_eq!(
+            MaybeUndefined::<i32>::Null.map(|n| n * 2),
+            MaybeUndefined::Null
+        );
+        assert_eq!(
+            MaybeUndefined::Value(10).map(|n| n.to_string()),
+            MaybeUndefined::Value("10".to_string())
+        );
+    }
+
+    #[test]
+    fn test_maybe_undefined_and_then() {
+        let half = |n: i32| {
+            if n % 2 == 0 {
+                MaybeUndefined::Value(n / 2)
+            } else {
+                MaybeUndefined::Null
+            }
+        };
+        assert_eq!(
+            MaybeUndefined::Undefined.and_then(half),
+            MaybeUndefined::Undefined
+        );
+        assert_eq!(MaybeUndefined::Null.and_then(half), MaybeUndefined::Null);
+        assert_eq!(
+            MaybeUndefined::Value(10).and_then(half),
+            MaybeUndefined::Value(5)
+        );
+        assert_eq!(
+            MaybeUndefined::Value(3).and_then(half),
+            MaybeUndefined::Null
+        );
+    }
+
+    #[test]
+    fn test_maybe_undefined_as_ref() {
+        let value = MaybeUndefined::Value("abc".to_string());
+        assert_eq!(value.as_ref().map(String::len), MaybeUndefined::Value(3));
+        assert_eq!(
+            MaybeUndefined::<String>::Undefined.as_ref(),
+            MaybeUndefined::Undefined
+        );
+        assert_eq!(
+            MaybeUndefined::<String>::Null.as_ref(),
+            MaybeUndefined::Null
+        );
+    }
+
     #[test]
     fn test_maybe_undefined_serde() {
         assert_eq!(
@@ -3,15 +3,24 @@
 pub mod connection;
 
 mod any;
+mod dynamic_merged_object;
 mod empty_mutation;
 mod empty_subscription;
+mod entries;
+#[cfg(feature = "url")]
+mod https_url;
 mod id;
+#[cfg(feature = "chrono")]
+mod iso_duration;
 mod json;
 mod maybe_undefined;
 mod merged_object;
 mod query_root;
+mod raw_json;
+mod seconds;
 #[cfg(feature = "string_number")]
 mod string_number;
+mod timestamp;
 mod upload;
 
 mod external;
@@ -19,12 +28,21 @@ mod external;
 pub use any::Any;
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
+pub use entries::Entries;
+#[cfg(feature = "url")]
+pub use https_url::{HttpsUrl, HttpsUrlError};
 pub use id::ID;
+#[cfg(feature = "chrono")]
+pub use iso_duration::IsoDuration;
 pub use json::{Json, OutputJson};
 pub use maybe_undefined::MaybeUndefined;
 pub use merged_object::{MergedObject, MergedObjectTail};
+pub use raw_json::RawJson;
+pub use seconds::Seconds;
 #[cfg(feature = "string_number")]
 pub use string_number::StringNumber;
+pub use timestamp::Timestamp;
 pub use upload::{Upload, UploadValue};
 
+pub(crate) use dynamic_merged_object::DynamicMergedObject;
 pub(crate) use query_root::QueryRoot;
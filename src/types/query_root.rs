@@ -58,17 +58,7 @@ impl<T: Type> Type for QueryRoot<T> {
                         description: Some("Request the type information of a single type."),
                         args: {
                             let mut args = IndexMap::new();
-                            args.insert(
-                                "name",
-                                registry::MetaInputValue {
-                                    name: "name",
-                                    description: None,
-                                    ty: "String!".to_string(),
-                                    default_value: None,
-                                    validator: None,
-                                    visible: None,
-                                },
-                            );
+                            args.insert("name", registry::MetaInputValue::new("name", "String!"));
                             args
                         },
                         ty: "__Type".to_string(),
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use crate::parser::types::Field;
+use crate::registry::Registry;
+use crate::{
+    ContextSelectionSet, Json, OutputType, Positioned, ServerError, ServerResult, Type, Value,
+};
+
+/// A JSON string that is written into the response as-is.
+///
+/// The string is only checked, not converted into a `Value` and back, which avoids that overhead
+/// for pre-rendered JSON. If it is not valid JSON, the field resolves to an error.
+///
+/// The verbatim output only applies when the response is serialized with `serde_json`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn config(&self) -> RawJson {
+///         RawJson(r#"{"theme": "dark"}"#.to_string())
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ config }").await;
+///     assert_eq!(
+///         serde_json::to_string(&res.data).unwrap(),
+///         r#"{"config":{"theme": "dark"}}"#
+///     );
+/// });
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct RawJson(pub String);
+
+impl From<String> for RawJson {
+    fn from(json: String) -> Self {
+        Self(json)
+    }
+}
+
+impl Type for RawJson {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("JSON")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        // Shares the `JSON` scalar with `Json`, so the schema is the same whichever registers it.
+        <Json<serde_json::Value> as Type>::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl OutputType for RawJson {
+    async fn resolve(
+        &self,
+        _ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        Value::from_raw_json(self.0.clone())
+            .map_err(|err| ServerError::new(format!("Invalid JSON: {}", err)).at(field.pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_raw_json_type() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn a(&self) -> i32 {
+                1
+            }
+
+            async fn raw(&self) -> RawJson {
+                RawJson(r#"{ "b": [1, 2.50, null], "c": "é" }"#.to_string())
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let res = schema.execute("{ a raw }").await.into_result().unwrap();
+        assert_eq!(
+            serde_json::to_string(&res.data).unwrap(),
+            r#"{"a":1,"raw":{ "b": [1, 2.50, null], "c": "é" }}"#
+        );
+        assert_eq!(
+            serde_json::to_value(&res.data).unwrap(),
+            serde_json::json!({ "a": 1, "raw": { "b": [1, 2.5, null], "c": "é" } })
+        );
+    }
+
+    #[async_std::test]
+    async fn test_raw_json_not_forgeable() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn echo(&self, value: Json<serde_json::Value>) -> Json<serde_json::Value> {
+                value
+            }
+
+            async fn invalid(&self) -> Option<RawJson> {
+                Some(RawJson("{ not json".to_string()))
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let res = schema
+            .execute(
+                Request::new("query($v: JSON!) { echo(value: $v) }").variables(
+                    Variables::from_json(serde_json::json!({
+                        "v": { "$serde_json::private::RawValue": "1, \"injected\": 2" }
+                    })),
+                ),
+            )
+            .await
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&res.data).unwrap(),
+            r#"{"echo":{"$serde_json::private::RawValue":"1, \"injected\": 2"}}"#
+        );
+
+        let res = schema.execute("{ invalid }").await;
+        assert_eq!(res.data, Value::Null);
+        assert!(res.errors[0].message.starts_with("Invalid JSON: "));
+    }
+}
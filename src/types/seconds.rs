@@ -0,0 +1,114 @@
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A `std::time::Duration` represented as a non-negative integer number of seconds.
+///
+/// Any fraction of a second is truncated in the output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord)]
+pub struct Seconds(pub Duration);
+
+impl Deref for Seconds {
+    type Target = Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Seconds {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Duration> for Seconds {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<Seconds> for Duration {
+    fn from(seconds: Seconds) -> Self {
+        seconds.0
+    }
+}
+
+/// A duration in seconds, represented as a non-negative integer.
+#[Scalar(internal, name = "Seconds")]
+impl ScalarType for Seconds {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Number(n) => match n.as_u64() {
+                Some(secs) => Ok(Seconds(Duration::from_secs(secs))),
+                None => Err(InputValueError::custom(format!(
+                    "the value {} is not a non-negative integer",
+                    n
+                ))),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::Number(n) if n.is_u64())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Number(self.0.as_secs().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_seconds() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, input: Seconds) -> Seconds {
+                input
+            }
+
+            async fn timeout(&self) -> Seconds {
+                Seconds(Duration::from_millis(90_500))
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute("{ value(input: 90) timeout }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "value": 90, "timeout": 90 })
+        );
+
+        assert_eq!(
+            <Seconds as ScalarType>::parse(value!(90)).unwrap(),
+            Seconds(Duration::from_secs(90))
+        );
+        assert_eq!(
+            ScalarType::to_value(&Seconds(Duration::from_secs(90))),
+            value!(90)
+        );
+        assert!(<Seconds as ScalarType>::parse(value!(-1)).is_err());
+        assert!(<Seconds as ScalarType>::parse(value!(1.5)).is_err());
+        assert!(<Seconds as ScalarType>::parse(value!("90")).is_err());
+
+        assert!(schema
+            .execute("{ value(input: -1) }")
+            .await
+            .into_result()
+            .is_err());
+    }
+}
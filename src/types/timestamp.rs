@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::ops::{Deref, DerefMut};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::{
+    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
+    ScalarType, ServerError, ServerResult, Type, Value,
+};
+
+/// A `std::time::SystemTime` represented as an integer number of milliseconds since the Unix
+/// epoch.
+///
+/// Times before the epoch are negative. Any fraction of a millisecond is rounded down in the
+/// output, and resolving a time too far from the epoch to fit in an `i64` is a field error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Timestamp(pub SystemTime);
+
+impl Deref for Timestamp {
+    type Target = SystemTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Timestamp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        Self(time)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl Timestamp {
+    /// Create a timestamp from milliseconds since the Unix epoch, or `None` if it is out of
+    /// range for `SystemTime`.
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        if millis >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_millis(millis as u64))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_millis(millis.wrapping_neg() as u64))
+        }
+        .map(Timestamp)
+    }
+
+    /// Returns the milliseconds since the Unix epoch, or `None` if they don't fit in an `i64`.
+    pub fn to_millis(&self) -> Option<i64> {
+        match self.0.duration_since(UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_millis()).ok(),
+            Err(err) => {
+                let duration = err.duration();
+                let mut millis = duration.as_millis();
+                if duration.subsec_nanos() % 1_000_000 != 0 {
+                    millis += 1;
+                }
+                i64::try_from(-(millis as i128)).ok()
+            }
+        }
+    }
+}
+
+impl ScalarType for Timestamp {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::Number(n) => match n.as_i64() {
+                Some(millis) => Timestamp::from_millis(millis).ok_or_else(|| {
+                    InputValueError::custom(format!("the timestamp {} is out of range", millis))
+                }),
+                None => Err(InputValueError::custom(format!(
+                    "the value {} is not an integer in the range of i64",
+                    n
+                ))),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::Number(n) if n.is_i64())
+    }
+
+    /// A time that doesn't fit in an `i64` is clamped, `OutputType::resolve` returns an error
+    /// instead.
+    fn to_value(&self) -> Value {
+        let millis = self.to_millis().unwrap_or_else(|| {
+            if self.0 < UNIX_EPOCH {
+                std::i64::MIN
+            } else {
+                std::i64::MAX
+            }
+        });
+        Value::Number(millis.into())
+    }
+}
+
+impl Type for Timestamp {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed("Timestamp")
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Timestamp, _>(|_| MetaType::Scalar {
+            name: "Timestamp".to_string(),
+            description: Some("Milliseconds since the Unix epoch, represented as an integer."),
+            is_valid: |value| <Timestamp as ScalarType>::is_valid(value),
+            visible: None,
+            specified_by_url: None,
+        })
+    }
+}
+
+impl InputType for Timestamp {
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Timestamp as ScalarType>::parse(value.unwrap_or_default())
+    }
+
+    fn to_value(&self) -> Value {
+        <Timestamp as ScalarType>::to_value(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl OutputType for Timestamp {
+    async fn resolve(
+        &self,
+        _: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        match self.to_millis() {
+            Some(millis) => Ok(Value::Number(millis.into())),
+            None => Err(ServerError::new(format!(
+                "Timestamp cannot represent a time out of the range of i64 milliseconds: {:?}",
+                self.0
+            ))
+            .at(field.pos)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::*;
+
+    #[async_std::test]
+    async fn test_timestamp() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, input: Timestamp) -> Timestamp {
+                input
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(
+            schema
+                .execute("{ a: value(input: 1600000000123) b: value(input: -86400001) }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "a": 1600000000123i64, "b": -86400001 })
+        );
+
+        let time = Timestamp(UNIX_EPOCH + Duration::from_millis(1_600_000_000_123));
+        assert_eq!(
+            <Timestamp as ScalarType>::parse(value!(1600000000123i64)).unwrap(),
+            time
+        );
+        assert_eq!(ScalarType::to_value(&time), value!(1600000000123i64));
+
+        // 1969-12-31T00:00:00Z
+        let time = Timestamp(UNIX_EPOCH - Duration::from_secs(86_400));
+        assert_eq!(
+            <Timestamp as ScalarType>::parse(value!(-86400000)).unwrap(),
+            time
+        );
+        assert_eq!(ScalarType::to_value(&time), value!(-86400000));
+
+        let time = Timestamp(UNIX_EPOCH - Duration::from_micros(1_500));
+        assert_eq!(ScalarType::to_value(&time), value!(-2));
+        assert_eq!(Timestamp::from_millis(-2).unwrap().to_millis(), Some(-2));
+
+        assert!(<Timestamp as ScalarType>::parse(value!(1.5)).is_err());
+        assert!(<Timestamp as ScalarType>::parse(value!("0")).is_err());
+        assert!(<Timestamp as ScalarType>::parse(value!(std::u64::MAX)).is_err());
+    }
+
+    #[async_std::test]
+    async fn test_timestamp_overflow() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self) -> Option<Timestamp> {
+                let time = UNIX_EPOCH.checked_add(Duration::from_secs(std::i64::MAX as u64))?;
+                Some(Timestamp(time))
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        if let Some(time) = UNIX_EPOCH.checked_add(Duration::from_secs(std::i64::MAX as u64)) {
+            let res = schema.execute("{ value }").await;
+            assert_eq!(res.data, Value::Null);
+            assert_eq!(
+                res.errors,
+                vec![ServerError {
+                    message: format!(
+                        "Timestamp cannot represent a time out of the range of i64 milliseconds: {:?}",
+                        time
+                    ),
+                    locations: vec![Pos { line: 1, column: 3 }],
+                    path: vec![PathSegment::Field("value".to_string())],
+                    extensions: None,
+                }]
+            );
+        }
+    }
+}
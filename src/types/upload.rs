@@ -61,6 +61,10 @@ impl UploadValue {
 /// Enable this feature by accepting an argument of type `Upload` (single file) or
 /// `Vec<Upload>` (multiple files) in your mutation like in the example blow.
 ///
+/// Optional uploads work too: an `Option<Upload>` argument is `None` when its variable is
+/// omitted or `null`, and a `Vec<Option<Upload>>` has `None` in every slot that no file was
+/// mapped to.
+///
 ///
 /// # Example
 /// *[Full Example](<https://github.com/async-graphql/examples/blob/master/models/files/src/lib.rs>)*
@@ -73,7 +77,12 @@ impl UploadValue {
 /// #[Object]
 /// impl MutationRoot {
 ///     async fn upload(&self, ctx: &Context<'_>, file: Upload) -> bool {
-///         println!("upload: filename={}", file.value(ctx).unwrap().filename);
+///         println!(
+///             "upload: filename={:?} content_type={:?} size={:?}",
+///             file.filename(ctx),
+///             file.content_type(ctx),
+///             file.size(ctx),
+///         );
 ///         true
 ///     }
 /// }
@@ -96,7 +105,37 @@ pub struct Upload(usize);
 impl Upload {
     /// Get the upload value.
     pub fn value(&self, ctx: &Context<'_>) -> std::io::Result<UploadValue> {
-        ctx.query_env.uploads[self.0].try_clone()
+        ctx.query_env
+            .uploads
+            .get(self.0)
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::NotFound, "the upload does not exist")
+            })?
+            .try_clone()
+    }
+
+    fn get<'a>(&self, ctx: &'a Context<'_>) -> Option<&'a UploadValue> {
+        ctx.query_env.uploads.get(self.0)
+    }
+
+    /// Returns the name of the file, or `None` if the upload does not exist.
+    pub fn filename<'a>(&self, ctx: &'a Context<'_>) -> Option<&'a str> {
+        self.get(ctx).map(|upload| upload.filename.as_str())
+    }
+
+    /// Returns the content type declared in the headers of the multipart part, or `None` if it
+    /// was not declared or the upload does not exist.
+    ///
+    /// The content type is sent by the client and is not checked against the content of the file.
+    pub fn content_type<'a>(&self, ctx: &'a Context<'_>) -> Option<&'a str> {
+        self.get(ctx)
+            .and_then(|upload| upload.content_type.as_deref())
+    }
+
+    /// Returns the size of the file in bytes, or `None` if the upload does not exist or its size
+    /// could not be read.
+    pub fn size(&self, ctx: &Context<'_>) -> Option<u64> {
+        self.get(ctx).and_then(|upload| upload.size().ok())
     }
 }
 
@@ -111,6 +150,7 @@ impl Type for Upload {
             description: None,
             is_valid: |value| matches!(value, Value::String(_)),
             visible: None,
+            specified_by_url: None,
         })
     }
 }
@@ -120,8 +160,8 @@ impl InputType for Upload {
         const PREFIX: &str = "#__graphql_file__:";
         let value = value.unwrap_or_default();
         if let Value::String(s) = &value {
-            if let Some(filename) = s.strip_prefix(PREFIX) {
-                return Ok(Upload(filename.parse::<usize>().unwrap()));
+            if let Some(index) = s.strip_prefix(PREFIX).and_then(|s| s.parse::<usize>().ok()) {
+                return Ok(Upload(index));
             }
         }
         Err(InputValueError::expected_type(value))
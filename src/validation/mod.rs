@@ -8,12 +8,15 @@ mod utils;
 mod visitor;
 mod visitors;
 
-use crate::parser::types::ExecutableDocument;
+use std::collections::HashMap;
+
+use crate::parser::types::{DocumentOperations, ExecutableDocument};
 use crate::registry::Registry;
-use crate::{CacheControl, ServerError, Variables};
+use crate::{CacheControl, Pos, ServerError, Variables};
 
 pub use visitor::VisitorContext;
 use visitor::{visit, VisitorNil};
+pub(crate) use visitors::REDACTED;
 
 /// Validation results.
 pub struct ValidationResult {
@@ -23,14 +26,23 @@ pub struct ValidationResult {
     /// Query complexity
     pub complexity: usize,
 
+    /// Position of the operation whose complexity was calculated
+    pub complexity_pos: Option<Pos>,
+
     /// Query depth
     pub depth: usize,
+
+    /// Position of the first field at the maximum depth
+    pub depth_pos: Option<Pos>,
 }
 
 /// Validation mode
 #[derive(Copy, Clone, Debug)]
 pub enum ValidationMode {
     /// Execute all validation rules.
+    ///
+    /// The whole document is visited, so every validation error is reported with its position
+    /// instead of only the first one.
     Strict,
 
     /// The executor itself also has error handling, so it can improve performance, but it can lose some error messages.
@@ -41,12 +53,16 @@ pub fn check_rules(
     registry: &Registry,
     doc: &ExecutableDocument,
     variables: Option<&Variables>,
+    operation_name: Option<&str>,
     mode: ValidationMode,
 ) -> Result<ValidationResult, Vec<ServerError>> {
     let mut ctx = VisitorContext::new(registry, doc, variables);
+    ctx.operation_name = operation_name;
     let mut cache_control = CacheControl::default();
     let mut complexity = 0;
+    let mut complexity_pos = None;
     let mut depth = 0;
+    let mut depth_pos = None;
 
     match mode {
         ValidationMode::Strict => {
@@ -75,8 +91,11 @@ pub fn check_rules(
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
-                .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::ComplexityCalculate::new(
+                    &mut complexity,
+                    &mut complexity_pos,
+                ))
+                .with(visitors::DepthCalculate::new(&mut depth, &mut depth_pos));
             visit(&mut visitor, &mut ctx, doc);
         }
         ValidationMode::Fast => {
@@ -86,8 +105,11 @@ pub fn check_rules(
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
-                .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::ComplexityCalculate::new(
+                    &mut complexity,
+                    &mut complexity_pos,
+                ))
+                .with(visitors::DepthCalculate::new(&mut depth, &mut depth_pos));
             visit(&mut visitor, &mut ctx, doc);
         }
     }
@@ -99,6 +121,38 @@ pub fn check_rules(
     Ok(ValidationResult {
         cache_control,
         complexity,
+        complexity_pos,
         depth,
+        depth_pos,
     })
 }
+
+/// Returns the query, the document and the variables with the values of the secret arguments and
+/// input fields replaced, so that extensions can log them.
+///
+/// The query is printed from the redacted document rather than edited in place. If a secret
+/// value can't be found in the document, the query is replaced by `"[redacted]"` and the document
+/// by an empty one, so that nothing is passed on unredacted.
+pub(crate) fn redact_secrets(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    variables: &Variables,
+) -> (String, ExecutableDocument, Variables) {
+    let mut ctx = VisitorContext::new(registry, doc, Some(variables));
+    let mut visitor = visitors::SecretArguments::default();
+    visit(&mut visitor, &mut ctx, doc);
+    let variables = visitors::redact_variables(registry, &visitor, variables);
+    match visitors::redact_document(doc, &visitor.literals) {
+        Some(doc) => (visitors::print_document(&doc), doc, variables),
+        None => (visitors::REDACTED.to_string(), empty_document(), variables),
+    }
+}
+
+/// Returns a document without operations or fragments, which stands in for a document that can't
+/// be passed to extensions.
+fn empty_document() -> ExecutableDocument {
+    ExecutableDocument {
+        operations: DocumentOperations::Multiple(HashMap::new()),
+        fragments: HashMap::new(),
+    }
+}
@@ -6,7 +6,7 @@ use crate::registry::MetaInputValue;
 use crate::validation::utils::is_valid_input_value;
 use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::{Name, Positioned, QueryPathSegment};
-use async_graphql_value::Value;
+use async_graphql_value::{ConstValue, Value};
 
 #[derive(Default)]
 pub struct ArgumentsOfCorrectType<'a> {
@@ -56,7 +56,11 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
                 .ok();
 
             if let Some(validator) = &arg.validator {
-                if let Some(value) = &value {
+                // Validators only check concrete values, an explicit `null` is left to the type.
+                if let Some(value) = value
+                    .as_ref()
+                    .filter(|value| !matches!(value, ConstValue::Null))
+                {
                     if let Err(reason) = validator.is_valid(value) {
                         ctx.report_error(
                             vec![name.pos],
@@ -12,7 +12,9 @@ impl<'a> Visitor<'a> for FieldsOnCorrectType {
             if let Some(registry::MetaType::Union { .. })
             | Some(registry::MetaType::Interface { .. }) = ctx.parent_type()
             {
-                if field.node.name.node == "__typename" {
+                if field.node.name.node == "__typename"
+                    || ctx.registry.typename_alias.as_deref() == Some(field.node.name.node.as_str())
+                {
                     return;
                 }
             }
@@ -119,16 +119,50 @@ pub fn is_valid_input_value(
                 registry::MetaType::InputObject {
                     input_fields,
                     name: object_name,
+                    one_of,
                     ..
                 } => match value {
                     ConstValue::Object(values) => {
+                        if *one_of {
+                            let fields = values
+                                .iter()
+                                .filter(|(_, value)| !matches!(value, ConstValue::Null))
+                                .map(|(name, _)| name.as_str())
+                                .collect::<Vec<_>>();
+                            if let Some(reason) = registry::check_one_of_fields(&fields) {
+                                return Some(valid_error(&path_node, reason));
+                            }
+                        }
+
                         let mut input_names =
                             values.keys().map(AsRef::as_ref).collect::<HashSet<_>>();
 
                         for field in input_fields.values() {
                             input_names.remove(field.name);
-                            if let Some(value) = values.get(field.name) {
-                                if let Some(validator) = &field.validator {
+                            for alias in field.aliases {
+                                input_names.remove(alias);
+                            }
+                            let mut provided = std::iter::once(&field.name)
+                                .chain(field.aliases)
+                                .filter(|name| values.contains_key(**name));
+                            let value = match (provided.next(), provided.next()) {
+                                (Some(first), Some(second)) => {
+                                    return Some(valid_error(
+                                        &path_node,
+                                        format!(
+                                            "field \"{}\" of type \"{}\" is provided as both \"{}\" and \"{}\"",
+                                            field.name, object_name, first, second
+                                        ),
+                                    ));
+                                }
+                                (name, _) => name.and_then(|name| values.get(*name)),
+                            };
+                            if let Some(value) = value {
+                                if let Some(validator) = field
+                                    .validator
+                                    .as_ref()
+                                    .filter(|_| !matches!(value, ConstValue::Null))
+                                {
                                     if let Err(reason) = validator.is_valid(value) {
                                         return Some(valid_error(
                                             &QueryPathNode {
@@ -14,6 +14,8 @@ use crate::{InputType, Name, Pos, Positioned, ServerError, ServerResult, Variabl
 pub struct VisitorContext<'a> {
     pub(crate) registry: &'a registry::Registry,
     pub(crate) variables: Option<&'a Variables>,
+    /// The name of the operation that will be executed, the variables are only used in it.
+    pub(crate) operation_name: Option<&'a str>,
     pub(crate) errors: Vec<RuleError>,
     type_stack: Vec<Option<&'a registry::MetaType>>,
     input_type: Vec<Option<MetaTypeName<'a>>>,
@@ -29,6 +31,7 @@ impl<'a> VisitorContext<'a> {
         Self {
             registry,
             variables,
+            operation_name: None,
             errors: Default::default(),
             type_stack: Default::default(),
             input_type: Default::default(),
@@ -547,6 +550,15 @@ fn visit_operation_definition<'a, V: Visitor<'a>>(
     name: Option<&'a Name>,
     operation: &'a Positioned<OperationDefinition>,
 ) {
+    // The variables belong to the executed operation, other operations may declare variables
+    // with the same names but other types.
+    let variables = ctx.variables;
+    if let (Some(operation_name), Some(name)) = (ctx.operation_name, name) {
+        if name != operation_name {
+            ctx.variables = None;
+        }
+    }
+
     v.enter_operation_definition(ctx, name, operation);
     let root_name = match &operation.node.ty {
         OperationType::Query => Some(&*ctx.registry.query_type),
@@ -567,6 +579,7 @@ fn visit_operation_definition<'a, V: Visitor<'a>>(
         );
     }
     v.exit_operation_definition(ctx, name, operation);
+    ctx.variables = variables;
 }
 
 fn visit_selection_set<'a, V: Visitor<'a>>(
@@ -684,7 +697,12 @@ fn visit_input_value<'a, V: Visitor<'a>>(
                         .get(MetaTypeName::concrete_typename(expected_ty))
                     {
                         for (item_key, item_value) in values {
-                            if let Some(input_value) = input_fields.get(item_key.as_str()) {
+                            let input_value = input_fields.get(item_key.as_str()).or_else(|| {
+                                input_fields
+                                    .values()
+                                    .find(|field| field.aliases.contains(&item_key.as_str()))
+                            });
+                            if let Some(input_value) = input_value {
                                 visit_input_value(
                                     v,
                                     ctx,
@@ -766,7 +784,12 @@ fn visit_fragment_spread<'a, V: Visitor<'a>>(
             .fragments
             .get(fragment_spread.node.fragment_name.node.as_str())
         {
-            visit_selection_set(v, ctx, &fragment.node.selection_set);
+            ctx.with_type(
+                ctx.registry
+                    .types
+                    .get(fragment.node.type_condition.node.on.node.as_str()),
+                |ctx| visit_selection_set(v, ctx, &fragment.node.selection_set),
+            );
         }
     }
     v.exit_fragment_spread(ctx, fragment_spread);
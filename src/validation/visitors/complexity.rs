@@ -1,25 +1,97 @@
-use async_graphql_parser::types::{ExecutableDocument, OperationDefinition, VariableDefinition};
+use std::collections::BTreeMap;
+
+use async_graphql_parser::types::{
+    ExecutableDocument, FragmentSpread, InlineFragment, OperationDefinition, VariableDefinition,
+};
 use async_graphql_value::Name;
 
 use crate::parser::types::Field;
 use crate::registry::{ComplexityType, MetaType, MetaTypeName};
 use crate::validation::visitor::{VisitMode, Visitor, VisitorContext};
-use crate::Positioned;
+use crate::{Pos, Positioned};
+
+/// The complexity of a selection set.
+///
+/// When the selection set is on an interface or a union, fragments on its possible types are
+/// counted per type in `possible_types`, and only the most expensive type contributes to the
+/// total, since a single value can only ever be one of them.
+#[derive(Default)]
+pub struct Complexity<'a> {
+    common: usize,
+    possible_types: BTreeMap<&'a str, usize>,
+}
+
+impl<'a> Complexity<'a> {
+    fn total(&self) -> usize {
+        self.common
+            + self
+                .possible_types
+                .values()
+                .copied()
+                .max()
+                .unwrap_or_default()
+    }
+
+    fn merge(&mut self, other: Complexity<'a>) {
+        self.common += other.common;
+        for (name, n) in other.possible_types {
+            *self.possible_types.entry(name).or_default() += n;
+        }
+    }
+
+    /// Add the complexity of a fragment with the type condition `on` to this selection set on
+    /// `ty`.
+    fn add_fragment(
+        &mut self,
+        ty: Option<&'a MetaType>,
+        on: Option<&'a MetaType>,
+        fragment: Complexity<'a>,
+    ) {
+        let (ty, on) = match (ty, on) {
+            (Some(ty), Some(on)) if ty.is_abstract() && ty.name() != on.name() => (ty, on),
+            _ => return self.merge(fragment),
+        };
+
+        match on.possible_types() {
+            Some(on_possible_types) => {
+                for name in on_possible_types {
+                    if ty.is_possible_type(name) {
+                        *self.possible_types.entry(name.as_str()).or_default() += fragment.common
+                            + fragment
+                                .possible_types
+                                .get(name.as_str())
+                                .copied()
+                                .unwrap_or_default();
+                    }
+                }
+            }
+            None => {
+                *self.possible_types.entry(on.name()).or_default() += fragment.total();
+            }
+        }
+    }
+}
 
 pub struct ComplexityCalculate<'ctx, 'a> {
     pub complexity: &'a mut usize,
-    pub complexity_stack: Vec<usize>,
+    pub complexity_pos: &'a mut Option<Pos>,
+    pub complexity_stack: Vec<Complexity<'ctx>>,
     pub variable_definition: Option<&'ctx [Positioned<VariableDefinition>]>,
 }
 
 impl<'ctx, 'a> ComplexityCalculate<'ctx, 'a> {
-    pub fn new(complexity: &'a mut usize) -> Self {
+    pub fn new(complexity: &'a mut usize, complexity_pos: &'a mut Option<Pos>) -> Self {
         Self {
             complexity,
+            complexity_pos,
             complexity_stack: Default::default(),
             variable_definition: None,
         }
     }
+
+    fn add(&mut self, n: usize) {
+        self.complexity_stack.last_mut().unwrap().common += n;
+    }
 }
 
 impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
@@ -28,11 +100,11 @@ impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
     }
 
     fn enter_document(&mut self, _ctx: &mut VisitorContext<'ctx>, _doc: &'ctx ExecutableDocument) {
-        self.complexity_stack.push(0);
+        self.complexity_stack.push(Default::default());
     }
 
     fn exit_document(&mut self, _ctx: &mut VisitorContext<'ctx>, _doc: &'ctx ExecutableDocument) {
-        *self.complexity = self.complexity_stack.pop().unwrap();
+        *self.complexity = self.complexity_stack.pop().unwrap().total();
     }
 
     fn enter_operation_definition(
@@ -42,23 +114,24 @@ impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
         operation_definition: &'ctx Positioned<OperationDefinition>,
     ) {
         self.variable_definition = Some(&operation_definition.node.variable_definitions);
+        *self.complexity_pos = Some(operation_definition.pos);
     }
 
     fn enter_field(&mut self, _ctx: &mut VisitorContext<'_>, _field: &Positioned<Field>) {
-        self.complexity_stack.push(0);
+        self.complexity_stack.push(Default::default());
     }
 
     fn exit_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
-        let children_complex = self.complexity_stack.pop().unwrap();
+        let children_complex = self.complexity_stack.pop().unwrap().total();
 
-        if let Some(MetaType::Object { fields, .. }) = ctx.parent_type() {
+        if let Some(fields) = ctx.parent_type().and_then(MetaType::fields) {
             if let Some(meta_field) = fields.get(MetaTypeName::concrete_typename(
                 field.node.name.node.as_str(),
             )) {
                 if let Some(compute_complexity) = &meta_field.compute_complexity {
                     match compute_complexity {
                         ComplexityType::Const(n) => {
-                            *self.complexity_stack.last_mut().unwrap() += n;
+                            self.add(*n);
                         }
                         ComplexityType::Fn(f) => {
                             if MetaTypeName::create(&meta_field.ty).is_list() {
@@ -68,9 +141,7 @@ impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
                                     &field.node,
                                     children_complex,
                                 ) {
-                                    Ok(n) => {
-                                        *self.complexity_stack.last_mut().unwrap() += n;
-                                    }
+                                    Ok(n) => self.add(n),
                                     Err(err) => ctx.report_error(vec![field.pos], err.to_string()),
                                 }
                             }
@@ -82,7 +153,55 @@ impl<'ctx, 'a> Visitor<'ctx> for ComplexityCalculate<'ctx, 'a> {
             }
         }
 
-        *self.complexity_stack.last_mut().unwrap() += 1 + children_complex;
+        self.add(1 + children_complex);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _fragment_spread: &'ctx Positioned<FragmentSpread>,
+    ) {
+        self.complexity_stack.push(Default::default());
+    }
+
+    fn exit_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'ctx>,
+        fragment_spread: &'ctx Positioned<FragmentSpread>,
+    ) {
+        let fragment = self.complexity_stack.pop().unwrap();
+        let on = ctx
+            .fragment(fragment_spread.node.fragment_name.node.as_str())
+            .and_then(|fragment| {
+                ctx.registry
+                    .types
+                    .get(fragment.node.type_condition.node.on.node.as_str())
+            });
+        self.complexity_stack
+            .last_mut()
+            .unwrap()
+            .add_fragment(ctx.current_type(), on, fragment);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _inline_fragment: &'ctx Positioned<InlineFragment>,
+    ) {
+        self.complexity_stack.push(Default::default());
+    }
+
+    fn exit_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'ctx>,
+        _inline_fragment: &'ctx Positioned<InlineFragment>,
+    ) {
+        let fragment = self.complexity_stack.pop().unwrap();
+        self.complexity_stack.last_mut().unwrap().add_fragment(
+            ctx.parent_type(),
+            ctx.current_type(),
+            fragment,
+        );
     }
 }
 
@@ -91,11 +210,20 @@ mod tests {
     use super::*;
     use crate::parser::parse_query;
     use crate::validation::{visit, VisitorContext};
-    use crate::{EmptyMutation, Object, Schema, Subscription};
+    use crate::{EmptyMutation, Interface, Object, Schema, SimpleObject, Subscription, Union};
     use futures_util::stream::BoxStream;
 
     struct Query;
 
+    #[derive(SimpleObject)]
+    #[graphql(internal)]
+    struct Page {
+        #[graphql(complexity = 5)]
+        total: i32,
+        #[graphql(complexity = "child_complexity * 10")]
+        objs: Vec<MyObj>,
+    }
+
     #[derive(Copy, Clone)]
     struct MyObj;
 
@@ -115,6 +243,36 @@ mod tests {
         }
     }
 
+    #[derive(SimpleObject)]
+    #[graphql(internal)]
+    struct Dog {
+        name: i32,
+        #[graphql(complexity = 10)]
+        bark: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(internal)]
+    struct Cat {
+        name: i32,
+        #[graphql(complexity = 3)]
+        meow: i32,
+    }
+
+    #[derive(Interface)]
+    #[graphql(internal, field(name = "name", type = "&i32"))]
+    enum Animal {
+        Dog(Dog),
+        Cat(Cat),
+    }
+
+    #[derive(Union)]
+    #[graphql(internal)]
+    enum Pet {
+        Dog(Dog),
+        Cat(Cat),
+    }
+
     #[Object(internal)]
     #[allow(unreachable_code)]
     impl Query {
@@ -136,6 +294,24 @@ mod tests {
         async fn d(&self) -> MyObj {
             todo!()
         }
+
+        #[graphql(complexity = "child_complexity * first")]
+        #[allow(unused_variables)]
+        async fn items(&self, first: usize) -> Vec<MyObj> {
+            todo!()
+        }
+
+        async fn page(&self) -> Page {
+            todo!()
+        }
+
+        async fn animal(&self) -> Animal {
+            todo!()
+        }
+
+        async fn pet(&self) -> Pet {
+            todo!()
+        }
     }
 
     struct Subscription;
@@ -170,7 +346,8 @@ mod tests {
         let doc = parse_query(query).unwrap();
         let mut ctx = VisitorContext::new(&registry, &doc, None);
         let mut complex = 0;
-        let mut complex_calculate = ComplexityCalculate::new(&mut complex);
+        let mut complex_pos = None;
+        let mut complex_calculate = ComplexityCalculate::new(&mut complex, &mut complex_pos);
         visit(&mut complex_calculate, &mut ctx, &doc);
         assert_eq!(complex, expect_complex);
     }
@@ -290,6 +467,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn complex_field_weights() {
+        check_complex(
+            r#"
+        {
+            items(first: 100) { # 100 * 2
+                a b
+            }
+        }"#,
+            200,
+        );
+
+        check_complex(
+            r#"
+        {
+            page { # 1
+                total # 5
+                objs { # 10 * 2
+                    a b
+                }
+            }
+        }"#,
+            26,
+        );
+    }
+
+    #[test]
+    fn complex_abstract_type() {
+        check_complex(
+            r#"
+        {
+            animal { # 1
+                name # 1
+                ... on Dog { bark } # 10
+                ... on Cat { meow } # 3
+            }
+        }"#,
+            12,
+        );
+
+        check_complex(
+            r#"
+        {
+            animal { # 1
+                ... on Dog { name bark } # 11
+                ... on Dog { name } # 1
+                ... on Cat { name meow } # 4
+            }
+        }"#,
+            13,
+        );
+
+        check_complex(
+            r#"
+        fragment D on Dog {
+            bark # 10
+        }
+
+        fragment C on Cat {
+            name meow # 4
+        }
+
+        {
+            pet { # 1
+                ... D
+                ... C
+            }
+        }"#,
+            11,
+        );
+
+        check_complex(
+            r#"
+        {
+            pet { # 1
+                ... on Animal { name } # 1 for both
+                ... on Dog { bark } # 10
+                ... on Cat { meow } # 3
+            }
+        }"#,
+            12,
+        );
+
+        check_complex(
+            r#"
+        {
+            animal { # 1
+                ... on Animal { name } # 1
+            }
+        }"#,
+            2,
+        );
+    }
+
     #[test]
     fn complex_subscription() {
         check_complex(
@@ -1,16 +1,18 @@
 use crate::validation::visitor::{VisitMode, Visitor, VisitorContext};
-use crate::Positioned;
+use crate::{Pos, Positioned};
 use async_graphql_parser::types::Field;
 
 pub struct DepthCalculate<'a> {
     max_depth: &'a mut usize,
+    max_depth_pos: &'a mut Option<Pos>,
     current_depth: usize,
 }
 
 impl<'a> DepthCalculate<'a> {
-    pub fn new(max_depth: &'a mut usize) -> Self {
+    pub fn new(max_depth: &'a mut usize, max_depth_pos: &'a mut Option<Pos>) -> Self {
         Self {
             max_depth,
+            max_depth_pos,
             current_depth: 0,
         }
     }
@@ -21,9 +23,12 @@ impl<'ctx, 'a> Visitor<'ctx> for DepthCalculate<'a> {
         VisitMode::Inline
     }
 
-    fn enter_field(&mut self, _ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
+    fn enter_field(&mut self, _ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
         self.current_depth += 1;
-        *self.max_depth = (*self.max_depth).max(self.current_depth);
+        if self.current_depth > *self.max_depth {
+            *self.max_depth = self.current_depth;
+            *self.max_depth_pos = Some(field.pos);
+        }
     }
 
     fn exit_field(&mut self, _ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
@@ -75,7 +80,8 @@ mod tests {
         let doc = parse_query(query).unwrap();
         let mut ctx = VisitorContext::new(&registry, &doc, None);
         let mut depth = 0;
-        let mut depth_calculate = DepthCalculate::new(&mut depth);
+        let mut depth_pos = None;
+        let mut depth_calculate = DepthCalculate::new(&mut depth, &mut depth_pos);
         visit(&mut depth_calculate, &mut ctx, &doc);
         assert_eq!(depth, expect_depth);
     }
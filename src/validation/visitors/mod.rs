@@ -1,7 +1,10 @@
 mod cache_control;
 mod complexity;
 mod depth;
+mod secrets;
 
 pub use cache_control::CacheControlCalculate;
 pub use complexity::ComplexityCalculate;
 pub use depth::DepthCalculate;
+pub use secrets::SecretArguments;
+pub(crate) use secrets::{print_document, redact_document, redact_variables, REDACTED};
@@ -0,0 +1,519 @@
+use std::fmt::Write;
+
+use indexmap::map::IndexMap;
+
+use crate::parser::types::{
+    Directive, DocumentOperations, ExecutableDocument, Field, OperationDefinition, OperationType,
+    Selection, SelectionSet, VariableDefinition,
+};
+use crate::registry::{MetaInputValue, MetaType, MetaTypeName, Registry};
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Name, Pos, Positioned, Variables};
+use async_graphql_value::{ConstValue, Value};
+
+/// The marker that replaces secret values.
+pub const REDACTED: &str = "[redacted]";
+
+/// A step from a literal to one of the values inside it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiteralStep {
+    /// The value of a field of an object.
+    Field(String),
+    /// An item of a list.
+    Index(usize),
+}
+
+/// Collects the values of secret arguments and input fields in a document.
+///
+/// Variables bound to a secret are collected by name, inline literals by the position of the
+/// argument or variable default that contains them and the steps from there to the secret.
+#[derive(Default)]
+pub struct SecretArguments<'a> {
+    current_args: Option<&'a IndexMap<&'static str, MetaInputValue>>,
+    pub variable_types: Vec<(&'a Name, String, Option<&'a Positioned<ConstValue>>)>,
+    pub variables: Vec<&'a Name>,
+    pub literals: Vec<(Pos, Vec<LiteralStep>)>,
+}
+
+impl<'a> Visitor<'a> for SecretArguments<'a> {
+    fn enter_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        self.variable_types.push((
+            &variable_definition.node.name.node,
+            variable_definition.node.var_type.node.to_string(),
+            variable_definition.node.default_value.as_ref(),
+        ));
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+        self.current_args = ctx
+            .registry
+            .directives
+            .get(directive.node.name.node.as_str())
+            .map(|d| &d.args);
+    }
+
+    fn exit_directive(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _directive: &'a Positioned<Directive>,
+    ) {
+        self.current_args = None;
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+        let arg = match self
+            .current_args
+            .and_then(|args| args.get(name.node.as_str()))
+        {
+            Some(arg) => arg,
+            None => return,
+        };
+
+        self.collect(
+            ctx.registry,
+            value.pos,
+            &mut Vec::new(),
+            arg.is_secret,
+            &arg.ty,
+            &value.node,
+        );
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|p| p.field_by_name(&field.node.name.node))
+            .map(|f| &f.args);
+    }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
+        self.current_args = None;
+    }
+
+    fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
+        for (name, ty, default_value) in &self.variable_types {
+            let default_value = match default_value {
+                Some(default_value) => default_value,
+                None => continue,
+            };
+            if self.variables.contains(name) {
+                self.literals.push((default_value.pos, Vec::new()));
+            } else {
+                collect_const(
+                    ctx.registry,
+                    default_value.pos,
+                    &mut Vec::new(),
+                    ty,
+                    &default_value.node,
+                    &mut self.literals,
+                );
+            }
+        }
+    }
+}
+
+impl<'a> SecretArguments<'a> {
+    /// Collects the variables bound to secrets in `value`, and the secret literals in it, at
+    /// `steps` from the literal at `pos`.
+    fn collect(
+        &mut self,
+        registry: &Registry,
+        pos: Pos,
+        steps: &mut Vec<LiteralStep>,
+        secret: bool,
+        ty: &str,
+        value: &'a Value,
+    ) {
+        if let Value::Variable(name) = value {
+            if secret {
+                self.variables.push(name);
+            }
+            return;
+        }
+        if secret {
+            self.literals.push((pos, steps.clone()));
+            return;
+        }
+
+        match (MetaTypeName::create(ty).unwrap_non_null(), value) {
+            (MetaTypeName::List(ty), Value::List(items)) => {
+                for (idx, item) in items.iter().enumerate() {
+                    steps.push(LiteralStep::Index(idx));
+                    self.collect(registry, pos, steps, false, ty, item);
+                    steps.pop();
+                }
+            }
+            (MetaTypeName::Named(ty), Value::Object(fields)) => {
+                let input_fields = match registry.types.get(ty) {
+                    Some(MetaType::InputObject { input_fields, .. }) => input_fields,
+                    _ => return,
+                };
+                for (name, value) in fields {
+                    if let Some(field) = input_field(input_fields, name.as_str()) {
+                        steps.push(LiteralStep::Field(name.to_string()));
+                        self.collect(registry, pos, steps, field.is_secret, &field.ty, value);
+                        steps.pop();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collects the secret input fields in a variable default of type `ty`.
+fn collect_const(
+    registry: &Registry,
+    pos: Pos,
+    steps: &mut Vec<LiteralStep>,
+    ty: &str,
+    value: &ConstValue,
+    literals: &mut Vec<(Pos, Vec<LiteralStep>)>,
+) {
+    match (MetaTypeName::create(ty).unwrap_non_null(), value) {
+        (MetaTypeName::List(ty), ConstValue::List(items)) => {
+            for (idx, item) in items.iter().enumerate() {
+                steps.push(LiteralStep::Index(idx));
+                collect_const(registry, pos, steps, ty, item, literals);
+                steps.pop();
+            }
+        }
+        (MetaTypeName::Named(ty), ConstValue::Object(fields)) => {
+            if let Some(MetaType::InputObject { input_fields, .. }) = registry.types.get(ty) {
+                for (name, value) in fields {
+                    if let Some(field) = input_field(input_fields, name.as_str()) {
+                        steps.push(LiteralStep::Field(name.to_string()));
+                        if field.is_secret {
+                            literals.push((pos, steps.clone()));
+                        } else {
+                            collect_const(registry, pos, steps, &field.ty, value, literals);
+                        }
+                        steps.pop();
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn input_field<'a>(
+    input_fields: &'a IndexMap<String, MetaInputValue>,
+    name: &str,
+) -> Option<&'a MetaInputValue> {
+    input_fields.get(name).or_else(|| {
+        input_fields
+            .values()
+            .find(|field| field.aliases.contains(&name))
+    })
+}
+
+/// Replaces the secret input fields in the value of a variable of type `ty`.
+pub fn redact_variable(registry: &Registry, ty: &str, value: &mut ConstValue) {
+    match (MetaTypeName::create(ty).unwrap_non_null(), value) {
+        (MetaTypeName::List(ty), ConstValue::List(items)) => {
+            for item in items {
+                redact_variable(registry, ty, item);
+            }
+        }
+        (MetaTypeName::Named(ty), ConstValue::Object(fields)) => {
+            if let Some(MetaType::InputObject { input_fields, .. }) = registry.types.get(ty) {
+                for (name, value) in fields.iter_mut() {
+                    if let Some(field) = input_field(input_fields, name.as_str()) {
+                        if field.is_secret {
+                            *value = ConstValue::String(REDACTED.to_string());
+                        } else {
+                            redact_variable(registry, &field.ty, value);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns a copy of `doc` with the values at `steps` from the literals at the given positions
+/// replaced, or `None` if any of them can't be found.
+pub fn redact_document(
+    doc: &ExecutableDocument,
+    literals: &[(Pos, Vec<LiteralStep>)],
+) -> Option<ExecutableDocument> {
+    let mut doc = doc.clone();
+    let mut redacted = 0;
+    let mut redact = |pos: Pos, value: &mut Value| {
+        for (_, steps) in literals
+            .iter()
+            .filter(|(literal_pos, _)| *literal_pos == pos)
+        {
+            if redact_value(value, steps) {
+                redacted += 1;
+            }
+        }
+    };
+
+    let operations: Vec<&mut Positioned<OperationDefinition>> = match &mut doc.operations {
+        DocumentOperations::Single(operation) => vec![operation],
+        DocumentOperations::Multiple(operations) => operations.values_mut().collect(),
+    };
+    for operation in operations {
+        for variable_definition in &mut operation.node.variable_definitions {
+            if let Some(default_value) = &mut variable_definition.node.default_value {
+                let mut value = default_value.node.clone().into_value();
+                redact(default_value.pos, &mut value);
+                default_value.node = value.into_const()?;
+            }
+        }
+        redact_directives(&mut operation.node.directives, &mut redact);
+        redact_selection_set(&mut operation.node.selection_set.node, &mut redact);
+    }
+    for fragment in doc.fragments.values_mut() {
+        redact_directives(&mut fragment.node.directives, &mut redact);
+        redact_selection_set(&mut fragment.node.selection_set.node, &mut redact);
+    }
+
+    if redacted == literals.len() {
+        Some(doc)
+    } else {
+        None
+    }
+}
+
+fn redact_selection_set(
+    selection_set: &mut SelectionSet,
+    redact: &mut impl FnMut(Pos, &mut Value),
+) {
+    for selection in &mut selection_set.items {
+        match &mut selection.node {
+            Selection::Field(field) => {
+                for (_, value) in &mut field.node.arguments {
+                    redact(value.pos, &mut value.node);
+                }
+                redact_directives(&mut field.node.directives, redact);
+                redact_selection_set(&mut field.node.selection_set.node, redact);
+            }
+            Selection::FragmentSpread(spread) => {
+                redact_directives(&mut spread.node.directives, redact);
+            }
+            Selection::InlineFragment(fragment) => {
+                redact_directives(&mut fragment.node.directives, redact);
+                redact_selection_set(&mut fragment.node.selection_set.node, redact);
+            }
+        }
+    }
+}
+
+fn redact_directives(
+    directives: &mut [Positioned<Directive>],
+    redact: &mut impl FnMut(Pos, &mut Value),
+) {
+    for directive in directives {
+        for (_, value) in &mut directive.node.arguments {
+            redact(value.pos, &mut value.node);
+        }
+    }
+}
+
+/// Replaces the value at `steps` from `value`, returns `false` if there is no such value.
+fn redact_value(value: &mut Value, steps: &[LiteralStep]) -> bool {
+    match (steps.split_first(), value) {
+        (None, value) => {
+            *value = Value::String(REDACTED.to_string());
+            true
+        }
+        (Some((LiteralStep::Index(idx), steps)), Value::List(items)) => items
+            .get_mut(*idx)
+            .map_or(false, |item| redact_value(item, steps)),
+        (Some((LiteralStep::Field(name), steps)), Value::Object(fields)) => fields
+            .get_mut(name.as_str())
+            .map_or(false, |value| redact_value(value, steps)),
+        _ => false,
+    }
+}
+
+/// Prints `doc` as GraphQL source, with the operations and fragments in the order they appear in
+/// the original source.
+pub fn print_document(doc: &ExecutableDocument) -> String {
+    let mut definitions: Vec<(Pos, String)> = Vec::new();
+    match &doc.operations {
+        DocumentOperations::Single(operation) => {
+            definitions.push((operation.pos, print_operation(None, &operation.node)))
+        }
+        DocumentOperations::Multiple(operations) => {
+            for (name, operation) in operations {
+                definitions.push((operation.pos, print_operation(Some(name), &operation.node)));
+            }
+        }
+    }
+    for (name, fragment) in &doc.fragments {
+        let mut s = format!(
+            "fragment {} on {}",
+            name, fragment.node.type_condition.node.on.node
+        );
+        print_directives(&mut s, &fragment.node.directives);
+        s.push(' ');
+        print_selection_set(&mut s, &fragment.node.selection_set.node);
+        definitions.push((fragment.pos, s));
+    }
+
+    definitions.sort_by_key(|(pos, _)| *pos);
+    definitions
+        .into_iter()
+        .map(|(_, s)| s)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn print_operation(name: Option<&Name>, operation: &OperationDefinition) -> String {
+    let mut s = String::new();
+    if name.is_some()
+        || operation.ty != OperationType::Query
+        || !operation.variable_definitions.is_empty()
+        || !operation.directives.is_empty()
+    {
+        s.push_str(&operation.ty.to_string());
+        if let Some(name) = name {
+            write!(s, " {}", name).unwrap();
+        }
+        if !operation.variable_definitions.is_empty() {
+            s.push('(');
+            for (idx, variable_definition) in operation.variable_definitions.iter().enumerate() {
+                if idx > 0 {
+                    s.push_str(", ");
+                }
+                let variable_definition = &variable_definition.node;
+                write!(
+                    s,
+                    "${}: {}",
+                    variable_definition.name.node, variable_definition.var_type.node
+                )
+                .unwrap();
+                if let Some(default_value) = &variable_definition.default_value {
+                    s.push_str(" = ");
+                    print_value(&mut s, &default_value.node.clone().into_value());
+                }
+            }
+            s.push(')');
+        }
+        print_directives(&mut s, &operation.directives);
+        s.push(' ');
+    }
+    print_selection_set(&mut s, &operation.selection_set.node);
+    s
+}
+
+fn print_selection_set(s: &mut String, selection_set: &SelectionSet) {
+    s.push('{');
+    for selection in &selection_set.items {
+        s.push(' ');
+        match &selection.node {
+            Selection::Field(field) => {
+                let field = &field.node;
+                if let Some(alias) = &field.alias {
+                    write!(s, "{}: ", alias.node).unwrap();
+                }
+                s.push_str(&field.name.node);
+                print_arguments(s, &field.arguments);
+                print_directives(s, &field.directives);
+                if !field.selection_set.node.items.is_empty() {
+                    s.push(' ');
+                    print_selection_set(s, &field.selection_set.node);
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                write!(s, "...{}", spread.node.fragment_name.node).unwrap();
+                print_directives(s, &spread.node.directives);
+            }
+            Selection::InlineFragment(fragment) => {
+                s.push_str("...");
+                if let Some(type_condition) = &fragment.node.type_condition {
+                    write!(s, " on {}", type_condition.node.on.node).unwrap();
+                }
+                print_directives(s, &fragment.node.directives);
+                s.push(' ');
+                print_selection_set(s, &fragment.node.selection_set.node);
+            }
+        }
+    }
+    s.push_str(" }");
+}
+
+fn print_directives(s: &mut String, directives: &[Positioned<Directive>]) {
+    for directive in directives {
+        write!(s, " @{}", directive.node.name.node).unwrap();
+        print_arguments(s, &directive.node.arguments);
+    }
+}
+
+fn print_arguments(s: &mut String, arguments: &[(Positioned<Name>, Positioned<Value>)]) {
+    if arguments.is_empty() {
+        return;
+    }
+    s.push('(');
+    for (idx, (name, value)) in arguments.iter().enumerate() {
+        if idx > 0 {
+            s.push_str(", ");
+        }
+        write!(s, "{}: ", name.node).unwrap();
+        print_value(s, &value.node);
+    }
+    s.push(')');
+}
+
+fn print_value(s: &mut String, value: &Value) {
+    match value {
+        Value::List(items) => {
+            s.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    s.push_str(", ");
+                }
+                print_value(s, item);
+            }
+            s.push(']');
+        }
+        Value::Object(fields) => {
+            s.push('{');
+            for (idx, (name, value)) in fields.iter().enumerate() {
+                s.push_str(if idx > 0 { ", " } else { " " });
+                write!(s, "{}: ", name).unwrap();
+                print_value(s, value);
+            }
+            s.push_str(" }");
+        }
+        value => write!(s, "{}", value).unwrap(),
+    }
+}
+
+/// Redacts the secret variables in `variables`.
+pub fn redact_variables(
+    registry: &Registry,
+    visitor: &SecretArguments<'_>,
+    variables: &Variables,
+) -> Variables {
+    let mut variables = variables.clone();
+    for (name, ty, _) in &visitor.variable_types {
+        if let Some(value) = variables.get_mut(*name) {
+            redact_variable(registry, ty, value);
+        }
+    }
+    for name in &visitor.variables {
+        if let Some(value) = variables.get_mut(*name) {
+            *value = ConstValue::String(REDACTED.to_string());
+        }
+    }
+    variables
+}
@@ -0,0 +1,153 @@
+use std::ops::{Bound, RangeBounds};
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+
+use crate::validators::range::{bound_ref, format_range, range_contains};
+use crate::validators::InputValueValidator;
+use crate::Value;
+
+/// Date range validator
+///
+/// The input must be a date string in `%Y-%m-%d` format, just like the `NaiveDate` scalar.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::validators::DateInRange;
+/// use chrono::NaiveDate;
+/// use std::ops::Bound;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     // Input is a date on or after 1900-01-01
+///     async fn value(
+///         &self,
+///         #[graphql(validator(DateInRange(
+///             min = "Bound::Included(NaiveDate::from_ymd(1900, 1, 1))",
+///             max = "Bound::Unbounded"
+///         )))]
+///         date: NaiveDate,
+///     ) -> i32 {
+///         unimplemented!()
+///     }
+/// }
+/// ```
+pub struct DateInRange {
+    /// The lower bound of the range.
+    pub min: Bound<NaiveDate>,
+
+    /// The upper bound of the range.
+    pub max: Bound<NaiveDate>,
+}
+
+impl RangeBounds<NaiveDate> for DateInRange {
+    fn start_bound(&self) -> Bound<&NaiveDate> {
+        bound_ref(&self.min)
+    }
+
+    fn end_bound(&self) -> Bound<&NaiveDate> {
+        bound_ref(&self.max)
+    }
+}
+
+impl InputValueValidator for DateInRange {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+                if !range_contains(self, &date) {
+                    return Err(format!(
+                        "the value is {}, must be in range {}",
+                        date,
+                        format_range(self)
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Date time window validator
+///
+/// The input must be a date time string in RFC3339 format, just like the `DateTime` scalars, and
+/// must be no more than `past` before and no more than `future` after the current time. The
+/// current time is read from `clock` every time a value is validated.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::validators::DateTimeInWindow;
+/// use chrono::{DateTime, Duration, Utc};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     // Input is a date time within 30 days of now
+///     async fn book(
+///         &self,
+///         #[graphql(validator(DateTimeInWindow(
+///             past = "Duration::days(30)",
+///             future = "Duration::days(30)",
+///             clock = "Utc::now"
+///         )))]
+///         at: DateTime<Utc>,
+///     ) -> i32 {
+///         unimplemented!()
+///     }
+/// }
+/// ```
+pub struct DateTimeInWindow {
+    /// How far before the current time the value may be.
+    pub past: Duration,
+
+    /// How far after the current time the value may be.
+    pub future: Duration,
+
+    /// The source of the current time.
+    pub clock: Clock,
+}
+
+/// The clock of [`DateTimeInWindow`](struct.DateTimeInWindow.html), any
+/// `Fn() -> DateTime<Utc>` such as `Utc::now` can be converted into it.
+pub struct Clock(Box<dyn Fn() -> DateTime<Utc> + Send + Sync>);
+
+impl<F> From<F> for Clock
+where
+    F: Fn() -> DateTime<Utc> + Send + Sync + 'static,
+{
+    fn from(clock: F) -> Self {
+        Clock(Box::new(clock))
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Utc::now.into()
+    }
+}
+
+impl InputValueValidator for DateTimeInWindow {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if let Ok(datetime) = s.parse::<DateTime<FixedOffset>>() {
+                let now = (self.clock.0)();
+                let (min, max) = (now - self.past, now + self.future);
+                let utc = datetime.with_timezone(&Utc);
+                if utc < min || utc > max {
+                    return Err(format!(
+                        "the value is {}, must be between {} and {}",
+                        datetime.to_rfc3339(),
+                        min.to_rfc3339(),
+                        max.to_rfc3339()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use crate::validators::InputValueValidator;
-use crate::Value;
+use crate::{InputType, Value};
 
 /// List minimum length validator
 pub struct ListMinLength {
@@ -48,3 +50,69 @@ impl InputValueValidator for ListMaxLength {
         }
     }
 }
+
+/// List validator that checks the whole list with a function
+///
+/// Unlike the validators of the items, the function can check the items against each other. The
+/// list is parsed as `Vec<T>` first, if this fails the error is left to the type check.
+pub struct ListWith<T> {
+    /// Returns the reason for the error if the list is invalid.
+    pub check: ListCheck<T>,
+}
+
+impl<T> ListWith<T> {
+    /// Create a validator that checks the list with `check`.
+    pub fn new(check: impl Fn(&[T]) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self {
+            check: check.into(),
+        }
+    }
+}
+
+/// The function of [`ListWith`](struct.ListWith.html), any `Fn(&[T]) -> Result<(), String>` can be
+/// converted into it.
+pub struct ListCheck<T>(Box<dyn Fn(&[T]) -> Result<(), String> + Send + Sync>);
+
+impl<T, F> From<F> for ListCheck<T>
+where
+    F: Fn(&[T]) -> Result<(), String> + Send + Sync + 'static,
+{
+    fn from(check: F) -> Self {
+        ListCheck(Box::new(check))
+    }
+}
+
+impl<T: InputType> InputValueValidator for ListWith<T> {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::List(_) = value {
+            if let Ok(values) = Vec::<T>::parse(Some(value.clone())) {
+                return (self.check.0)(&values);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// List validator that rejects duplicate items
+///
+/// Items are compared by their JSON representation, so it works for any item type, including
+/// input objects.
+pub struct Unique {}
+
+impl InputValueValidator for Unique {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::List(values) = value {
+            let mut seen = HashMap::new();
+            for (idx, value) in values.iter().enumerate() {
+                let key = serde_json::to_string(value).map_err(|err| err.to_string())?;
+                if let Some(first) = seen.insert(key, idx) {
+                    return Err(format!(
+                        "the item at index {} is a duplicate of the item at index {}",
+                        idx, first
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,25 @@
 //! Input value validators
 
+#[cfg(feature = "chrono")]
+mod date_validators;
 mod int_validators;
 mod list_validators;
+mod range;
 mod string_validators;
 
-use crate::Value;
+use crate::{Context, Result, Value};
 
+#[cfg(feature = "chrono")]
+pub use date_validators::{Clock, DateInRange, DateTimeInWindow};
 pub use int_validators::{IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange};
-pub use list_validators::{ListMaxLength, ListMinLength};
-pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
+pub use list_validators::{ListCheck, ListMaxLength, ListMinLength, ListWith, Unique};
+#[cfg(feature = "unicode-segmentation")]
+pub use range::StringGraphemes;
+pub use range::{InRange, RangeIndex};
+pub use string_validators::{
+    Base64, Email, Hex, PasswordStrength, Regex, RegexPattern, StringMaxLength, StringMinLength,
+    MAC,
+};
 
 /// Input value validator
 ///
@@ -47,9 +58,59 @@ where
     /// Check value is valid, returns the reason for the error if it fails, otherwise None.
     ///
     /// If the input type is different from the required type, return `Ok(())` directly, and other validators will find this error.
+    ///
+    /// It is only called when a value is present, an argument or input field that is absent or
+    /// explicitly `null` is not validated.
     fn is_valid(&self, value: &Value) -> Result<(), String>;
 }
 
+/// Asynchronous input value validator
+///
+/// Unlike `InputValueValidator`, it is called with the context after the argument has been parsed,
+/// so it can check the value against external data such as a database.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::validators::AsyncInputValueValidator;
+/// use std::collections::HashSet;
+///
+/// struct UsernameNotTaken;
+///
+/// #[async_trait::async_trait]
+/// impl AsyncInputValueValidator for UsernameNotTaken {
+///     async fn validate(&self, ctx: &Context<'_>, value: &Value) -> Result<()> {
+///         if let Value::String(name) = value {
+///             if ctx.data_unchecked::<HashSet<String>>().contains(name) {
+///                 return Err(Error::new(format!("The username \"{}\" is already taken", name)));
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// struct MutationRoot;
+///
+/// #[Object]
+/// impl MutationRoot {
+///     async fn register(&self, #[graphql(validator_async(UsernameNotTaken))] username: String) -> bool {
+///         unimplemented!()
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait AsyncInputValueValidator
+where
+    Self: Sync + Send,
+{
+    /// Check value is valid, returns the error if it fails.
+    ///
+    /// Like [`InputValueValidator::is_valid`](trait.InputValueValidator.html#tymethod.is_valid),
+    /// it is not called for absent or `null` values.
+    async fn validate(&self, ctx: &Context<'_>, value: &Value) -> Result<()>;
+}
+
 /// An extension trait for `InputValueValidator`
 pub trait InputValueValidatorExt: InputValueValidator + Sized {
     /// Merge the two validators and return None only if both validators are successful.
@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Bound, RangeBounds};
+
+use serde_json::Number;
+
+use crate::validators::InputValueValidator;
+use crate::Value;
+
+/// Number range validator
+///
+/// The value is compared numerically with the bounds, whether it is written as an integer or as
+/// a float, so `5.0` is in the integer range `[1, 10]` and `10.5` is not.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::validators::InRange;
+/// use std::ops::Bound;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     // Input is a number from 1 to 10
+///     async fn value(
+///         &self,
+///         #[graphql(validator(InRange(
+///             min = "Bound::Included(1_i64)",
+///             max = "Bound::Included(10_i64)"
+///         )))]
+///         n: f64,
+///     ) -> i32 {
+///         unimplemented!()
+///     }
+/// }
+/// ```
+pub struct InRange<Idx> {
+    /// The lower bound of the range.
+    pub min: Bound<Idx>,
+
+    /// The upper bound of the range.
+    pub max: Bound<Idx>,
+}
+
+impl<Idx> RangeBounds<Idx> for InRange<Idx> {
+    fn start_bound(&self) -> Bound<&Idx> {
+        bound_ref(&self.min)
+    }
+
+    fn end_bound(&self) -> Bound<&Idx> {
+        bound_ref(&self.max)
+    }
+}
+
+/// A bound of [`InRange`](struct.InRange.html), which can be compared with any number.
+pub trait RangeIndex: Display + Send + Sync + 'static {
+    /// Returns the ordering of `number` relative to this bound.
+    fn cmp_number(&self, number: &Number) -> Option<Ordering>;
+}
+
+impl RangeIndex for i64 {
+    fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+        match number.as_i64() {
+            Some(n) => Some(n.cmp(self)),
+            None => number.as_f64()?.partial_cmp(&(*self as f64)),
+        }
+    }
+}
+
+impl RangeIndex for u64 {
+    fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+        match (number.as_u64(), number.as_i64()) {
+            (Some(n), _) => Some(n.cmp(self)),
+            (None, Some(_)) => Some(Ordering::Less),
+            (None, None) => number.as_f64()?.partial_cmp(&(*self as f64)),
+        }
+    }
+}
+
+impl RangeIndex for f64 {
+    fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+        number.as_f64()?.partial_cmp(self)
+    }
+}
+
+impl<Idx: RangeIndex> InputValueValidator for InRange<Idx> {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::Number(n) = value {
+            let above_min = match &self.min {
+                Bound::Included(min) => matches!(
+                    min.cmp_number(n),
+                    Some(Ordering::Greater) | Some(Ordering::Equal)
+                ),
+                Bound::Excluded(min) => matches!(min.cmp_number(n), Some(Ordering::Greater)),
+                Bound::Unbounded => true,
+            };
+            let below_max = match &self.max {
+                Bound::Included(max) => matches!(
+                    max.cmp_number(n),
+                    Some(Ordering::Less) | Some(Ordering::Equal)
+                ),
+                Bound::Excluded(max) => matches!(max.cmp_number(n), Some(Ordering::Less)),
+                Bound::Unbounded => true,
+            };
+            if !above_min || !below_max {
+                return Err(format!(
+                    "the value is {}, must be in range {}",
+                    n,
+                    format_range(self)
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// String length validator counting grapheme clusters
+///
+/// Unlike [`StringMinLength`](struct.StringMinLength.html) and
+/// [`StringMaxLength`](struct.StringMaxLength.html), which count bytes, this counts the
+/// characters as a user perceives them, so that an emoji made of several code points counts as
+/// one.
+#[cfg(feature = "unicode-segmentation")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode-segmentation")))]
+pub struct StringGraphemes {
+    /// Minimum number of grapheme clusters, including this value.
+    pub min: i32,
+
+    /// Maximum number of grapheme clusters, including this value.
+    pub max: i32,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl InputValueValidator for StringGraphemes {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        if let Value::String(s) = value {
+            let length = s.graphemes(true).count();
+            if length < self.min as usize || length > self.max as usize {
+                return Err(format!(
+                    "the value length is {}, must be between {} and {}",
+                    length, self.min, self.max
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn bound_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Returns `true` if `value` is contained in `range`.
+#[cfg(feature = "chrono")]
+pub(crate) fn range_contains<T: PartialOrd, R: RangeBounds<T> + ?Sized>(
+    range: &R,
+    value: &T,
+) -> bool {
+    (match range.start_bound() {
+        Bound::Included(start) => start <= value,
+        Bound::Excluded(start) => start < value,
+        Bound::Unbounded => true,
+    }) && (match range.end_bound() {
+        Bound::Included(end) => value <= end,
+        Bound::Excluded(end) => value < end,
+        Bound::Unbounded => true,
+    })
+}
+
+/// Format a range for use in error messages, e.g. `[2000-01-01, 2010-01-01)`.
+pub(crate) fn format_range<T: std::fmt::Display, R: RangeBounds<T> + ?Sized>(range: &R) -> String {
+    let start = match range.start_bound() {
+        Bound::Included(start) => format!("[{}", start),
+        Bound::Excluded(start) => format!("({}", start),
+        Bound::Unbounded => "(-∞".to_string(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => format!("{}]", end),
+        Bound::Excluded(end) => format!("{})", end),
+        Bound::Unbounded => "∞)".to_string(),
+    };
+    format!("{}, {}", start, end)
+}
@@ -1,5 +1,4 @@
 use once_cell::sync::Lazy;
-use regex::Regex;
 
 use crate::validators::InputValueValidator;
 use crate::Value;
@@ -52,8 +51,8 @@ impl InputValueValidator for StringMaxLength {
     }
 }
 
-static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new("^(([0-9A-Za-z!#$%&'*+-/=?^_`{|}~&&[^@]]+)|(\"([0-9A-Za-z!#$%&'*+-/=?^_`{|}~ \"(),:;<>@\\[\\\\\\]]+)\"))@").unwrap()
+static EMAIL_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new("^(([0-9A-Za-z!#$%&'*+-/=?^_`{|}~&&[^@]]+)|(\"([0-9A-Za-z!#$%&'*+-/=?^_`{|}~ \"(),:;<>@\\[\\\\\\]]+)\"))@").unwrap()
 });
 
 /// Email validator
@@ -73,10 +72,10 @@ impl InputValueValidator for Email {
     }
 }
 
-static MAC_ADDRESS_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new("^([0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}$").unwrap());
-static MAC_ADDRESS_NO_COLON_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new("^[0-9a-fA-F]{12}$").unwrap());
+static MAC_ADDRESS_RE: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new("^([0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}$").unwrap());
+static MAC_ADDRESS_NO_COLON_RE: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new("^[0-9a-fA-F]{12}$").unwrap());
 
 /// MAC address validator
 pub struct MAC {
@@ -103,3 +102,176 @@ impl InputValueValidator for MAC {
         }
     }
 }
+
+/// Base64 validator
+pub struct Base64 {
+    /// Use the URL-safe alphabet, with `-` and `_` instead of `+` and `/`.
+    pub url_safe: bool,
+    /// Must be padded with `=` to a multiple of 4 characters, otherwise padding is not allowed.
+    pub padding: bool,
+}
+
+impl Base64 {
+    fn is_valid_base64(&self, s: &str) -> bool {
+        let data = s.trim_end_matches('=');
+        let padding = s.len() - data.len();
+        let valid_chars = data.chars().all(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' => true,
+            '+' | '/' => !self.url_safe,
+            '-' | '_' => self.url_safe,
+            _ => false,
+        });
+        let valid_len = if self.padding {
+            s.len() % 4 == 0 && padding <= 2
+        } else {
+            padding == 0 && s.len() % 4 != 1
+        };
+        valid_chars && valid_len
+    }
+}
+
+impl InputValueValidator for Base64 {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if !self.is_valid_base64(s) {
+                Err("invalid base64 format".to_string())
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Hex validator
+pub struct Hex {}
+
+impl InputValueValidator for Hex {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if s.len() % 2 != 0 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+                Err("invalid hex format".to_string())
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Regular expression validator
+///
+/// The pattern is compiled once when the schema is built, which panics if it is invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::validators::Regex;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     // Input is three uppercase letters
+///     async fn value(&self, #[graphql(validator(Regex(pattern = r#""^[A-Z]{3}$""#)))] code: String) -> i32 {
+///         unimplemented!()
+///     }
+/// }
+/// ```
+pub struct Regex {
+    /// Must match this pattern.
+    pub pattern: RegexPattern,
+}
+
+/// A compiled pattern of the `Regex` validator.
+pub struct RegexPattern(regex::Regex);
+
+impl From<regex::Regex> for RegexPattern {
+    fn from(re: regex::Regex) -> Self {
+        Self(re)
+    }
+}
+
+impl From<&str> for RegexPattern {
+    fn from(pattern: &str) -> Self {
+        match regex::Regex::new(pattern) {
+            Ok(re) => Self(re),
+            Err(err) => panic!("Invalid regex pattern \"{}\": {}", pattern, err),
+        }
+    }
+}
+
+impl InputValueValidator for Regex {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if !self.pattern.0.is_match(s) {
+                Err(format!(
+                    "the value does not match the pattern \"{}\"",
+                    self.pattern.0.as_str()
+                ))
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Password strength validator
+pub struct PasswordStrength {
+    /// The number of characters must be greater than or equal to this value.
+    pub min_length: i32,
+    /// Must contain an uppercase letter.
+    pub upper: bool,
+    /// Must contain a lowercase letter.
+    pub lower: bool,
+    /// Must contain a digit.
+    pub digit: bool,
+    /// Must contain a symbol, any character that is neither alphanumeric nor whitespace.
+    pub symbol: bool,
+}
+
+impl InputValueValidator for PasswordStrength {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            let mut length = 0;
+            let (mut has_upper, mut has_lower, mut has_digit, mut has_symbol) =
+                (false, false, false, false);
+            for c in s.chars() {
+                length += 1;
+                if c.is_uppercase() {
+                    has_upper = true;
+                } else if c.is_lowercase() {
+                    has_lower = true;
+                } else if c.is_numeric() {
+                    has_digit = true;
+                } else if !c.is_alphanumeric() && !c.is_whitespace() {
+                    has_symbol = true;
+                }
+            }
+
+            if length < self.min_length as usize {
+                Err(format!(
+                    "the password length is {}, must be greater than or equal to {}",
+                    length, self.min_length
+                ))
+            } else if self.upper && !has_upper {
+                Err("the password must contain an uppercase letter".to_string())
+            } else if self.lower && !has_lower {
+                Err("the password must contain a lowercase letter".to_string())
+            } else if self.digit && !has_digit {
+                Err("the password must contain a digit".to_string())
+            } else if self.symbol && !has_symbol {
+                Err("the password must contain a symbol".to_string())
+            } else {
+                Ok(())
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
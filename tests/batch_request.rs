@@ -31,3 +31,69 @@ pub async fn test_batch_request() {
         ])
     );
 }
+
+#[async_std::test]
+pub async fn test_batch_request_concurrency() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Counters {
+        started: AtomicUsize,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    struct Query(Arc<Counters>);
+
+    #[Object]
+    impl Query {
+        async fn value(&self, id: i32) -> Result<i32> {
+            let running = self.0.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.0.max_running.fetch_max(running, Ordering::SeqCst);
+            self.0.started.fetch_add(1, Ordering::SeqCst);
+
+            // Only completes once the first two requests are both in flight.
+            let res = async_std::future::timeout(Duration::from_secs(5), async {
+                while self.0.started.load(Ordering::SeqCst) < 2 {
+                    async_std::task::sleep(Duration::from_millis(1)).await;
+                }
+            })
+            .await;
+            self.0.running.fetch_sub(1, Ordering::SeqCst);
+            res.map_err(|_| "the requests are not executed concurrently")?;
+
+            if id == 0 {
+                Err("zero".into())
+            } else {
+                Ok(id)
+            }
+        }
+    }
+
+    let counters = Arc::new(Counters::default());
+    let schema = Schema::build(Query(counters.clone()), EmptyMutation, EmptySubscription)
+        .batch_concurrency(2)
+        .finish();
+    let batch: BatchRequest = vec![
+        Request::new("{ value(id: 1) }"),
+        Request::new("{ value(id: 0) }"),
+        Request::new("{ value(id: 3) }"),
+    ]
+    .into();
+    let resp = schema.execute_batch(batch).await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!([
+            {"data": { "value": 1 }},
+            {"data": null, "errors": [{
+                "message": "zero",
+                "locations": [{"line": 1, "column": 3}],
+                "path": ["value"],
+            }]},
+            {"data": { "value": 3 }},
+        ])
+    );
+    assert_eq!(counters.max_running.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,97 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_complex_object() {
+    #[derive(SimpleObject)]
+    #[graphql(complex)]
+    struct MyObj {
+        a: i32,
+        b: i32,
+    }
+
+    #[ComplexObject]
+    impl MyObj {
+        async fn c(&self) -> i32 {
+            self.a + self.b
+        }
+
+        async fn d(&self, n: i32) -> i32 {
+            (self.a + self.b) * n
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 10, b: 20 }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ obj { a b c d(n: 2) } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "obj": {
+                "a": 10,
+                "b": 20,
+                "c": 30,
+                "d": 60,
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyObj") { fields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "fields": [
+                    { "name": "a" },
+                    { "name": "b" },
+                    { "name": "c" },
+                    { "name": "d" },
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Field \"a\" of the ComplexObject is already defined.")]
+pub fn test_complex_object_duplicate_field() {
+    #[derive(SimpleObject)]
+    #[graphql(complex)]
+    struct MyObj {
+        a: i32,
+    }
+
+    #[ComplexObject]
+    impl MyObj {
+        #[graphql(name = "a")]
+        async fn other_a(&self) -> i32 {
+            self.a
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 10 }
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
@@ -107,3 +107,184 @@ pub async fn test_default_value_inputobject() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_default_value_refer_to_fields() {
+    #[derive(InputObject)]
+    struct MyInput {
+        a: i32,
+
+        #[graphql(default_with = "a + 1", from_fields)]
+        b: i32,
+
+        #[graphql(default_with = "a + b", from_fields)]
+        c: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput) -> Vec<i32> {
+            vec![input.a, input.b, input.c]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ value(input: { a: 1 }) }").await.data,
+        value!({ "value": [1, 2, 3] })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(input: { a: 1, b: 10 }) }")
+            .await
+            .data,
+        value!({ "value": [1, 10, 11] })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(input: { a: 1, b: 10, c: 100 }) }")
+            .await
+            .data,
+        value!({ "value": [1, 10, 100] })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(input: { a: 1, b: null, c: null }) }")
+            .await
+            .data,
+        value!({ "value": [1, 2, 3] })
+    );
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query($input: MyInput!) { value(input: $input) }").variables(
+                    Variables::from_value(value!({ "input": { "a": 1, "b": null } }))
+                )
+            )
+            .await
+            .data,
+        value!({ "value": [1, 2, 3] })
+    );
+    assert!(schema
+        .sdl()
+        .contains("input MyInput {\na: Int!\nb: Int\nc: Int\n}"));
+}
+
+#[async_std::test]
+pub async fn test_default_value_sdl() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    #[derive(InputObject)]
+    struct MyInput {
+        #[graphql(default = 100)]
+        value1: i32,
+
+        #[graphql(default = "abc")]
+        value2: String,
+
+        #[graphql(default_with = "MyEnum::B")]
+        value3: MyEnum,
+
+        value4: Option<i32>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(default = 10)] first: i32,
+            #[graphql(default = "a\"b")] text: String,
+            input: MyInput,
+        ) -> i32 {
+            input.value1 + first + text.len() as i32
+        }
+    }
+
+    let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+    assert!(
+        sdl.contains(r#"value(first: Int! = 10, text: String! = "a\"b", input: MyInput!): Int!"#)
+    );
+    assert!(sdl.contains("value1: Int! = 100\n"));
+    assert!(sdl.contains("value2: String! = \"abc\"\n"));
+    assert!(sdl.contains("value3: MyEnum! = B\n"));
+    assert!(sdl.contains("value4: Int\n"));
+}
+
+#[async_std::test]
+pub async fn test_default_value_matches_schema_default() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(default = 1.5)] number: f64,
+            #[graphql(default_with = "vec![MyEnum::A, MyEnum::B]")] list: Vec<MyEnum>,
+            #[graphql(default)] opt: Option<String>,
+        ) -> String {
+            format!("{} {} {:?}", number, list.len(), opt)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "1.5 2 None" })
+    );
+    assert!(schema.sdl().contains(
+        "value(number: Float! = 1.5, list: [MyEnum!]! = [A,B,], opt: String = null): String!"
+    ));
+}
+
+#[cfg(debug_assertions)]
+#[async_std::test]
+#[should_panic(
+    expected = "The default value for argument \"value\" of field \"Query.value\" is advertised as 1.5 but does not parse back to the same value"
+)]
+pub async fn test_default_value_diverges_from_schema_default() {
+    struct Rounded(f64);
+
+    #[Scalar]
+    impl ScalarType for Rounded {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match &value {
+                Value::Number(n) => Ok(Rounded(n.as_f64().unwrap_or_default().round())),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Number::from_f64(self.0).map_or(Value::Null, Value::Number)
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, #[graphql(default_with = "Rounded(1.5)")] value: Rounded) -> f64 {
+            value.0
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
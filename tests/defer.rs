@@ -0,0 +1,48 @@
+use async_graphql::*;
+use futures_util::stream::StreamExt;
+
+#[async_std::test]
+pub async fn test_defer() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        async fn b(&self) -> i32 {
+            2
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{ a ... @defer(label: "slow") { b } }"#;
+
+    let responses = schema.execute_stream(query).collect::<Vec<_>>().await;
+    assert_eq!(responses.len(), 2);
+
+    assert!(responses[0].is_ok());
+    assert_eq!(responses[0].data, value!({ "a": 1 }));
+    assert_eq!(responses[0].path(), None);
+    assert_eq!(responses[0].has_next(), Some(true));
+
+    assert!(responses[1].is_ok());
+    assert_eq!(responses[1].data, value!({ "b": 2 }));
+    assert_eq!(responses[1].path(), Some(&[][..]));
+    assert_eq!(responses[1].label(), Some("slow"));
+    assert_eq!(responses[1].has_next(), Some(false));
+
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({ "a": 1, "b": 2 })
+    );
+
+    let responses = schema
+        .execute_stream("{ a ... @defer(if: false) { b } }")
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].data, value!({ "a": 1, "b": 2 }));
+    assert_eq!(responses[0].has_next(), None);
+}
@@ -115,3 +115,71 @@ pub async fn test_directive_ifdef() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_custom_directive() {
+    use async_graphql::indexmap::IndexMap;
+    use async_graphql::registry::{__DirectiveLocation, MetaDirective, MetaInputValue};
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn value(&self, ctx: &Context<'_>) -> Result<String> {
+            match ctx.directive_arguments("auth")? {
+                Some(args) => Ok(format!("requires {}", args["requires"])),
+                None => Ok("public".to_string()),
+            }
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .register_directive(MetaDirective {
+            name: "auth",
+            description: Some("Requires the specified role."),
+            locations: vec![__DirectiveLocation::FIELD],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("requires", MetaInputValue::new("requires", "String!"));
+                args
+            },
+        })
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(
+                    r#"query($role: String!) {
+                    value1: value
+                    value2: value @auth(requires: "ADMIN")
+                    value3: value @auth(requires: $role)
+                }"#
+                )
+                .variables(Variables::from_value(value!({ "role": "USER" })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "value1": "public",
+            "value2": "requires \"ADMIN\"",
+            "value3": "requires \"USER\"",
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ value @unknown }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Unknown directive "unknown""#.to_string(),
+            locations: vec![Pos { line: 1, column: 9 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
@@ -92,3 +92,48 @@ pub async fn test_remote_enum() {
     let _: remote::RemoteEnum = LocalEnum::A.into();
     let _: LocalEnum = remote::RemoteEnum::A.into();
 }
+
+#[async_std::test]
+pub async fn test_enum_deprecation() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum MyEnum {
+        A,
+        #[graphql(deprecation = "Use A instead")]
+        B,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> MyEnum {
+            MyEnum::A
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        __type(name: "MyEnum") {
+            enumValues(includeDeprecated: true) {
+                name
+                isDeprecated
+                deprecationReason
+            }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "__type": {
+                "enumValues": [
+                    { "name": "A", "isDeprecated": false, "deprecationReason": null },
+                    { "name": "B", "isDeprecated": true, "deprecationReason": "Use A instead" },
+                ]
+            }
+        })
+    );
+
+    assert!(schema
+        .sdl()
+        .contains("enum MyEnum {\n\tA\n\tB @deprecated(reason: \"Use A instead\")\n}"));
+}
@@ -66,3 +66,291 @@ pub async fn test_error_extensions() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_result_with_custom_error() {
+    #[derive(Debug)]
+    enum MyError {
+        NotFound,
+        Forbidden(String),
+    }
+
+    impl ErrorExtensions for MyError {
+        fn extend(&self) -> Error {
+            match self {
+                MyError::NotFound => Error::new("not found").extend_with(|_, e| {
+                    e.set("code", "NOT_FOUND");
+                }),
+                MyError::Forbidden(reason) => Error::new("forbidden").extend_with(|_, e| {
+                    e.set("code", "FORBIDDEN");
+                    e.set("reason", reason.as_str());
+                }),
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn not_found(&self) -> Result<i32, MyError> {
+            Err(MyError::NotFound)
+        }
+
+        async fn forbidden(&self) -> Result<i32, MyError> {
+            Err(MyError::Forbidden("no access".to_string()))
+        }
+
+        async fn display_error(&self) -> Result<i32, std::num::ParseIntError> {
+            "abc".parse()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ notFound }").await).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "not found",
+                "locations": [{
+                    "column": 3,
+                    "line": 1,
+                }],
+                "path": ["notFound"],
+                "extensions": {
+                    "code": "NOT_FOUND"
+                }
+            }]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ forbidden }").await).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "forbidden",
+                "locations": [{
+                    "column": 3,
+                    "line": 1,
+                }],
+                "path": ["forbidden"],
+                "extensions": {
+                    "code": "FORBIDDEN",
+                    "reason": "no access"
+                }
+            }]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ displayError }").await).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "invalid digit found in string",
+                "locations": [{
+                    "column": 3,
+                    "line": 1,
+                }],
+                "path": ["displayError"]
+            }]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_error_http_status() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn secret(&self, ctx: &Context<'_>) -> Result<i32> {
+            Err(ctx.abort_with_status(403, "Forbidden"))
+        }
+
+        async fn extended(&self, ctx: &Context<'_>) -> Result<i32> {
+            Err(ctx
+                .abort_with_status(403, "Forbidden")
+                .extend_with(|_, e| e.set("code", 1)))
+        }
+
+        async fn value(&self) -> Result<i32> {
+            Err(Error::new("Internal error"))
+        }
+
+        async fn optional(&self, ctx: &Context<'_>) -> Result<Option<i32>> {
+            Err(ctx.abort_with_status(403, "Forbidden"))
+        }
+
+        async fn ok(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema.execute("{ secret }").await;
+    assert_eq!(resp.http_status(), Some(403));
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "Forbidden",
+                "locations": [{
+                    "column": 3,
+                    "line": 1,
+                }],
+                "path": ["secret"],
+                "extensions": {
+                    "httpStatus": 403
+                }
+            }]
+        })
+    );
+
+    let resp = schema.execute("{ extended }").await;
+    assert_eq!(resp.http_status(), Some(403));
+    assert_eq!(
+        resp.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!(1))
+    );
+
+    assert_eq!(schema.execute("{ value }").await.http_status(), None);
+
+    // An error in a nullable field also fails the whole response.
+    let resp = schema.execute("{ optional ok }").await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(resp.http_status(), Some(403));
+}
+
+#[async_std::test]
+pub async fn test_error_hook() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> Result<i32> {
+            Err("connection refused: 10.0.0.1:5432".into())
+        }
+
+        async fn public(&self) -> Result<i32> {
+            Err("Not found".extend_with(|_, e| e.set("code", "NOT_FOUND")))
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .error_hook(|err| {
+            let extensions = err.extensions.get_or_insert_with(Default::default);
+            if err.message.starts_with("connection refused") {
+                err.message = "Internal server error".to_string();
+                extensions.set("code", "INTERNAL");
+            }
+        })
+        .finish();
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ value }").await).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "Internal server error",
+                "locations": [{ "line": 1, "column": 3 }],
+                "path": ["value"],
+                "extensions": { "code": "INTERNAL" }
+            }]
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(&schema.execute("{ public }").await).unwrap(),
+        serde_json::json!({
+            "data": null,
+            "errors": [{
+                "message": "Not found",
+                "locations": [{ "line": 1, "column": 3 }],
+                "path": ["public"],
+                "extensions": { "code": "NOT_FOUND" }
+            }]
+        })
+    );
+
+    let res = schema.execute("{ unknown }").await;
+    assert_eq!(res.errors.len(), 1);
+    assert!(res.errors[0].message.contains("Unknown field"));
+}
+
+#[async_std::test]
+pub async fn test_error_extensions_through_interface() {
+    #[derive(Debug)]
+    enum MyError {
+        NotFound,
+    }
+
+    impl std::fmt::Display for MyError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                MyError::NotFound => f.write_str("not found"),
+            }
+        }
+    }
+
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> Result<i32> {
+            Err(MyError::NotFound.extend_with(|_, e| {
+                e.set("code", "NOT_FOUND");
+                e.set("retryable", false);
+            }))
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32"))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+
+        async fn iface(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    for (query, path) in &[
+        ("{ obj { value } }", "obj"),
+        ("{ iface { value } }", "iface"),
+    ] {
+        assert_eq!(
+            serde_json::to_value(&schema.execute(*query).await).unwrap(),
+            serde_json::json!({
+                "data": null,
+                "errors": [{
+                    "message": "not found",
+                    "locations": [{
+                        "line": 1,
+                        "column": path.len() + 6,
+                    }],
+                    "path": [path, "value"],
+                    "extensions": {
+                        "code": "NOT_FOUND",
+                        "retryable": false
+                    }
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_export_sdl() {
+    /// A thing with an id
+    #[derive(Interface)]
+    #[graphql(field(name = "id", type = "ID"))]
+    enum Node {
+        Entity(Entity),
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "id", type = "ID"))]
+    enum Entity {
+        Company(Company),
+    }
+
+    struct Company;
+
+    #[Object]
+    impl Company {
+        async fn id(&self) -> ID {
+            "1".into()
+        }
+
+        /// The """name""" of the company
+        #[graphql(deprecation = "Use id")]
+        async fn name(&self) -> String {
+            "abc".to_string()
+        }
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Kind {
+        /// Kind a
+        A,
+        #[graphql(deprecation = "Use A")]
+        B,
+    }
+
+    #[derive(InputObject)]
+    struct Filter {
+        #[graphql(default = 10)]
+        limit: i32,
+        #[graphql(default_with = "vec![Kind::A]")]
+        kinds: Vec<Kind>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn node(&self) -> Node {
+            Entity::Company(Company).into()
+        }
+
+        async fn companies(&self, #[graphql(desc = "The filter")] filter: Filter) -> Vec<Company> {
+            Vec::with_capacity(filter.limit as usize)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.sdl(),
+        r#"type Company implements Entity {
+	id: ID!
+	"""
+	The \"""name\""" of the company
+	"""
+	name: String! @deprecated(reason: "Use id")
+}
+interface Entity implements Node {
+	id: ID!
+}
+input Filter {
+limit: Int! = 10
+kinds: [Kind!]! = [A,]
+}
+enum Kind {
+	"""
+	Kind a
+	"""
+	A
+	B @deprecated(reason: "Use A")
+}
+"""
+A thing with an id
+"""
+interface Node {
+	id: ID!
+}
+type Query {
+	node: Node!
+	companies(
+		"""
+		The filter
+		"""
+		filter: Filter!
+	): [Company!]!
+}
+"""
+Directs the executor to deliver this fragment in a subsequent payload of `Schema::execute_stream`, it only applies to the fragments on the query root.
+"""
+directive @defer(if: Boolean = true, label: String) on FRAGMENT_SPREAD | INLINE_FRAGMENT
+"""
+Directs the executor to query only when the field exists.
+"""
+directive @ifdef on FIELD
+"""
+Indicates that exactly one field of this input object must be set and non-null.
+"""
+directive @oneOf on INPUT_OBJECT
+schema {
+	query: Query
+}
+"#
+    );
+}
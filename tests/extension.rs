@@ -1,4 +1,5 @@
 use async_graphql::extensions::{Extension, ExtensionContext, ExtensionFactory};
+use async_graphql::parser::types::ExecutableDocument;
 use async_graphql::*;
 use spin::Mutex;
 use std::sync::Arc;
@@ -66,3 +67,319 @@ pub async fn test_extension_ctx() {
         assert_eq!(*data.0.lock(), 100);
     }
 }
+
+#[async_std::test]
+pub async fn test_extensions_in_successful_response() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, ctx: &Context<'_>, cost: Option<i32>) -> bool {
+            if let Some(cost) = cost {
+                ctx.append_extension("cost", cost);
+            }
+            true
+        }
+    }
+
+    struct MyExtensionImpl;
+
+    impl Extension for MyExtensionImpl {
+        fn name(&self) -> Option<&'static str> {
+            Some("myExtension")
+        }
+
+        fn result(&mut self, _ctx: &ExtensionContext<'_>) -> Option<Value> {
+            Some(value!("abc"))
+        }
+    }
+
+    struct MyExtension;
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Box<dyn Extension> {
+            Box::new(MyExtensionImpl)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema.execute("{ value(cost: 2) }").await;
+    assert!(resp.errors.is_empty());
+    assert_eq!(resp.extensions, Some(value!({ "cost": 2 })));
+
+    let resp = schema.execute("{ value }").await;
+    assert_eq!(resp.extensions, None);
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({ "data": { "value": true } })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension)
+        .finish();
+    let resp = schema.execute("{ value(cost: 2) }").await;
+    assert!(resp.errors.is_empty());
+    assert_eq!(
+        resp.extensions,
+        Some(value!({ "cost": 2, "myExtension": "abc" }))
+    );
+}
+
+#[async_std::test]
+pub async fn test_report_deprecation() {
+    struct User;
+
+    #[Object]
+    impl User {
+        #[graphql(deprecation = "Use `name` instead.")]
+        async fn username(&self, ctx: &Context<'_>) -> &str {
+            ctx.report_deprecation("Use `name` instead.");
+            "sunli"
+        }
+
+        async fn name(&self) -> &str {
+            "sunli"
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn users(&self) -> Vec<User> {
+            vec![User, User]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema.execute("{ users { name username } }").await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "users": [
+                { "name": "sunli", "username": "sunli" },
+                { "name": "sunli", "username": "sunli" },
+            ]
+        })
+    );
+    assert_eq!(
+        resp.extensions,
+        Some(value!({
+            "deprecations": [
+                { "path": "users.username", "detail": "Use `name` instead." },
+            ]
+        }))
+    );
+
+    let resp = schema.execute("{ users { name } }").await;
+    assert_eq!(resp.extensions, None);
+}
+
+#[async_std::test]
+pub async fn test_extension_secret_arguments() {
+    #[derive(InputObject)]
+    struct LoginInput {
+        username: String,
+        #[graphql(secret)]
+        password: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn login(&self, username: String, #[graphql(secret)] password: String) -> bool {
+            username == "admin" && password == "hunter2"
+        }
+
+        async fn login_with(&self, input: LoginInput) -> bool {
+            input.username == "admin" && input.password == "hunter2"
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<(String, String)>>>, Arc<Mutex<Vec<String>>>);
+
+    struct MyExtensionImpl(Captured);
+
+    impl Extension for MyExtensionImpl {
+        fn parse_start(
+            &mut self,
+            _ctx: &ExtensionContext<'_>,
+            query_source: &str,
+            variables: &Variables,
+        ) {
+            self.0
+                 .0
+                .lock()
+                .push((query_source.to_string(), variables.to_string()));
+        }
+
+        fn parse_end(&mut self, _ctx: &ExtensionContext<'_>, document: &ExecutableDocument) {
+            self.0 .1.lock().push(format!("{:?}", document));
+        }
+    }
+
+    struct MyExtension(Captured);
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Box<dyn Extension> {
+            Box::new(MyExtensionImpl(self.0.clone()))
+        }
+    }
+
+    let captured = Captured::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension(captured.clone()))
+        .finish();
+
+    let res = schema
+        .execute(r#"{ login(username: "admin", password: "hunter2") }"#)
+        .await;
+    assert_eq!(res.into_result().unwrap().data, value!({ "login": true }));
+
+    let res = schema
+        .execute(
+            Request::new(
+                r#"query($password: String!, $input: LoginInput!) {
+                    login(username: "admin", password: $password)
+                    loginWith(input: $input)
+                    inline: loginWith(input: { username: "admin", password: "hunter2" })
+                }"#,
+            )
+            .variables(Variables::from_json(serde_json::json!({
+                "password": "hunter2",
+                "input": { "username": "admin", "password": "hunter2" },
+            }))),
+        )
+        .await;
+    assert_eq!(
+        res.into_result().unwrap().data,
+        value!({ "login": true, "loginWith": true, "inline": true })
+    );
+
+    let res = schema
+        .execute(
+            r#"query($p: String = "hunter2", $input: LoginInput = { username: "admin", password: "hunter2" }) {
+                login(username: "admin", password: $p)
+                loginWith(input: $input)
+            }"#,
+        )
+        .await;
+    assert_eq!(
+        res.into_result().unwrap().data,
+        value!({ "login": true, "loginWith": true })
+    );
+
+    // a lone carriage return is a line terminator
+    let res = schema
+        .execute("{\r login(username: \"admin\",\r password: \"hunter2\") }")
+        .await;
+    assert_eq!(res.into_result().unwrap().data, value!({ "login": true }));
+
+    // the secrets of a query that doesn't parse can't be found
+    schema
+        .execute(r#"{ login(username: "admin", password: "hunter2" }"#)
+        .await
+        .into_result()
+        .unwrap_err();
+
+    let documents = captured.1.lock();
+    assert_eq!(documents.len(), 4);
+    assert!(documents
+        .iter()
+        .all(|document| !document.contains("hunter2")));
+
+    let captured = captured.0.lock();
+    assert_eq!(
+        captured[0].0,
+        r#"{ login(username: "admin", password: "[redacted]") }"#
+    );
+    assert!(captured[1]
+        .0
+        .contains(r#"inline: loginWith(input: { username: "admin", password: "[redacted]" })"#));
+    assert!(captured[1]
+        .0
+        .contains(r#"login(username: "admin", password: $password)"#));
+    assert!(!captured[1].1.contains("hunter2"));
+    assert!(captured[1].1.contains(r#""[redacted]""#));
+    assert!(captured[1].1.contains(r#"username: "admin""#));
+    assert!(captured[2].0.starts_with(
+        r#"query($p: String = "[redacted]", $input: LoginInput = { username: "admin", password: "[redacted]" })"#
+    ));
+    assert_eq!(
+        captured[3].0,
+        r#"{ login(username: "admin", password: "[redacted]") }"#
+    );
+    assert_eq!(captured[4].0, "[redacted]");
+    assert!(captured.iter().all(|(query, variables)| {
+        !query.contains("hunter2") && !variables.contains("hunter2")
+    }));
+}
+
+#[async_std::test]
+pub async fn test_apollo_tracing() {
+    #[derive(SimpleObject)]
+    struct MyObj {
+        a: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 20 }
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_apollo_tracing()
+        .finish();
+    let resp = schema.execute("{ value obj { a } }").await;
+    assert_eq!(resp.data, value!({ "value": 10, "obj": { "a": 20 } }));
+
+    let extensions = serde_json::to_value(&resp.extensions).unwrap();
+    let tracing = &extensions["tracing"];
+    assert_eq!(tracing["version"], 1);
+    assert!(tracing["duration"].as_i64().unwrap() >= 0);
+
+    let resolvers = tracing["execution"]["resolvers"].as_array().unwrap();
+    let mut paths = Vec::new();
+    for resolver in resolvers {
+        assert!(resolver["startOffset"].as_i64().unwrap() >= 0);
+        assert!(resolver["duration"].as_i64().unwrap() >= 0);
+        paths.push((
+            serde_json::from_value::<Vec<String>>(resolver["path"].clone()).unwrap(),
+            resolver["parentType"].as_str().unwrap().to_string(),
+            resolver["returnType"].as_str().unwrap().to_string(),
+        ));
+    }
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            (
+                vec!["obj".to_string()],
+                "Query".to_string(),
+                "MyObj!".to_string()
+            ),
+            (
+                vec!["obj".to_string(), "a".to_string()],
+                "MyObj".to_string(),
+                "Int!".to_string()
+            ),
+            (
+                vec!["value".to_string()],
+                "Query".to_string(),
+                "Int!".to_string()
+            ),
+        ]
+    );
+}
@@ -146,3 +146,99 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_federation_sdl() {
+    struct User {
+        id: ID,
+    }
+
+    #[Object(extends)]
+    impl User {
+        #[graphql(external)]
+        async fn id(&self) -> &ID {
+            &self.id
+        }
+
+        #[graphql(external)]
+        async fn name(&self) -> String {
+            todo!()
+        }
+
+        #[graphql(requires = "name")]
+        async fn greeting(&self) -> String {
+            todo!()
+        }
+
+        /// The reviews of the user
+        #[graphql(provides = "body")]
+        async fn reviews(&self) -> Vec<Review> {
+            todo!()
+        }
+    }
+
+    struct Review;
+
+    #[Object]
+    impl Review {
+        async fn body(&self) -> String {
+            todo!()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[graphql(entity)]
+        async fn find_user_by_id(&self, id: ID) -> User {
+            User { id }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let sdl = schema.federation_sdl();
+    assert_eq!(
+        schema
+            .execute("{ _service { sdl } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "_service": { "sdl": sdl.clone() } })
+    );
+    assert!(sdl.contains(
+        "extend type User @key(fields: \"id\") {\n\
+         \tid: ID! @external\n\
+         \tname: String! @external\n\
+         \tgreeting: String! @requires(fields: \"name\")\n\
+         \treviews: [Review!]! @provides(fields: \"body\")\n\
+         }"
+    ));
+    for internal in &[
+        "_Service",
+        "_Entity",
+        "_Any",
+        "_service",
+        "_entities",
+        "schema {",
+    ] {
+        assert!(
+            !sdl.contains(internal),
+            "{} in the federation SDL",
+            internal
+        );
+    }
+
+    struct PlainQuery;
+
+    #[Object]
+    impl PlainQuery {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(PlainQuery, EmptyMutation, EmptySubscription);
+    assert!(schema.execute("{ _service { sdl } }").await.is_err());
+}
@@ -0,0 +1,234 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_graphql::field_cache::{FieldCache, MemoryFieldCache};
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_field_cache() {
+    #[derive(SimpleObject)]
+    struct User {
+        id: i32,
+        name: String,
+    }
+
+    struct Query {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[Object]
+    impl Query {
+        #[graphql(cached, cache_control(max_age = 60))]
+        async fn user(&self, id: i32) -> User {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            User {
+                id,
+                name: format!("user{}", id),
+            }
+        }
+
+        async fn uncached(&self) -> i32 {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            1
+        }
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(
+        Query {
+            calls: calls.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .field_cache(MemoryFieldCache::new())
+    .finish();
+    let calls = || calls.load(Ordering::SeqCst);
+
+    for _ in 0..2 {
+        assert_eq!(
+            schema
+                .execute(
+                    Request::new("query($id: Int!) { user(id: $id) { id name } }")
+                        .variables(Variables::from_json(serde_json::json!({ "id": 1 })))
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "user": { "id": 1, "name": "user1" } })
+        );
+    }
+    assert_eq!(calls(), 1);
+
+    // A different selection set is cached separately.
+    assert_eq!(
+        schema
+            .execute("{ user(id: 1) { ... on User { id } name } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": { "id": 1, "name": "user1" } })
+    );
+    assert_eq!(calls(), 2);
+    schema
+        .execute("{ user(id: 1) { ... on User { id } name } }")
+        .await;
+    assert_eq!(calls(), 2);
+
+    schema.execute("{ user(id: 2) { id name } }").await;
+    assert_eq!(calls(), 3);
+
+    schema.execute("{ user(id: 1) { id } }").await;
+    assert_eq!(calls(), 4);
+
+    schema.execute("{ uncached }").await;
+    schema.execute("{ uncached }").await;
+    assert_eq!(calls(), 6);
+}
+
+#[async_std::test]
+pub async fn test_field_cache_parent_key() {
+    struct User {
+        id: i32,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[Object]
+    impl User {
+        #[graphql(cached, cache_key = "self.id", cache_control(max_age = 60))]
+        async fn name(&self) -> String {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            format!("user{}", self.id)
+        }
+
+        #[graphql(cached, cache_control(max_age = 60))]
+        async fn unkeyed(&self) -> i32 {
+            self.id
+        }
+    }
+
+    struct Query {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[Object]
+    impl Query {
+        async fn users(&self) -> Vec<User> {
+            (1..=2)
+                .map(|id| User {
+                    id,
+                    calls: self.calls.clone(),
+                })
+                .collect()
+        }
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(
+        Query {
+            calls: calls.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .field_cache(MemoryFieldCache::new())
+    .finish();
+
+    for _ in 0..2 {
+        assert_eq!(
+            schema
+                .execute("{ users { name } }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "users": [{ "name": "user1" }, { "name": "user2" }] })
+        );
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    assert_eq!(
+        schema
+            .execute("{ users { unkeyed } }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "The cached field \"User.unkeyed\" is not at the top level, so it needs a cache_key."
+    );
+}
+
+#[async_std::test]
+pub async fn test_field_cache_max_age_of_request() {
+    #[derive(Default, Clone)]
+    struct MyCache(Arc<Mutex<Vec<(String, Duration)>>>);
+
+    #[async_trait::async_trait]
+    impl FieldCache for MyCache {
+        async fn get(&self, _key: &str) -> Option<Value> {
+            None
+        }
+
+        async fn set(&self, key: String, _value: Value, max_age: Duration) {
+            self.0.lock().unwrap().push((key, max_age));
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 10))]
+    struct User {
+        id: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 30, private))]
+    struct Secret {
+        value: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(cached, cache_control(max_age = 60))]
+        async fn user(&self) -> User {
+            User { id: 1 }
+        }
+
+        #[graphql(cached, cache_control(max_age = 60))]
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        async fn secret(&self) -> Secret {
+            Secret { value: 1 }
+        }
+    }
+
+    let cache = MyCache::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .field_cache(cache.clone())
+        .finish();
+
+    schema.execute("{ value }").await.into_result().unwrap();
+    schema
+        .execute("{ user { id } }")
+        .await
+        .into_result()
+        .unwrap();
+    schema
+        .execute("{ value secret { value } }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        *cache.0.lock().unwrap(),
+        vec![
+            ("Query.value".to_string(), Duration::from_secs(60)),
+            ("Query.user{id:id,}".to_string(), Duration::from_secs(10)),
+        ]
+    );
+}
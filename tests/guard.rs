@@ -1,6 +1,7 @@
-use async_graphql::guard::Guard;
+use async_graphql::guard::{Guard, RateLimit, RateLimitState};
 use async_graphql::*;
 use futures_util::stream::{Stream, StreamExt};
+use std::time::Duration;
 
 #[derive(Eq, PartialEq, Copy, Clone)]
 enum Role {
@@ -486,3 +487,55 @@ pub async fn test_guard_race_operator() {
         }]
     );
 }
+
+#[async_std::test]
+pub async fn test_guard_rate_limit() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(guard(and(
+            RoleGuard(role = "Role::Admin"),
+            RateLimit(max = "2usize", window = "Duration::from_secs(60)")
+        )))]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(RateLimitState::new(|ctx| {
+            ctx.data_unchecked::<Username>().0.clone()
+        }))
+        .finish();
+    let execute = |username: &str| {
+        schema.execute(
+            Request::new("{ value }")
+                .data(Role::Admin)
+                .data(Username(username.to_string())),
+        )
+    };
+
+    for _ in 0..2 {
+        assert_eq!(execute("test1").await.data, value!({"value": 10}));
+    }
+    assert_eq!(
+        execute("test1").await.into_result().unwrap_err(),
+        vec![ServerError {
+            message: "Too many requests".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("value".to_owned())],
+            extensions: None,
+        }]
+    );
+    assert_eq!(execute("test2").await.data, value!({"value": 10}));
+
+    assert!(schema
+        .execute(
+            Request::new("{ value }")
+                .data(Role::Guest)
+                .data(Username("test3".to_string()))
+        )
+        .await
+        .is_err());
+}
@@ -349,3 +349,267 @@ pub async fn test_box_input_object() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_input_object_field_alias() {
+    #[derive(InputObject)]
+    struct MyInput {
+        #[graphql(alias = "oldValue", alias = "legacyValue")]
+        value: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn test(&self, input: MyInput) -> i32 {
+            input.value
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    for query in &[
+        "{ test(input: { value: 10 }) }",
+        "{ test(input: { oldValue: 10 }) }",
+        "{ test(input: { legacyValue: 10 }) }",
+    ] {
+        assert_eq!(
+            schema.execute(*query).await.into_result().unwrap().data,
+            value!({ "test": 10 })
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query($input: MyInput!) { test(input: $input) }").variables(
+                    Variables::from_value(value!({ "input": { "oldValue": 10 } }))
+                )
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "test": 10 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ test(input: { value: 10, oldValue: 20 }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "input", field "value" of type "MyInput" is provided as both "value" and "oldValue""#.to_string(),
+            locations: vec![Pos { line: 1, column: 8 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyInput") { inputFields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "inputFields": [{ "name": "value" }]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_one_of_input_object() {
+    #[derive(InputObject)]
+    #[graphql(one_of)]
+    struct Target {
+        id: Option<i32>,
+        name: Option<String>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: Target) -> String {
+            match (input.id, input.name) {
+                (Some(id), None) => format!("id:{}", id),
+                (None, Some(name)) => format!("name:{}", name),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema
+        .sdl()
+        .contains("input Target @oneOf {\nid: Int\nname: String\n}"));
+
+    let query = "query($input: Target!) { value(input: $input) }";
+    let execute = |input| {
+        schema.execute(
+            Request::new(query).variables(Variables::from_value(value!({ "input": input }))),
+        )
+    };
+
+    assert_eq!(
+        execute(value!({ "id": 1 }))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "id:1" })
+    );
+    assert_eq!(
+        execute(value!({ "id": null, "name": "abc" }))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": "name:abc" })
+    );
+
+    for (input, reason) in &[
+        (value!({}), "exactly one field must be set, but none was"),
+        (
+            value!({ "id": null }),
+            "exactly one field must be set, but none was",
+        ),
+        (
+            value!({ "id": 1, "name": "abc" }),
+            r#"exactly one field must be set, but "id", "name" were"#,
+        ),
+    ] {
+        assert_eq!(
+            execute(input.clone()).await.into_result().unwrap_err(),
+            vec![ServerError {
+                message: format!(r#"Invalid value for argument "input", {}"#, reason),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 32
+                }],
+                path: Vec::new(),
+                extensions: None,
+            }]
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{ value(input: { id: 1, name: "abc" }) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "input", exactly one field must be set, but "id", "name" were"#.to_string(),
+            locations: vec![Pos { line: 1, column: 9 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+
+    // The object itself is also checked when parsed.
+    assert_eq!(
+        <Target as InputType>::parse(Some(value!({})))
+            .unwrap_err()
+            .into_server_error()
+            .message,
+        r#"Failed to parse "Target": exactly one field must be set, but none was"#
+    );
+    assert_eq!(
+        <Target as InputType>::parse(Some(value!({ "id": 1, "name": "abc" })))
+            .unwrap_err()
+            .into_server_error()
+            .message,
+        r#"Failed to parse "Target": exactly one field must be set, but "id", "name" were"#
+    );
+}
+
+#[async_std::test]
+#[should_panic(expected = "Field \"Target.id\" of a one_of input object must be nullable")]
+pub async fn test_one_of_input_object_non_null_field() {
+    #[derive(InputObject)]
+    #[graphql(one_of)]
+    struct Target {
+        id: i32,
+        name: Option<String>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, _input: Target) -> bool {
+            true
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_input_object_validator() {
+    use chrono::NaiveDate;
+
+    fn validate_range(range: &DateRange) -> Result<(), String> {
+        if range.start < range.end {
+            Ok(())
+        } else {
+            Err(format!(
+                "the start date {} is not before the end date {}",
+                range.start, range.end
+            ))
+        }
+    }
+
+    #[derive(InputObject)]
+    #[graphql(validator = "validate_range")]
+    struct DateRange {
+        start: NaiveDate,
+        end: NaiveDate,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn days(&self, range: DateRange) -> i64 {
+            (range.end - range.start).num_days()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{ days(range: { start: "2021-01-01", end: "2021-01-10" }) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "days": 9 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ days(range: { start: "2021-01-10", end: "2021-01-01" }) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": the start date 2021-01-10 is not before the end date 2021-01-01"#
+                .to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 15,
+            }],
+            path: vec![PathSegment::Field("days".to_owned())],
+            extensions: None,
+        }]
+    );
+}
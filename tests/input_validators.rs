@@ -1,6 +1,7 @@
 use async_graphql::validators::{
-    Email, IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange, ListMaxLength,
-    ListMinLength, StringMaxLength, StringMinLength, MAC,
+    Base64, Email, Hex, InputValueValidator, IntEqual, IntGreaterThan, IntLessThan, IntNonZero,
+    IntRange, ListMaxLength, ListMinLength, ListWith, PasswordStrength, Regex, StringMaxLength,
+    StringMinLength, Unique, MAC,
 };
 use async_graphql::*;
 
@@ -1772,3 +1773,931 @@ pub async fn test_input_validator_variable() {
         }
     }
 }
+
+#[cfg(feature = "unicode-segmentation")]
+#[async_std::test]
+pub async fn test_input_validator_string_graphemes() {
+    use async_graphql::validators::StringGraphemes;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(StringGraphemes(min = "1", max = "2")))] name: String,
+        ) -> String {
+            name
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+    assert_eq!(family.chars().count(), 7);
+    assert_eq!(
+        schema
+            .execute(format!("{{fieldParameter(name: \"{}\")}}", family))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "fieldParameter": family })
+    );
+
+    for (case, length) in &[("", 0), ("abc", 3)] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(name: \"{}\")}}", case))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: format!(
+                    "Invalid value for argument \"name\", the value length is {}, must be between 1 and 2",
+                    length
+                ),
+                locations: vec!(Pos {
+                    line: 1,
+                    column: 17
+                }),
+                path: Vec::new(),
+                extensions: None,
+            }]
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_date_in_range() {
+    use async_graphql::validators::DateInRange;
+    use chrono::NaiveDate;
+    use std::ops::Bound;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(DateInRange(
+                min = "Bound::Included(NaiveDate::from_ymd(1900, 1, 1))",
+                max = "Bound::Excluded(NaiveDate::from_ymd(2000, 1, 1))"
+            )))]
+            date: NaiveDate,
+        ) -> NaiveDate {
+            date
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(date: "1950-06-15")}"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({"fieldParameter": "1950-06-15"})
+    );
+
+    for case in &["1899-12-31", "2000-01-01"] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(date: \"{}\")}}", case))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: format!(
+                    "Invalid value for argument \"date\", the value is {}, must be in range [1900-01-01, 2000-01-01)",
+                    case
+                ),
+                locations: vec!(Pos {
+                    line: 1,
+                    column: 17
+                }),
+                path: Vec::new(),
+                extensions: None,
+            }]
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_in_range_number() {
+    use async_graphql::validators::InRange;
+    use std::ops::Bound;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(InRange(
+                min = "Bound::Included(1_i64)",
+                max = "Bound::Included(10_i64)"
+            )))]
+            n: f64,
+        ) -> f64 {
+            n
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    for case in &["5", "5.0", "10", "1.0"] {
+        assert!(
+            schema
+                .execute(format!("{{fieldParameter(n: {})}}", case))
+                .await
+                .is_ok(),
+            "Failed to validate {} with InRange",
+            case
+        );
+    }
+
+    for case in &[5.0, 10.0] {
+        assert!(
+            schema
+                .execute(
+                    Request::new("query($n: Float!) { fieldParameter(n: $n) }")
+                        .variables(Variables::from_json(serde_json::json!({ "n": case })))
+                )
+                .await
+                .is_ok(),
+            "Failed to validate variable {} with InRange",
+            case
+        );
+    }
+
+    for case in &["10.5", "0.5", "0"] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(n: {})}}", case))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: format!(
+                    "Invalid value for argument \"n\", the value is {}, must be in range [1, 10]",
+                    case
+                ),
+                locations: vec!(Pos {
+                    line: 1,
+                    column: 17
+                }),
+                path: Vec::new(),
+                extensions: None,
+            }],
+            "Failed to invalidate {} with InRange",
+            case
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query($n: Float!) { fieldParameter(n: $n) }")
+                    .variables(Variables::from_json(serde_json::json!({ "n": 10.5 })))
+            )
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Invalid value for argument \"n\", the value is 10.5, must be in range [1, 10]"
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_datetime_in_window() {
+    use async_graphql::validators::DateTimeInWindow;
+    use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(DateTimeInWindow(
+                past = "Duration::days(30)",
+                future = "Duration::days(30)",
+                clock = "|| Utc.ymd(2020, 6, 1).and_hms(0, 0, 0)"
+            )))]
+            at: DateTime<FixedOffset>,
+        ) -> bool {
+            at.timestamp() > 0
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    for case in &[
+        "2020-06-01T00:00:00Z",
+        "2020-05-02T00:00:00Z",
+        "2020-07-01T08:00:00+08:00",
+        "2020-05-01T20:00:00-04:00",
+    ] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(at: \"{}\")}}", case))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({"fieldParameter": true}),
+            "Failed to validate {} with DateTimeInWindow",
+            case
+        );
+    }
+
+    for (case, value) in &[
+        ("2020-07-01T00:00:01Z", "2020-07-01T00:00:01+00:00"),
+        ("2020-05-01T23:59:59Z", "2020-05-01T23:59:59+00:00"),
+        ("2020-07-01T08:00:01+08:00", "2020-07-01T08:00:01+08:00"),
+    ] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(at: \"{}\")}}", case))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: format!(
+                    "Invalid value for argument \"at\", the value is {}, must be between 2020-05-02T00:00:00+00:00 and 2020-07-01T00:00:00+00:00",
+                    value
+                ),
+                locations: vec!(Pos {
+                    line: 1,
+                    column: 17
+                }),
+                path: Vec::new(),
+                extensions: None,
+            }],
+            "Failed to invalidate {} with DateTimeInWindow",
+            case
+        );
+    }
+}
+
+#[async_std::test]
+#[should_panic(
+    expected = "Invalid default value for argument \"value\" of field \"QueryRoot.fieldParameter\": the value is 0, must be greater than 5"
+)]
+pub async fn test_argument_default_value_checked_by_validator() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(default = 0, validator(IntGreaterThan(value = "5")))] value: i32,
+        ) -> i32 {
+            value
+        }
+    }
+
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+#[should_panic(
+    expected = "Invalid default value for field \"MyInput.value\": the value is 0, must be greater than 5"
+)]
+pub async fn test_input_object_default_value_checked_by_validator() {
+    #[derive(InputObject)]
+    struct MyInput {
+        #[graphql(default = 0, validator(IntGreaterThan(value = "5")))]
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(&self, input: MyInput) -> i32 {
+            input.value
+        }
+    }
+
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_async_input_validator() {
+    use async_graphql::validators::AsyncInputValueValidator;
+    use std::collections::HashSet;
+
+    struct TakenUsernames(HashSet<String>);
+
+    struct UsernameNotTaken;
+
+    #[async_trait::async_trait]
+    impl AsyncInputValueValidator for UsernameNotTaken {
+        async fn validate(&self, ctx: &Context<'_>, value: &Value) -> Result<()> {
+            if let Value::String(name) = value {
+                if ctx.data_unchecked::<TakenUsernames>().0.contains(name) {
+                    return Err(Error::new(format!(
+                        "The username \"{}\" is already taken",
+                        name
+                    )));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn register(
+            &self,
+            #[graphql(validator_async(UsernameNotTaken))] username: String,
+        ) -> String {
+            username
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(TakenUsernames(
+            vec!["alice".to_string()].into_iter().collect(),
+        ))
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(r#"{ register(username: "bob") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "register": "bob" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ register(username: "alice") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The username \"alice\" is already taken".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("register".to_owned())],
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_password_strength() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(PasswordStrength(
+                min_length = "8",
+                upper = "true",
+                lower = "true",
+                digit = "true",
+                symbol = "true"
+            )))]
+            _password: String,
+        ) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(password: "Str0ng!Pass")}"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "fieldParameter": true })
+    );
+
+    let test_cases = [
+        (
+            "Ab1!",
+            "the password length is 4, must be greater than or equal to 8",
+        ),
+        (
+            "weak1!password",
+            "the password must contain an uppercase letter",
+        ),
+        (
+            "WEAK1!PASSWORD",
+            "the password must contain a lowercase letter",
+        ),
+        ("Weak!Password", "the password must contain a digit"),
+        ("Weak1Password", "the password must contain a symbol"),
+    ];
+
+    for (password, reason) in &test_cases {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(password: \"{}\")}}", password))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: format!("Invalid value for argument \"password\", {}", reason),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 17
+                }],
+                path: Vec::new(),
+                extensions: None,
+            }],
+            "password: {}",
+            password
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_string_regex() {
+    struct QueryRoot;
+
+    #[derive(InputObject)]
+    struct InputRegex {
+        #[graphql(validator(Regex(pattern = r#""^[A-Z]{3}$""#)))]
+        pub code: String,
+    }
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(or(
+                Regex(pattern = r#""^[A-Z]{3}$""#),
+                Regex(pattern = r#""^\\d+$""#)
+            )))]
+            _code: String,
+        ) -> bool {
+            true
+        }
+
+        async fn input_object(&self, _input: InputRegex) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    for code in &["ABC", "123"] {
+        assert_eq!(
+            schema
+                .execute(format!("{{fieldParameter(code: \"{}\")}}", code))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "fieldParameter": true })
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(code: "abc")}"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message:
+                r#"Invalid value for argument "code", the value does not match the pattern "^\d+$""#
+                    .to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 17
+            }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{inputObject(input: {code: "ABCD"})}"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "input.code", the value does not match the pattern "^[A-Z]{3}$""#
+                .to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 14
+            }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+#[should_panic(expected = "Invalid regex pattern \"[A-Z\"")]
+pub async fn test_input_validator_invalid_regex() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(Regex(pattern = r#""[A-Z""#)))] _code: String,
+        ) -> bool {
+            true
+        }
+    }
+
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_input_validator_string_base64() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn standard(
+            &self,
+            #[graphql(validator(Base64(url_safe = "false", padding = "true")))] _value: String,
+        ) -> bool {
+            true
+        }
+
+        async fn url_safe(
+            &self,
+            #[graphql(validator(Base64(url_safe = "true", padding = "false")))] _value: String,
+        ) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    for (field, value) in &[
+        ("standard", ""),
+        ("standard", "aGVsbG8="),
+        ("standard", "aGk/Pz8+"),
+        ("standard", "aGVsbG8gd29ybGQ="),
+        ("urlSafe", "aGVsbG8"),
+        ("urlSafe", "aGk_Pz8-"),
+    ] {
+        assert!(
+            schema
+                .execute(format!("{{{}(value: \"{}\")}}", field, value))
+                .await
+                .is_ok(),
+            "{} should be valid for {}",
+            value,
+            field
+        );
+    }
+
+    for (field, value) in &[
+        ("standard", "aGVsbG8"),
+        ("standard", "aGk_Pz8-"),
+        ("standard", "aGVs=bG8"),
+        ("standard", "a==="),
+        ("standard", "aGVsbG8*"),
+        ("urlSafe", "aGVsbG8="),
+        ("urlSafe", "aGk/Pz8+"),
+        ("urlSafe", "aGVsb"),
+    ] {
+        assert_eq!(
+            schema
+                .execute(format!("{{{}(value: \"{}\")}}", field, value))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: r#"Invalid value for argument "value", invalid base64 format"#.to_string(),
+                locations: vec![Pos {
+                    line: 1,
+                    column: field.len() + 3
+                }],
+                path: Vec::new(),
+                extensions: None,
+            }],
+            "{} should be invalid for {}",
+            value,
+            field
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_validator_string_hex() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn field_parameter(
+            &self,
+            #[graphql(validator(or(Hex, Base64(url_safe = "false", padding = "true"))))]
+            _value: String,
+        ) -> bool {
+            true
+        }
+
+        async fn hex(&self, #[graphql(validator(Hex))] _value: String) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    for value in &["", "00ff", "DEADbeef"] {
+        assert_eq!(
+            schema
+                .execute(format!("{{hex(value: \"{}\")}}", value))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "hex": true }),
+            "{} should be valid",
+            value
+        );
+    }
+
+    for value in &["0", "abc", "0x00", "zz"] {
+        assert_eq!(
+            schema
+                .execute(format!("{{hex(value: \"{}\")}}", value))
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: r#"Invalid value for argument "value", invalid hex format"#.to_string(),
+                locations: vec![Pos { line: 1, column: 6 }],
+                path: Vec::new(),
+                extensions: None,
+            }],
+            "{} should be invalid",
+            value
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(value: "aGk=")}"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "fieldParameter": true })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{fieldParameter(value: "0x0")}"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "value", invalid base64 format"#.to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 17
+            }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_list_with() {
+    #[derive(InputObject)]
+    struct Coordinate {
+        crs: String,
+        x: f64,
+        y: f64,
+    }
+
+    fn same_crs(coordinates: &[Coordinate]) -> Result<(), String> {
+        match coordinates.split_first() {
+            Some((first, rest)) if rest.iter().any(|c| c.crs != first.crs) => {
+                Err("all coordinates must share the same CRS".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn sum(
+            &self,
+            #[graphql(validator(ListWith(check = "same_crs")))] points: Vec<Coordinate>,
+        ) -> f64 {
+            points.iter().map(|point| point.x + point.y).sum()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{sum(points: [{crs: "EPSG:4326", x: 1, y: 2}, {crs: "EPSG:4326", x: 3, y: 4}])}"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "sum": 10.0 })
+    );
+    assert_eq!(
+        schema
+            .execute(
+                r#"{sum(points: [{crs: "EPSG:4326", x: 1, y: 2}, {crs: "EPSG:3857", x: 3, y: 4}])}"#
+            )
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message:
+                r#"Invalid value for argument "points", all coordinates must share the same CRS"#
+                    .to_string(),
+            locations: vec![Pos { line: 1, column: 6 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_unique() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn count(&self, #[graphql(validator(Unique))] tags: Vec<i32>) -> usize {
+            tags.len()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{count(tags: [1, 2, 3])}")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "count": 3 })
+    );
+    assert_eq!(
+        schema
+            .execute("{count(tags: [1, 2, 2])}")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "tags", the item at index 2 is a duplicate of the item at index 1"#
+                .to_string(),
+            locations: vec![Pos { line: 1, column: 8 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_validator_skips_null() {
+    struct NotCalled {}
+
+    impl InputValueValidator for NotCalled {
+        fn is_valid(&self, value: &Value) -> Result<(), String> {
+            Err(format!("called with {}", value))
+        }
+    }
+
+    #[derive(InputObject)]
+    struct MyInput {
+        #[graphql(validator(NotCalled))]
+        value: Option<i32>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn email(&self, #[graphql(validator(Email))] email: Option<String>) -> bool {
+            email.is_some()
+        }
+
+        async fn value(
+            &self,
+            #[graphql(validator(NotCalled))] value: Option<i32>,
+            input: Option<MyInput>,
+        ) -> bool {
+            value.is_some() || input.map_or(false, |input| input.value.is_some())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    for query in &[
+        "{ email }",
+        "{ email(email: null) }",
+        "{ value }",
+        "{ value(value: null) }",
+        "{ value(input: {}) }",
+        "{ value(input: { value: null }) }",
+    ] {
+        assert!(
+            schema.execute(*query).await.is_ok(),
+            "validator called for {}",
+            query
+        );
+    }
+    assert!(schema
+        .execute(
+            Request::new("query($email: String) { email(email: $email) }")
+                .variables(Variables::from_value(value!({ "email": null })))
+        )
+        .await
+        .is_ok());
+
+    assert_eq!(
+        schema
+            .execute(r#"{ email(email: "abc") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Invalid value for argument \"email\", invalid email format".to_string(),
+            locations: vec![Pos { line: 1, column: 9 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ email(email: "a@b.com") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "email": true })
+    );
+    assert!(schema.execute("{ value(value: 1) }").await.is_err());
+    assert!(schema
+        .execute("{ value(input: { value: 1 }) }")
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+#[should_panic(
+    expected = "Invalid default value for argument \"value\" of field \"MyInterface.fieldParameter\": the value is 0, must be greater than 5"
+)]
+pub async fn test_interface_argument_default_value_checked_by_validator() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn field_parameter(&self, value: i32) -> i32 {
+            value
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(
+        name = "field_parameter",
+        type = "i32",
+        arg(
+            name = "value",
+            type = "i32",
+            default = 0,
+            validator(IntGreaterThan(value = "5"))
+        )
+    ))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
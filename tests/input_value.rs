@@ -27,3 +27,53 @@ pub async fn test_input_value_custom_error() {
         }],
     );
 }
+
+#[async_std::test]
+pub async fn test_input_value_non_zero() {
+    use std::num::NonZeroU32;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, n: Option<NonZeroU32>) -> Option<u32> {
+            n.map(NonZeroU32::get)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": null })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(n: 5) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 5 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(n: 0) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Failed to parse \"Int\": The value must be non-zero.".to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 12,
+            }],
+            path: vec![PathSegment::Field("value".to_owned())],
+            extensions: None,
+        }],
+    );
+}
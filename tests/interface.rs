@@ -473,3 +473,344 @@ pub async fn test_issue_330() {
         })
     );
 }
+
+#[test]
+#[should_panic(
+    expected = "Object \"MyObj\" does not implement the field \"value\" of interface \"MyInterface\"."
+)]
+pub fn test_interface_missing_field() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        #[graphql(name = "otherValue")]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32"))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "The field \"value\" of object \"MyObj\" has the type \"Int!\", which is not compatible with the type \"Float!\" of the field in interface \"MyInterface\"."
+)]
+pub fn test_interface_incompatible_field_type() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "f64"))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "The field \"value\" of object \"MyObj\" does not have the argument \"a\" of the field in interface \"MyInterface\"."
+)]
+pub fn test_interface_missing_argument() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32", arg(name = "a", type = "i32")))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "The argument \"a\" of the field \"value\" of object \"MyObj\" has the type \"Int\", which is not the type \"Int!\" of the argument in interface \"MyInterface\"."
+)]
+pub fn test_interface_incompatible_argument_type() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self, a: Option<i32>) -> i32 {
+            a.unwrap_or_default()
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32", arg(name = "a", type = "i32")))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = "The argument \"b\" of the field \"value\" of object \"MyObj\" is required, but it is not an argument of the field in interface \"MyInterface\"."
+)]
+pub fn test_interface_additional_required_argument() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self, a: i32, b: i32) -> i32 {
+            a + b
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32", arg(name = "a", type = "i32")))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_interface_additional_optional_arguments() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self, a: i32, b: Option<i32>, #[graphql(default = 1)] c: i32) -> i32 {
+            a + b.unwrap_or_default() + c
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", type = "i32", arg(name = "a", type = "i32")))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ obj { value(a: 1) } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "obj": { "value": 2 } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_interface_non_null_list_implements_nullable_list() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn values(&self) -> Vec<Option<i32>> {
+            vec![Some(1), None, Some(3)]
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "values", type = "Option<Vec<Option<i32>>>"))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ obj { values } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "obj": { "values": [1, null, 3] }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_interface_impl_discriminator() {
+    use std::collections::BTreeMap;
+
+    #[derive(SimpleObject)]
+    struct Circle {
+        area: f32,
+        radius: f32,
+    }
+
+    #[derive(SimpleObject)]
+    struct Square {
+        area: f32,
+        width: f32,
+    }
+
+    struct Shape {
+        kind: &'static str,
+        values: BTreeMap<&'static str, f32>,
+    }
+
+    #[InterfaceImpl(
+        field(name = "area", type = "f32"),
+        possible_type = "Circle",
+        possible_type = "Square"
+    )]
+    impl Shape {
+        fn type_name(&self) -> &str {
+            self.kind
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ContainerType for Shape {
+        async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+            Ok(self
+                .values
+                .get(ctx.item.node.name.node.as_str())
+                .map(|value| Value::from(*value)))
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn shapes(&self) -> Vec<Shape> {
+            let mut circle = BTreeMap::new();
+            circle.insert("area", 3.0);
+            circle.insert("radius", 1.0);
+            let mut square = BTreeMap::new();
+            square.insert("area", 4.0);
+            square.insert("width", 2.0);
+            vec![
+                Shape {
+                    kind: "Circle",
+                    values: circle,
+                },
+                Shape {
+                    kind: "Square",
+                    values: square,
+                },
+            ]
+        }
+    }
+
+    let query = r#"{
+        shapes {
+            __typename
+            area
+            ... on Circle { radius }
+            ... on Square { width }
+        }
+    }"#;
+    let expected = value!({
+        "shapes": [
+            { "__typename": "Circle", "area": 3.0, "radius": 1.0 },
+            { "__typename": "Square", "area": 4.0, "width": 2.0 },
+        ]
+    });
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        expected
+    );
+
+    // the fields of the concrete types are also found when extensions are enabled
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(extensions::Analyzer)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        expected
+    );
+
+    assert!(schema
+        .sdl()
+        .contains("interface Shape {\n\tarea: Float!\n}"));
+    assert!(schema.sdl().contains("type Circle implements Shape {"));
+}
@@ -1191,3 +1191,190 @@ pub async fn test_introspection_subscription() {
 //
 //     assert_eq!(res, res_json)
 // }
+
+#[async_std::test]
+pub async fn test_introspection_directives() {
+    use async_graphql::indexmap::IndexMap;
+    use async_graphql::registry::{__DirectiveLocation, MetaDirective, MetaInputValue};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_directive(MetaDirective {
+            name: "multiply",
+            description: None,
+            locations: vec![__DirectiveLocation::FIELD],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("factor", MetaInputValue::new("factor", "Int!"));
+                args
+            },
+        })
+        .finish();
+
+    let res = schema
+        .execute(
+            r#"{
+                __schema {
+                    directives {
+                        name
+                        locations
+                        args { name defaultValue }
+                    }
+                }
+            }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data
+        .into_json()
+        .unwrap();
+    let directives = res["__schema"]["directives"].as_array().unwrap();
+    let find = |name: &str| {
+        directives
+            .iter()
+            .find(|directive| directive["name"] == name)
+            .unwrap_or_else(|| panic!("directive \"{}\" is missing", name))
+    };
+
+    assert_eq!(
+        find("skip"),
+        &serde_json::json!({
+            "name": "skip",
+            "locations": ["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+            "args": [{ "name": "if", "defaultValue": null }],
+        })
+    );
+    assert_eq!(
+        find("include"),
+        &serde_json::json!({
+            "name": "include",
+            "locations": ["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+            "args": [{ "name": "if", "defaultValue": null }],
+        })
+    );
+    assert_eq!(
+        find("deprecated"),
+        &serde_json::json!({
+            "name": "deprecated",
+            "locations": ["FIELD_DEFINITION", "ENUM_VALUE"],
+            "args": [{ "name": "reason", "defaultValue": "\"No longer supported\"" }],
+        })
+    );
+    assert_eq!(
+        find("oneOf"),
+        &serde_json::json!({
+            "name": "oneOf",
+            "locations": ["INPUT_OBJECT"],
+            "args": [],
+        })
+    );
+    assert_eq!(
+        find("multiply"),
+        &serde_json::json!({
+            "name": "multiply",
+            "locations": ["FIELD"],
+            "args": [{ "name": "factor", "defaultValue": null }],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspection_type_full_shape() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let query = r#"
+    query($name: String!) {
+        __type(name: $name) {
+            kind
+            name
+            fields { name }
+            interfaces { name }
+            possibleTypes { name }
+            enumValues(includeDeprecated: true) { name }
+            inputFields { name type { kind ofType { name } } defaultValue }
+            ofType { name }
+        }
+    }
+    "#;
+    let query_type = |name: &str| {
+        Request::new(query).variables(Variables::from_json(serde_json::json!({ "name": name })))
+    };
+
+    assert_eq!(
+        schema
+            .execute(query_type("SimpleInput"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "kind": "INPUT_OBJECT",
+                "name": "SimpleInput",
+                "fields": null,
+                "interfaces": null,
+                "possibleTypes": null,
+                "enumValues": null,
+                "inputFields": [
+                    {
+                        "name": "a",
+                        "type": { "kind": "NON_NULL", "ofType": { "name": "String" } },
+                        "defaultValue": null,
+                    },
+                ],
+                "ofType": null,
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(query_type("TestEnum"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "kind": "ENUM",
+                "name": "TestEnum",
+                "fields": null,
+                "interfaces": null,
+                "possibleTypes": null,
+                "enumValues": [{ "name": "KIND_1" }, { "name": "KIND_2" }],
+                "inputFields": null,
+                "ofType": null,
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(query_type("TestInterface"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "kind": "INTERFACE",
+                "name": "TestInterface",
+                "fields": [{ "name": "scale" }],
+                "interfaces": null,
+                "possibleTypes": [{ "name": "Circle" }, { "name": "Square" }],
+                "enumValues": null,
+                "inputFields": null,
+                "ofType": null,
+            }
+        })
+    );
+}
@@ -67,6 +67,50 @@ pub async fn test_type_visible() {
         .is_none());
 }
 
+#[async_std::test]
+pub async fn test_possible_type_visible() {
+    #[derive(SimpleObject)]
+    struct A {
+        value: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(visible = false)]
+    struct B {
+        value: i32,
+    }
+
+    #[derive(Union)]
+    enum MyUnion {
+        A(A),
+        B(B),
+    }
+
+    struct Query;
+
+    #[Object]
+    #[allow(unreachable_code)]
+    impl Query {
+        async fn value(&self) -> MyUnion {
+            todo!()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyUnion") { possibleTypes { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": { "possibleTypes": [{ "name": "A" }] },
+        })
+    );
+}
+
 #[async_std::test]
 pub async fn test_field_visible() {
     #[derive(SimpleObject)]
@@ -256,3 +300,128 @@ pub async fn test_visible_fn() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_visible_fn_per_request() {
+    struct IsAdmin(bool);
+
+    fn is_admin(ctx: &Context<'_>) -> bool {
+        ctx.data_opt::<IsAdmin>()
+            .map_or(false, |is_admin| is_admin.0)
+    }
+
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+        #[graphql(visible = "is_admin")]
+        email: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(
+            &self,
+            name: String,
+            #[graphql(visible = "is_admin")] include_deleted: Option<bool>,
+        ) -> User {
+            User {
+                name,
+                email: format!("{:?}", include_deleted),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        __type(name: "User") { fields { name } }
+        __schema {
+            queryType { fields { name args { name } } }
+            types { name fields { name } }
+        }
+    }"#;
+
+    #[derive(Deserialize)]
+    struct QueryResponse {
+        #[serde(rename = "__type")]
+        ty: TypeResponse,
+        #[serde(rename = "__schema")]
+        schema: SchemaResponse,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct SchemaResponse {
+        query_type: TypeResponse,
+        types: Vec<TypeResponse>,
+    }
+
+    #[derive(Deserialize)]
+    struct TypeResponse {
+        #[serde(default)]
+        name: String,
+        fields: Option<Vec<FieldResponse>>,
+    }
+
+    #[derive(Deserialize)]
+    struct FieldResponse {
+        name: String,
+        #[serde(default)]
+        args: Vec<NameResponse>,
+    }
+
+    #[derive(Deserialize)]
+    struct NameResponse {
+        name: String,
+    }
+
+    fn field_names(ty: &TypeResponse) -> Vec<&str> {
+        ty.fields
+            .iter()
+            .flatten()
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+
+    for (admin, user_fields, user_args) in &[
+        (false, vec!["name"], vec!["name"]),
+        (true, vec!["name", "email"], vec!["name", "includeDeleted"]),
+    ] {
+        let resp: QueryResponse = from_value(
+            schema
+                .execute(Request::new(query).data(IsAdmin(*admin)))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+        )
+        .unwrap();
+
+        assert_eq!(&field_names(&resp.ty), user_fields);
+        let user_type = resp
+            .schema
+            .types
+            .iter()
+            .find(|ty| ty.name == "User")
+            .unwrap();
+        assert_eq!(&field_names(user_type), user_fields);
+
+        let user_field = resp
+            .schema
+            .query_type
+            .fields
+            .iter()
+            .flatten()
+            .find(|field| field.name == "user")
+            .unwrap();
+        assert_eq!(
+            &user_field
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>(),
+            user_args
+        );
+    }
+}
@@ -117,3 +117,83 @@ pub async fn test_list_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_tuple_type() {
+    struct Root;
+
+    #[Object]
+    impl Root {
+        async fn point(&self, point: (i32, i32)) -> (i32, i32) {
+            (point.1, point.0)
+        }
+
+        async fn point3(&self, point: (i32, i32, i32)) -> (i32, i32, i32) {
+            (point.0 + 1, point.1 + 1, point.2 + 1)
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ point(point: [1, 2]) point3(point: [1, 2, 3]) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "point": [2, 1],
+            "point3": [2, 3, 4],
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ point(point: [1, 2, 3]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "[Int!]": expected a list of 2 items, found 3 items"#
+                .to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 16,
+            }],
+            path: vec![PathSegment::Field("point".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ point3(point: [1, 2]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "[Int!]": expected a list of 3 items, found 2 items"#
+                .to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 17,
+            }],
+            path: vec![PathSegment::Field("point3".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ point(point: [1, "a"]) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "point.1", expected type "Int""#.to_owned(),
+            locations: vec![Pos { line: 1, column: 9 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
@@ -55,3 +55,71 @@ pub async fn test_maybe_undefined_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_maybe_undefined_list() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn filter(&self, ids: MaybeUndefined<Vec<i32>>) -> String {
+            match ids {
+                MaybeUndefined::Undefined => "undefined".to_string(),
+                MaybeUndefined::Null => "null".to_string(),
+                MaybeUndefined::Value(ids) => format!("{:?}", ids),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"
+        {
+            v1: filter
+            v2: filter(ids: null)
+            v3: filter(ids: [])
+            v4: filter(ids: [1, 2, 3])
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "v1": "undefined",
+            "v2": "null",
+            "v3": "[]",
+            "v4": "[1, 2, 3]",
+        })
+    );
+
+    let query = r#"
+        query($ids: [Int!]) {
+            filter(ids: $ids)
+        }
+    "#;
+    for (ids, expected) in vec![
+        (value!(null), "null"),
+        (value!([]), "[]"),
+        (value!([1, 2]), "[1, 2]"),
+    ] {
+        assert_eq!(
+            schema
+                .execute(Request::new(query).variables(Variables::from_value(value!({
+                    "ids": ids,
+                }))))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "filter": expected })
+        );
+    }
+
+    assert_eq!(
+        schema
+            .execute(Request::new(query))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "filter": "undefined" })
+    );
+}
@@ -387,3 +387,106 @@ pub async fn test_issue_333() {
         })
     )
 }
+
+#[async_std::test]
+pub async fn test_merge_roots_at_runtime() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct UserQuery;
+
+    #[Object]
+    impl UserQuery {
+        async fn users(&self) -> Vec<String> {
+            vec!["alice".to_string(), "bob".to_string()]
+        }
+
+        async fn value(&self) -> i32 {
+            20
+        }
+    }
+
+    struct MovieQuery;
+
+    #[Object]
+    impl MovieQuery {
+        async fn movies(&self) -> Vec<String> {
+            vec!["Alien".to_string()]
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn action(&self) -> bool {
+            true
+        }
+    }
+
+    struct UserMutation;
+
+    #[Object]
+    impl UserMutation {
+        async fn add_user(&self, name: String) -> String {
+            name
+        }
+    }
+
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .merge_query(UserQuery)
+        .merge_query(MovieQuery)
+        .merge_mutation(UserMutation)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value users movies }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "value": 10,
+            "users": ["alice", "bob"],
+            "movies": ["Alien"],
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"mutation { action addUser(name: "carol") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "action": true,
+            "addUser": "carol",
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Query") { fields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "fields": [
+                    { "name": "value" },
+                    { "name": "users" },
+                    { "name": "movies" },
+                ]
+            }
+        })
+    );
+}
@@ -84,3 +84,90 @@ pub async fn test_mutation_fragment() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_mutation_execution_order_with_fragments() {
+    #[derive(Default)]
+    struct Log {
+        values: Vec<i32>,
+        running: usize,
+        max_running: usize,
+    }
+
+    type State = Arc<Mutex<Log>>;
+
+    // Yields to the executor while the field is running, so that fields executed concurrently
+    // overlap and are counted in `max_running`.
+    async fn append(state: &State, value: i32) -> bool {
+        {
+            let mut log = state.lock().await;
+            log.running += 1;
+            log.max_running = log.max_running.max(log.running);
+        }
+        async_std::task::yield_now().await;
+        let mut log = state.lock().await;
+        log.running -= 1;
+        log.values.push(value);
+        true
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn append(&self, ctx: &Context<'_>, value: i32) -> bool {
+            append(ctx.data_unchecked::<State>(), value).await
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        async fn append(&self, ctx: &Context<'_>, value: i32) -> bool {
+            append(ctx.data_unchecked::<State>(), value).await
+        }
+    }
+
+    let state = State::default();
+    let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(state.clone())
+        .finish();
+
+    // Mutation fields are executed serially in the order they appear in the document, including
+    // the fields of fragments.
+    schema
+        .execute(
+            r#"
+            mutation {
+                a: append(value: 1)
+                ... A
+                ... on MutationRoot {
+                    c: append(value: 3)
+                }
+                d: append(value: 4)
+            }
+
+            fragment A on MutationRoot {
+                b: append(value: 2)
+            }
+        "#,
+        )
+        .await
+        .into_result()
+        .unwrap();
+    {
+        let log = state.lock().await;
+        assert_eq!(log.values, vec![1, 2, 3, 4]);
+        assert_eq!(log.max_running, 1);
+    }
+
+    // Query fields are executed concurrently.
+    *state.lock().await = Log::default();
+    schema
+        .execute("{ a: append(value: 1) b: append(value: 2) }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(state.lock().await.max_running, 2);
+}
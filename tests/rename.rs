@@ -137,3 +137,74 @@ pub async fn test_subscription() {
         value!({"CREATE_OBJECT": 100})
     );
 }
+
+#[async_std::test]
+pub async fn test_rename_fields_explicit_name() {
+    #[derive(SimpleObject)]
+    #[graphql(rename_fields = "SCREAMING_SNAKE_CASE")]
+    struct MyObj {
+        first_value: i32,
+        #[graphql(name = "other")]
+        second_value: i32,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(rename_fields = "lowercase")]
+    struct MyObj2 {
+        first_value: i32,
+    }
+
+    #[derive(InputObject)]
+    #[graphql(rename_fields = "camelCase")]
+    struct MyInput {
+        first_value: i32,
+        #[graphql(name = "other_value")]
+        second_value: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self, input: MyInput) -> MyObj {
+            MyObj {
+                first_value: input.first_value,
+                second_value: input.second_value,
+            }
+        }
+
+        async fn obj2(&self) -> MyObj2 {
+            MyObj2 { first_value: 3 }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(
+                    "query($input: MyInput!) { obj(input: $input) { FIRST_VALUE other } obj2 { first_value } }"
+                )
+                .variables(Variables::from_value(value!({
+                    "input": { "firstValue": 1, "other_value": 2 }
+                })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "obj": { "FIRST_VALUE": 1, "other": 2 },
+            "obj2": { "first_value": 3 },
+        })
+    );
+
+    assert!(schema
+        .execute("{ obj(input: { first_value: 1, other_value: 2 }) { other } }")
+        .await
+        .is_err());
+    assert!(schema
+        .execute("{ obj(input: { firstValue: 1, secondValue: 2 }) { other } }")
+        .await
+        .is_err());
+}
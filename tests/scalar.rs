@@ -39,3 +39,203 @@ pub async fn test_scalar_macro() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_scalar_specified_by_url() {
+    struct MyUuid(String);
+
+    #[Scalar(specified_by_url = "https://tools.ietf.org/html/rfc4122")]
+    impl ScalarType for MyUuid {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(MyUuid(s)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct MyCode(String);
+
+    #[Scalar]
+    impl ScalarType for MyCode {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(MyCode(s)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct MyQuoted(String);
+
+    #[Scalar(specified_by_url = r#"https://example.com/"quoted"\spec"#)]
+    impl ScalarType for MyQuoted {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(MyQuoted(s)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn uuid(&self) -> MyUuid {
+            MyUuid("a".to_string())
+        }
+
+        async fn code(&self) -> MyCode {
+            MyCode("b".to_string())
+        }
+
+        async fn quoted(&self) -> MyQuoted {
+            MyQuoted("c".to_string())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    uuid: __type(name: "MyUuid") { specifiedByURL }
+                    code: __type(name: "MyCode") { specifiedByURL }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "uuid": { "specifiedByURL": "https://tools.ietf.org/html/rfc4122" },
+            "code": { "specifiedByURL": null },
+        })
+    );
+    assert!(schema
+        .sdl()
+        .contains("scalar MyUuid @specifiedBy(url: \"https://tools.ietf.org/html/rfc4122\")\n"));
+    assert!(schema.sdl().contains("scalar MyCode\n"));
+    assert!(schema
+        .sdl()
+        .contains(r#"scalar MyQuoted @specifiedBy(url: "https://example.com/\"quoted\"\\spec")"#));
+}
+
+#[async_std::test]
+pub async fn test_scalar_accept() {
+    #[derive(Debug)]
+    struct Code(String);
+
+    #[Scalar(accept(String, Int))]
+    impl ScalarType for Code {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(Code(s)),
+                value => Ok(Code(value.to_string())),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn code(&self, code: Code) -> Code {
+            code
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ a: code(code: "abc") b: code(code: 123) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "a": "abc", "b": "123" })
+    );
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query($code: Code!) { code(code: $code) }")
+                    .variables(Variables::from_value(value!({ "code": 42 })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "code": "42" })
+    );
+    assert!(schema.execute("{ code(code: 1.5) }").await.is_err());
+    assert!(schema.execute("{ code(code: true) }").await.is_err());
+    assert!(schema
+        .execute(
+            Request::new("query($code: Code!) { code(code: $code) }")
+                .variables(Variables::from_value(value!({ "code": [1] })))
+        )
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_scalar_accept_and_is_valid() {
+    struct Code(String);
+
+    #[Scalar(accept(String))]
+    impl ScalarType for Code {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(Code(s)),
+                value => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn is_valid(value: &Value) -> bool {
+            matches!(value, Value::String(s) if !s.is_empty())
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn code(&self, code: Code) -> String {
+            code.0
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ code(code: "abc") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "code": "abc" })
+    );
+    assert!(schema.execute(r#"{ code(code: "") }"#).await.is_err());
+    assert!(schema.execute("{ code(code: 1) }").await.is_err());
+}
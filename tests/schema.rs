@@ -16,3 +16,508 @@ pub async fn test_schema_default() {
 
     let _schema = MySchema::default();
 }
+
+#[async_std::test]
+pub async fn test_response_keys_sorted() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn b(&self) -> i32 {
+            2
+        }
+
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        async fn c(&self) -> i32 {
+            3
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let resp1 = schema.execute("{ c a b }").await;
+    let resp2 = schema.execute("{ a b c }").await;
+    assert_eq!(
+        serde_json::to_string(&resp1).unwrap(),
+        r#"{"data":{"a":1,"b":2,"c":3}}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&resp1).unwrap(),
+        serde_json::to_string(&resp2).unwrap()
+    );
+}
+
+#[async_std::test]
+pub async fn test_limit_depth_error_position() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(2)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ obj { obj { value } } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Query is nested too deep.".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 15
+            }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_limit_depth_and_complexity() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    // Depth 4 and complexity 4.
+    let query = "{ obj { obj { obj { value } } } }";
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(3)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err()[0].message,
+        "Query is nested too deep."
+    );
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(4)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({ "obj": { "obj": { "obj": { "value": 1 } } } })
+    );
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(3)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err(),
+        vec![ServerError {
+            message: "Query is too complex.".to_owned(),
+            locations: vec![Pos { line: 1, column: 1 }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(4)
+        .finish();
+    assert!(schema.execute(query).await.is_ok());
+}
+
+#[async_std::test]
+pub async fn test_limit_query_length() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_query_length(11)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 1 })
+    );
+
+    // Rejected before parsing, so the query is not reported as invalid syntax.
+    assert_eq!(
+        schema
+            .execute("{ value value")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Query is too long.".to_string(),
+            locations: Vec::new(),
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_operation_and_fragments() {
+    use async_graphql::parser::types::Selection;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn selections(&self, ctx: &Context<'_>) -> Vec<String> {
+            ctx.operation()
+                .node
+                .selection_set
+                .node
+                .items
+                .iter()
+                .map(|selection| match &selection.node {
+                    Selection::Field(field) => field.node.name.node.to_string(),
+                    Selection::FragmentSpread(spread) => {
+                        format!("...{}", spread.node.fragment_name.node)
+                    }
+                    Selection::InlineFragment(_) => "...".to_string(),
+                })
+                .collect()
+        }
+
+        async fn fragments(&self, ctx: &Context<'_>) -> Vec<String> {
+            let mut names = ctx
+                .fragments()
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        }
+
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"
+        query {
+            selections
+            ... A
+            ... on QueryRoot { value }
+        }
+
+        fragment A on QueryRoot {
+            fragments
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "selections": ["selections", "...A", "..."],
+            "fragments": ["A"],
+            "value": 10,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_elapsed() {
+    use std::time::Duration;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        async fn first(&self, ctx: &Context<'_>) -> i64 {
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            ctx.elapsed().as_micros() as i64
+        }
+
+        async fn second(&self, ctx: &Context<'_>) -> i64 {
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            ctx.elapsed().as_micros() as i64
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let data = schema
+        .execute("mutation { first second }")
+        .await
+        .into_result()
+        .unwrap()
+        .data
+        .into_json()
+        .unwrap();
+    let first = data["first"].as_i64().unwrap();
+    let second = data["second"].as_i64().unwrap();
+    assert!(first >= 20_000);
+    assert!(second >= first + 20_000);
+}
+
+#[async_std::test]
+pub async fn test_context_request_cache() {
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Counter(i32);
+
+    fn increment(ctx: &Context<'_>) -> i32 {
+        ctx.with_request_cache(|counter: &Mutex<Counter>| {
+            let mut counter = counter.lock().unwrap();
+            counter.0 += 1;
+            counter.0
+        })
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn a(&self, ctx: &Context<'_>) -> i32 {
+            increment(ctx)
+        }
+
+        async fn b(&self, ctx: &Context<'_>) -> i32 {
+            increment(ctx)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    for _ in 0..2 {
+        let data = schema.execute("{ a b }").await.into_result().unwrap().data;
+        let mut values = match data {
+            Value::Object(obj) => obj.into_iter().map(|(_, value)| value).collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+        values.sort_by_key(|value| value.to_string());
+        assert_eq!(values, vec![value!(1), value!(2)]);
+    }
+
+    let request = Request::new("{ a }").data(Mutex::new(Counter(10)));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "a": 11 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_locals() {
+    struct Total(i32);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn total(&self, ctx: &Context<'_>) -> Option<i32> {
+            ctx.get_local::<Total>().map(|total| total.0)
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn add(&self, ctx: &Context<'_>, n: i32) -> i32 {
+            let total = ctx
+                .get_local::<Total>()
+                .map(|total| total.0)
+                .unwrap_or_default()
+                + n;
+            ctx.set_local(Total(total));
+            total
+        }
+
+        async fn total(&self, ctx: &Context<'_>) -> Option<i32> {
+            ctx.get_local::<Total>().map(|total| total.0)
+        }
+    }
+
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("mutation { a: add(n: 1) b: add(n: 2) total }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "a": 1, "b": 3, "total": 3 })
+    );
+
+    // Locals are not shared between requests
+    assert_eq!(
+        schema
+            .execute("{ total }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "total": null })
+    );
+}
+
+#[async_std::test]
+pub async fn test_catch_panics() {
+    #[derive(SimpleObject)]
+    struct MyObj {
+        a: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 20 }
+        }
+
+        async fn bad(&self) -> i32 {
+            panic!("something went wrong")
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .catch_panics()
+        .finish();
+
+    let resp = schema.execute("{ value obj { a } bad }").await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: "The resolver panicked: something went wrong".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 19
+            }],
+            path: vec![PathSegment::Field("bad".to_string())],
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value obj { a } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 10, "obj": { "a": 20 } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_context_operation() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn operation(&self, ctx: &Context<'_>) -> String {
+            format!(
+                "{} {}",
+                ctx.operation_type(),
+                ctx.operation_name().unwrap_or("<anonymous>")
+            )
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn operation(&self, ctx: &Context<'_>) -> String {
+            assert_eq!(ctx.operation_type(), parser::types::OperationType::Mutation);
+            format!(
+                "{} {}",
+                ctx.operation_type(),
+                ctx.operation_name().unwrap_or("<anonymous>")
+            )
+        }
+    }
+
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute("mutation Foo { operation }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "operation": "mutation Foo" })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ operation }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "operation": "query <anonymous>" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query A { operation } mutation B { operation }").operation_name("A")
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "operation": "query A" })
+    );
+}
@@ -0,0 +1,93 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct Base {
+    id: i32,
+}
+
+#[async_std::test]
+pub async fn test_flatten() {
+    #[derive(SimpleObject)]
+    struct User {
+        #[graphql(flatten)]
+        base: Base,
+        name: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            User {
+                base: Base { id: 1 },
+                name: "abc".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ user { id name ... on User { id } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": { "id": 1, "name": "abc" } })
+    );
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("type User {\n\tid: Int!\n\tname: String!\n}"));
+    assert!(!sdl.contains("type Base"));
+}
+
+#[test]
+#[should_panic(expected = "Field \"id\" of the flattened type \"Base\" is already defined.")]
+pub fn test_flatten_duplicate_field() {
+    #[derive(SimpleObject)]
+    struct User {
+        id: i32,
+        #[graphql(flatten)]
+        base: Base,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            User {
+                id: 1,
+                base: Base { id: 1 },
+            }
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(expected = "Field \"id\" is already defined by a flattened type.")]
+pub fn test_flatten_duplicate_field_after_flatten() {
+    #[derive(SimpleObject)]
+    struct User {
+        #[graphql(flatten)]
+        base: Base,
+        id: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> User {
+            User {
+                base: Base { id: 1 },
+                id: 1,
+            }
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
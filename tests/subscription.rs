@@ -109,6 +109,48 @@ pub async fn test_subscription_with_ctx_data() {
     }
 }
 
+#[async_std::test]
+pub async fn test_subscription_with_filter() {
+    struct MinValue(i32);
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self, ctx: &Context<'_>, even: bool) -> impl Stream<Item = i32> {
+            let min = ctx.data_unchecked::<MinValue>().0;
+            futures_util::stream::iter(0..10)
+                .filter(move |n| futures_util::future::ready(*n >= min && (n % 2 == 0) == even))
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(MinValue(3))
+        .finish();
+
+    {
+        let mut stream = schema
+            .execute_stream("subscription { values(even: true) }")
+            .map(|resp| resp.into_result().unwrap().data)
+            .boxed();
+        for i in &[4, 6, 8] {
+            assert_eq!(value!({ "values": i }), stream.next().await.unwrap());
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    {
+        let mut stream = schema
+            .execute_stream("subscription { values(even: false) }")
+            .map(|resp| resp.into_result().unwrap().data)
+            .boxed();
+        for i in &[3, 5, 7, 9] {
+            assert_eq!(value!({ "values": i }), stream.next().await.unwrap());
+        }
+        assert!(stream.next().await.is_none());
+    }
+}
+
 #[async_std::test]
 pub async fn test_subscription_with_token() {
     struct QueryRoot;
@@ -430,3 +430,127 @@ pub async fn test_query_over_websocket() {
         serde_json::from_str(&stream.next().await.unwrap().unwrap_text()).unwrap()
     );
 }
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_limit_subscriptions() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures_util::stream::pending()
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .limit_subscriptions(2)
+        .finish();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx, WebSocketProtocols::GraphQLWS);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "connection_ack",
+        }),
+    );
+
+    for id in &["1", "2", "3"] {
+        tx.send(
+            serde_json::to_string(&value!({
+                "type": "start",
+                "id": id,
+                "payload": {
+                    "query": "subscription { values }"
+                },
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    }
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "error",
+            "id": "3",
+            "payload": [{ "message": "Too many subscriptions, the limit is 2." }],
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "stop",
+            "id": "1",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "complete",
+            "id": "1",
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "3",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "4",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "error",
+            "id": "4",
+            "payload": [{ "message": "Too many subscriptions, the limit is 2." }],
+        }),
+    );
+}
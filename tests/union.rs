@@ -367,3 +367,75 @@ pub async fn test_union_flatten() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_union_typename_alias() {
+    #[derive(SimpleObject)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Cat {
+        name: String,
+        kind: String,
+    }
+
+    #[derive(Union)]
+    enum Pet {
+        Dog(Dog),
+        Cat(Cat),
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "name", type = "&String"))]
+    enum Named {
+        Dog(Dog),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn pets(&self) -> Vec<Pet> {
+            vec![
+                Dog {
+                    name: "dog".to_string(),
+                }
+                .into(),
+                Cat {
+                    name: "cat".to_string(),
+                    kind: "siamese".to_string(),
+                }
+                .into(),
+            ]
+        }
+
+        async fn named(&self) -> Named {
+            Dog {
+                name: "dog".to_string(),
+            }
+            .into()
+        }
+    }
+
+    let query = "{ pets { __typename kind } named { __typename kind name } }";
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .typename_alias("kind")
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "pets": [
+                { "__typename": "Dog", "kind": "Dog" },
+                // The field defined by the object takes precedence.
+                { "__typename": "Cat", "kind": "siamese" },
+            ],
+            "named": { "__typename": "Dog", "kind": "Dog", "name": "dog" },
+        })
+    );
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema.execute(query).await.into_result().is_err());
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "multipart")]
+
+use async_graphql::http::{receive_body, MultipartOptions};
+use async_graphql::*;
+use futures_util::io::Cursor;
+use std::io::Read;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+fn read_upload(ctx: &Context<'_>, file: Upload) -> Result<String> {
+    let mut content = String::new();
+    file.value(ctx)?.into_read().read_to_string(&mut content)?;
+    Ok(content)
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn single(&self, ctx: &Context<'_>, file: Option<Upload>) -> Result<Option<String>> {
+        file.map(|file| read_upload(ctx, file)).transpose()
+    }
+
+    async fn multiple(
+        &self,
+        ctx: &Context<'_>,
+        files: Vec<Option<Upload>>,
+    ) -> Result<Vec<Option<String>>> {
+        files
+            .into_iter()
+            .map(|file| file.map(|file| read_upload(ctx, file)).transpose())
+            .collect()
+    }
+
+    async fn info(&self, ctx: &Context<'_>, file: Upload) -> String {
+        format!(
+            "{:?} {:?} {:?}",
+            file.filename(ctx),
+            file.content_type(ctx),
+            file.size(ctx)
+        )
+    }
+}
+
+async fn receive_multipart(operations: &str, map: &str, files: &[(&str, &str)]) -> Request {
+    let mut body = format!(
+        "--xyz\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n{}\r\n\
+         --xyz\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n{}\r\n",
+        operations, map
+    );
+    for (name, content) in files {
+        body.push_str(&format!(
+            "--xyz\r\nContent-Disposition: form-data; name=\"{0}\"; filename=\"{0}.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n{1}\r\n",
+            name, content
+        ));
+    }
+    body.push_str("--xyz--\r\n");
+
+    receive_body(
+        Some("multipart/form-data; boundary=xyz"),
+        Cursor::new(body.into_bytes()),
+        MultipartOptions::default(),
+    )
+    .await
+    .unwrap()
+}
+
+#[async_std::test]
+pub async fn test_upload_optional() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let request = receive_multipart(
+        r#"{ "query": "mutation($file: Upload) { single(file: $file) }", "variables": {} }"#,
+        "{}",
+        &[],
+    )
+    .await;
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "single": null })
+    );
+
+    let request = receive_multipart(
+        r#"{ "query": "mutation($file: Upload) { single(file: $file) }", "variables": { "file": null } }"#,
+        r#"{ "0": ["variables.file"] }"#,
+        &[("0", "hello")],
+    )
+    .await;
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "single": "hello" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_upload_list_with_null_slot() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let request = receive_multipart(
+        r#"{
+            "query": "mutation($files: [Upload]!) { multiple(files: $files) }",
+            "variables": { "files": [null, null, null] }
+        }"#,
+        r#"{ "0": ["variables.files.2"], "1": ["variables.files.0"] }"#,
+        &[("0", "third"), ("1", "first")],
+    )
+    .await;
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "multiple": ["first", null, "third"] })
+    );
+}
+
+#[async_std::test]
+pub async fn test_upload_forged_reference() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let request = Request::new(r##"mutation { single(file: "#__graphql_file__:5") }"##);
+    let errors = schema.execute(request).await.errors;
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("the upload does not exist"));
+}
+
+#[async_std::test]
+pub async fn test_upload_metadata() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let request = receive_multipart(
+        r#"{ "query": "mutation($file: Upload!) { info(file: $file) }", "variables": { "file": null } }"#,
+        r#"{ "0": ["variables.file"] }"#,
+        &[("0", "hello")],
+    )
+    .await;
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "info": r#"Some("0.txt") Some("text/plain") Some(5)"# })
+    );
+}
@@ -0,0 +1,54 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_all_validation_errors_reported() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn add(&self, a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        async fn double(&self, n: i32) -> i32 {
+            n * 2
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ add(a: "one", b: 2) double unknown }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![
+            ServerError {
+                message: r#"Invalid value for argument "a", expected type "Int""#.to_string(),
+                locations: vec![Pos { line: 1, column: 7 }],
+                path: Vec::new(),
+                extensions: None,
+            },
+            ServerError {
+                message:
+                    r#"Field "double" argument "n" of type "Query" is required but not provided"#
+                        .to_string(),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 23
+                }],
+                path: Vec::new(),
+                extensions: None,
+            },
+            ServerError {
+                message: r#"Unknown field "unknown" on type "Query"."#.to_string(),
+                locations: vec![Pos {
+                    line: 1,
+                    column: 30
+                }],
+                path: Vec::new(),
+                extensions: None,
+            },
+        ]
+    );
+}
@@ -297,3 +297,244 @@ pub async fn test_variables_json() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_field_arguments() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn args(
+            &self,
+            ctx: &Context<'_>,
+            _a: i32,
+            _b: String,
+            _c: Vec<i32>,
+            _d: Option<bool>,
+        ) -> OutputJson<std::collections::BTreeMap<String, Value>> {
+            OutputJson(ctx.field_arguments().unwrap())
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = Request::new(
+        r#"
+            query QueryWithVariables($b: String!, $c: [Int!]!) {
+                args(a: 10, b: $b, c: $c)
+            }
+        "#,
+    )
+    .variables(Variables::from_value(value!({
+        "b": "abc",
+        "c": [1, 2, 3],
+    })));
+
+    assert_eq!(
+        schema.execute(query).await.data,
+        value!({
+            "args": {
+                "a": 10,
+                "b": "abc",
+                "c": [1, 2, 3],
+            },
+        })
+    );
+}
+
+#[test]
+pub fn test_variables_set_path() {
+    let mut variables = Variables::from_value(value!({
+        "a": {
+            "b": 1,
+        },
+        "c": [1, 2, 3],
+    }));
+
+    assert!(variables.set_path("variables.a.b", value!("abc")));
+    assert!(variables.set_path("variables.c.1", value!({ "d": true })));
+    assert!(variables.set_path("variables.c.1.d", value!(false)));
+
+    assert!(!variables.set_path("variables.a.x", value!(10)));
+    assert!(!variables.set_path("variables.c.3", value!(10)));
+    assert!(!variables.set_path("variables.c.a", value!(10)));
+    assert!(!variables.set_path("variables.a.b.c", value!(10)));
+    assert!(!variables.set_path("a.b", value!(10)));
+
+    assert_eq!(
+        variables.into_value(),
+        value!({
+            "a": {
+                "b": "abc",
+            },
+            "c": [1, { "d": false }, 3],
+        })
+    );
+}
+
+#[test]
+pub fn test_variables_typed_accessors() {
+    let mut variables = Variables::default();
+    assert!(variables.get::<i32>("a").is_none());
+
+    variables.set("a", 10);
+    variables.set("b", vec!["x".to_string(), "y".to_string()]);
+    assert_eq!(variables.get::<i32>("a").unwrap().unwrap(), 10);
+    assert_eq!(
+        variables.get::<Vec<String>>("b").unwrap().unwrap(),
+        vec!["x".to_string(), "y".to_string()]
+    );
+    assert!(variables.get::<i32>("b").unwrap().is_err());
+
+    *variables.get_mut("a").unwrap() = value!(20);
+    assert_eq!(variables.get::<i32>("a").unwrap().unwrap(), 20);
+    assert!(variables.get_mut("c").is_none());
+}
+
+#[async_std::test]
+pub async fn test_variables_set_before_execution() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, n: i32) -> i32 {
+            n * 2
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let mut request = Request::new("query($n: Int!) { value(n: $n) }");
+    request.variables.set("n", 21);
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "value": 42 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_variables_lenient_numbers() {
+    #[derive(InputObject)]
+    struct MyInput {
+        id: i64,
+        scores: Vec<f64>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int_val(&self, value: i64) -> i64 {
+            value
+        }
+
+        async fn float_val(&self, value: f64) -> f64 {
+            value
+        }
+
+        async fn input_val(&self, input: MyInput) -> String {
+            format!("{} {:?}", input.id, input.scores)
+        }
+    }
+
+    let query = r#"
+        query($int: Int!, $float: Float!, $input: MyInput!) {
+            intVal(value: $int)
+            floatVal(value: $float)
+            inputVal(input: $input)
+        }
+    "#;
+    let variables = value!({
+        "int": "9007199254740993",
+        "float": "1.5",
+        "input": { "id": "42", "scores": ["2", 0.5] },
+    });
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ floatVal(value: 42) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "floatVal": 42.0 })
+    );
+    assert!(schema
+        .execute(Request::new(query).variables(Variables::from_value(variables.clone())))
+        .await
+        .is_err());
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .lenient_numbers()
+        .finish();
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(Variables::from_value(variables)))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "intVal": 9007199254740993i64,
+            "floatVal": 1.5,
+            "inputVal": "42 [2.0, 0.5]",
+        })
+    );
+    assert!(schema
+        .execute(
+            Request::new("query($int: Int!) { intVal(value: $int) }")
+                .variables(Variables::from_value(value!({ "int": "abc" })))
+        )
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_variables_lenient_numbers_selected_operation() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn int_val(&self, value: i32) -> i32 {
+            value
+        }
+
+        async fn str_val(&self, value: String) -> String {
+            value
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .lenient_numbers()
+        .finish();
+    let query = r#"
+        query A($v: String!) { strVal(value: $v) }
+        query B($v: Int!) { intVal(value: $v) }
+    "#;
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .operation_name("A")
+                    .variables(Variables::from_value(value!({ "v": "42" })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "strVal": "42" })
+    );
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .operation_name("B")
+                    .variables(Variables::from_value(value!({ "v": "42" })))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "intVal": 42 })
+    );
+}
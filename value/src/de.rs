@@ -49,6 +49,7 @@ impl ConstValue {
             ConstValue::Enum(v) => Unexpected::Str(v),
             ConstValue::List(_) => Unexpected::Seq,
             ConstValue::Object(_) => Unexpected::Map,
+            ConstValue::RawJson(_) => Unexpected::Other("raw JSON"),
         }
     }
 }
@@ -109,6 +110,7 @@ impl<'de> de::Deserializer<'de> for ConstValue {
             ConstValue::Enum(v) => visitor.visit_str(v.as_str()),
             ConstValue::List(v) => visit_array(v, visitor),
             ConstValue::Object(v) => visit_object(v, visitor),
+            ConstValue::RawJson(v) => v.parse().deserialize_any(visitor),
         }
     }
 
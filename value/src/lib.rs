@@ -8,15 +8,18 @@ mod macros;
 mod ser;
 
 use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::convert::{TryFrom, TryInto};
-use std::fmt::{self, Display, Formatter, Write};
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::ops::Deref;
 use std::sync::Arc;
 
 use serde::ser::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
 
 pub use de::{from_value, DeserializerError};
 pub use ser::{to_value, SerializerError};
@@ -25,37 +28,78 @@ pub use serde_json::Number;
 /// A GraphQL name.
 ///
 /// [Reference](https://spec.graphql.org/June2018/#Name).
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Name(Arc<str>);
+#[derive(Clone)]
+pub struct Name(NameRepr);
+
+#[derive(Clone)]
+enum NameRepr {
+    Static(&'static str),
+    Shared(Arc<str>),
+}
 
 impl Serialize for Name {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.0)
+        serializer.serialize_str(self.as_str())
     }
 }
 
 impl Name {
     /// Create a new name.
     pub fn new(name: impl AsRef<str>) -> Self {
-        Self(name.as_ref().into())
+        Self(NameRepr::Shared(name.as_ref().into()))
+    }
+
+    /// Create a name from a string known at compile time, without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not a [valid](#method.is_valid) name.
+    #[must_use]
+    pub fn from_static(name: &'static str) -> Self {
+        assert!(Self::is_valid(name), "\"{}\" is not a valid name", name);
+        Self(NameRepr::Static(name))
     }
 
     /// Get the name as a string.
     #[must_use]
     pub fn as_str(&self) -> &str {
-        &self.0
+        match &self.0 {
+            NameRepr::Static(name) => name,
+            NameRepr::Shared(name) => name,
+        }
+    }
+
+    /// Check whether the string is a valid GraphQL name, i.e. it matches `/[_A-Za-z][_0-9A-Za-z]*/`.
+    #[must_use]
+    pub fn is_valid(name: &str) -> bool {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(c) if c == '_' || c.is_ascii_alphabetic() => {}
+            _ => return false,
+        }
+        chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+    }
+
+    /// Check whether the string is a valid name for a user defined type, field, argument or enum
+    /// value.
+    ///
+    /// This is the same as [`is_valid`](#method.is_valid), but additionally rejects names
+    /// starting with `__`, which are reserved by GraphQL's introspection system.
+    #[must_use]
+    pub fn is_valid_user_defined(name: &str) -> bool {
+        Self::is_valid(name) && !name.starts_with("__")
     }
 }
 
 impl AsRef<str> for Name {
     fn as_ref(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
 impl Borrow<str> for Name {
     fn borrow(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
@@ -63,13 +107,45 @@ impl Deref for Name {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_str()
     }
 }
 
 impl Display for Name {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(&self.0, f)
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for Name {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Name").field(&self.as_str()).finish()
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Name {}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
     }
 }
 
@@ -106,16 +182,20 @@ impl<'a> PartialEq<Name> for &'a str {
 
 impl<'de> Deserialize<'de> for Name {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Ok(Self(
+        Ok(Self(NameRepr::Shared(
             String::deserialize(deserializer)?.into_boxed_str().into(),
-        ))
+        )))
     }
 }
 
 /// A resolved GraphQL value, for example `1` or `"Hello World!"`.
 ///
 /// It can be serialized and deserialized. Enums will be converted to strings. Attempting to
-/// serialize `Upload` will fail, and `Enum` and `Upload` cannot be deserialized.
+/// serialize `Upload` will fail, and `Enum`, `RawJson` and `Upload` cannot be deserialized.
+///
+/// By default a number that doesn't fit in an `i64` or `u64`, such as `12345678901234567890123`,
+/// is stored as the nearest `f64`. Enable the `arbitrary_precision` feature to keep its exact
+/// digits instead, through `Display` and `into_json`/`from_json`.
 ///
 /// [Reference](https://spec.graphql.org/June2018/#Value).
 #[derive(Clone, Debug, Eq, Serialize, Deserialize)]
@@ -136,6 +216,11 @@ pub enum ConstValue {
     List(Vec<ConstValue>),
     /// An object. This is a map of keys to values.
     Object(BTreeMap<Name, ConstValue>),
+    /// JSON text, which `serde_json` serializes verbatim. It is only created by
+    /// [`from_raw_json`](#method.from_raw_json), it never comes from a query document or from
+    /// variables.
+    #[serde(skip_deserializing)]
+    RawJson(RawJsonValue),
 }
 
 impl PartialEq for ConstValue {
@@ -170,6 +255,7 @@ impl PartialEq for ConstValue {
 
                 true
             }
+            (ConstValue::RawJson(a), ConstValue::RawJson(b)) => a == b,
             _ => false,
         }
     }
@@ -273,7 +359,41 @@ impl ConstValue {
                     .map(|(key, value)| (key, value.into_value()))
                     .collect(),
             ),
+            Self::RawJson(json) => json.parse().into_value(),
+        }
+    }
+
+    /// Convert a string containing a number, such as `"42"` or `"1.5"`, into that number. Any other
+    /// value, including a string that is not a finite number, is returned unchanged.
+    ///
+    /// GraphQL doesn't coerce strings to numbers, this is meant for servers that choose to accept
+    /// them from clients that can't send numbers.
+    ///
+    /// Integers don't need to be converted to be used as floats, a `Number` holding an integer
+    /// can always be read with `Number::as_f64`.
+    #[must_use]
+    pub fn coerce_numeric_string(self) -> Self {
+        if let ConstValue::String(s) = &self {
+            if let Ok(n) = s.parse::<i64>() {
+                return ConstValue::Number(n.into());
+            }
+            if let Ok(n) = s.parse::<u64>() {
+                return ConstValue::Number(n.into());
+            }
+            if let Some(n) = s.parse::<f64>().ok().and_then(Number::from_f64) {
+                return ConstValue::Number(n);
+            }
         }
+        self
+    }
+
+    /// Create a value that `serde_json` serializes as the given JSON text, verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the text is not valid JSON.
+    pub fn from_raw_json(json: impl Into<String>) -> serde_json::Result<Self> {
+        RawValue::from_string(json.into()).map(|json| ConstValue::RawJson(RawJsonValue(json)))
     }
 
     /// Attempt to convert the value into JSON. This is equivalent to the `TryFrom` implementation.
@@ -293,6 +413,51 @@ impl ConstValue {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Returns an iterator over the entries of the object, or `None` if this is not an object.
+    pub fn as_object_iter(&self) -> Option<impl Iterator<Item = (&Name, &ConstValue)>> {
+        match self {
+            Self::Object(map) => Some(map.iter()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the entries of the object, which is empty if this is not an
+    /// object.
+    pub fn entries(&self) -> impl Iterator<Item = (&Name, &ConstValue)> {
+        self.as_object_iter().into_iter().flatten()
+    }
+
+    /// Apply a [JSON Merge Patch](https://tools.ietf.org/html/rfc7386) to this value.
+    ///
+    /// If the patch is an object, its fields are merged recursively into this value, which is
+    /// first replaced with an empty object if it is not one. Fields whose value is `null` are
+    /// removed. Any other patch, including a list, replaces this value.
+    pub fn apply_merge_patch(&mut self, patch: &ConstValue) {
+        let patch = match patch {
+            ConstValue::Object(patch) => patch,
+            _ => {
+                *self = patch.clone();
+                return;
+            }
+        };
+
+        if !matches!(self, ConstValue::Object(_)) {
+            *self = ConstValue::Object(BTreeMap::new());
+        }
+        if let ConstValue::Object(target) = self {
+            for (name, value) in patch {
+                if let ConstValue::Null = value {
+                    target.remove(name);
+                } else {
+                    target
+                        .entry(name.clone())
+                        .or_insert(ConstValue::Null)
+                        .apply_merge_patch(value);
+                }
+            }
+        }
+    }
 }
 
 impl Default for ConstValue {
@@ -312,6 +477,7 @@ impl Display for ConstValue {
             Self::Enum(name) => f.write_str(name),
             Self::List(items) => write_list(items, f),
             Self::Object(map) => write_object(map, f),
+            Self::RawJson(json) => json.parse().fmt(f),
         }
     }
 }
@@ -420,6 +586,20 @@ impl Value {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Returns an iterator over the entries of the object, or `None` if this is not an object.
+    pub fn as_object_iter(&self) -> Option<impl Iterator<Item = (&Name, &Value)>> {
+        match self {
+            Self::Object(map) => Some(map.iter()),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the entries of the object, which is empty if this is not an
+    /// object.
+    pub fn entries(&self) -> impl Iterator<Item = (&Name, &Value)> {
+        self.as_object_iter().into_iter().flatten()
+    }
 }
 
 impl Default for Value {
@@ -463,6 +643,37 @@ impl TryFrom<Value> for serde_json::Value {
     }
 }
 
+/// The text of a [`ConstValue::RawJson`](enum.ConstValue.html#variant.RawJson), which is always
+/// valid JSON.
+#[derive(Clone, Debug)]
+pub struct RawJsonValue(Box<RawValue>);
+
+impl RawJsonValue {
+    /// Get the JSON text.
+    #[must_use]
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    pub(crate) fn parse(&self) -> ConstValue {
+        serde_json::from_str(self.get()).unwrap_or(ConstValue::Null)
+    }
+}
+
+impl PartialEq for RawJsonValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawJsonValue {}
+
+impl Serialize for RawJsonValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
 fn fail_serialize_variable<S: Serializer>(_: &str, _: S) -> Result<S::Ok, S::Error> {
     Err(S::Error::custom("cannot serialize variable"))
 }
@@ -476,12 +687,20 @@ fn write_quoted(s: &str, f: &mut Formatter<'_>) -> fmt::Result {
             '\t' => f.write_str("\\t"),
             '"' => f.write_str("\\\""),
             '\\' => f.write_str("\\\\"),
-            c if c.is_control() => write!(f, "\\u{:04}", c as u32),
+            // Line and paragraph separators are valid in JSON strings, but not in JavaScript.
+            '\u{2028}' | '\u{2029}' => write_escaped_char(c, f),
+            c if c.is_control() => write_escaped_char(c, f),
             c => f.write_char(c),
         }?
     }
     f.write_char('"')
 }
+
+/// Write a char as a `\uXXXX` escape. Only used for chars in the Basic Multilingual Plane.
+fn write_escaped_char(c: char, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "\\u{:04x}", c as u32)
+}
+
 fn write_list<T: Display>(list: impl IntoIterator<Item = T>, f: &mut Formatter<'_>) -> fmt::Result {
     f.write_char('[')?;
     for item in list {
@@ -500,3 +719,226 @@ fn write_object<K: Display, V: Display>(
     }
     f.write_char('}')
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_is_valid() {
+        assert!(Name::is_valid("foo"));
+        assert!(Name::is_valid("_foo1"));
+        assert!(Name::is_valid("__foo"));
+        assert!(!Name::is_valid(""));
+        assert!(!Name::is_valid("1foo"));
+        assert!(!Name::is_valid("foo-bar"));
+
+        assert!(Name::is_valid_user_defined("foo"));
+        assert!(Name::is_valid_user_defined("_foo"));
+        assert!(!Name::is_valid_user_defined("__foo"));
+        assert!(!Name::is_valid_user_defined("1foo"));
+    }
+
+    #[test]
+    fn test_coerce_numeric_string() {
+        let coerce = |s: &str| ConstValue::String(s.to_string()).coerce_numeric_string();
+        assert_eq!(coerce("42"), ConstValue::Number((42).into()));
+        assert_eq!(coerce("-1"), ConstValue::Number((-1).into()));
+        assert_eq!(
+            coerce("18446744073709551615"),
+            ConstValue::Number(u64::MAX.into())
+        );
+        assert_eq!(
+            coerce("1.5"),
+            ConstValue::from_json(serde_json::json!(1.5)).unwrap()
+        );
+        assert_eq!(coerce("abc"), ConstValue::String("abc".to_string()));
+        assert_eq!(coerce("NaN"), ConstValue::String("NaN".to_string()));
+        assert_eq!(
+            ConstValue::Boolean(true).coerce_numeric_string(),
+            ConstValue::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn test_name_from_static() {
+        let names = (0..1000)
+            .map(|i| if i % 2 == 0 { "value" } else { "other" })
+            .collect::<Vec<_>>();
+        let static_names = names
+            .iter()
+            .copied()
+            .map(Name::from_static)
+            .collect::<Vec<_>>();
+        let shared_names = names.iter().map(Name::new).collect::<Vec<_>>();
+        assert_eq!(static_names, shared_names);
+
+        let name = Name::from_static("value");
+        assert_eq!(name, Name::new("value"));
+        assert_eq!(name.as_str(), "value");
+        assert_eq!(&*name, "value");
+        assert_eq!(name, "value");
+        assert_eq!(format!("{:?}", name), format!("{:?}", Name::new("value")));
+        assert!(Name::from_static("a") < Name::new("b"));
+
+        let mut map = BTreeMap::new();
+        map.insert(Name::new("value"), 1);
+        assert_eq!(map.get(&name), Some(&1));
+        assert_eq!(map.get("value"), Some(&1));
+
+        let hash = |name: &Name| {
+            use std::collections::hash_map::DefaultHasher;
+            let mut hasher = DefaultHasher::new();
+            name.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&name), hash(&Name::new("value")));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1a\" is not a valid name")]
+    fn test_name_from_static_invalid() {
+        let _ = Name::from_static("1a");
+    }
+
+    #[test]
+    fn test_display_escape() {
+        assert_eq!(
+            ConstValue::String("a\u{2028}b\u{2029}c".to_string()).to_string(),
+            r#""a\u2028b\u2029c""#
+        );
+        assert_eq!(
+            Value::String("\u{2028}".to_string()).to_string(),
+            r#""\u2028""#
+        );
+        assert_eq!(
+            ConstValue::String("\u{1}\u{1f}\u{7f}\u{9f}".to_string()).to_string(),
+            r#""\u0001\u001f\u007f\u009f""#
+        );
+    }
+
+    #[test]
+    fn test_display_escape_is_valid_json() {
+        assert_eq!(
+            ConstValue::String("\u{1b}\u{1f600}".to_string()).to_string(),
+            "\"\\u001b\u{1f600}\""
+        );
+
+        for s in &["\u{1b}", "\u{1f600}", "a\u{0}\u{2028}\u{1f600}b"] {
+            let displayed = ConstValue::String(s.to_string()).to_string();
+            assert_eq!(serde_json::from_str::<String>(&displayed).unwrap(), *s);
+        }
+    }
+
+    #[test]
+    fn test_apply_merge_patch() {
+        let mut value = ConstValue::from_json(serde_json::json!({
+            "a": "b",
+            "c": { "d": "e", "f": "g" },
+            "h": [1, 2, 3],
+        }))
+        .unwrap();
+        value.apply_merge_patch(
+            &ConstValue::from_json(serde_json::json!({
+                "a": "z",
+                "c": { "f": null, "x": { "y": 1 } },
+                "h": [4],
+                "i": null,
+            }))
+            .unwrap(),
+        );
+        assert_eq!(
+            value.into_json().unwrap(),
+            serde_json::json!({
+                "a": "z",
+                "c": { "d": "e", "x": { "y": 1 } },
+                "h": [4],
+            })
+        );
+
+        let mut value = ConstValue::from_json(serde_json::json!([1, 2])).unwrap();
+        value.apply_merge_patch(
+            &ConstValue::from_json(serde_json::json!({ "a": { "b": null } })).unwrap(),
+        );
+        assert_eq!(value.into_json().unwrap(), serde_json::json!({ "a": {} }));
+
+        let mut value = ConstValue::from_json(serde_json::json!({ "a": 1 })).unwrap();
+        value.apply_merge_patch(&ConstValue::from_json(serde_json::json!("abc")).unwrap());
+        assert_eq!(value.into_json().unwrap(), serde_json::json!("abc"));
+    }
+
+    #[test]
+    fn test_object_entries() {
+        let value = ConstValue::from_json(serde_json::json!({
+            "a": 1,
+            "b": { "c": "d", "e": [1, 2] },
+        }))
+        .unwrap();
+
+        let entries = value
+            .entries()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], ("a", &ConstValue::from(1)));
+
+        let nested = entries[1]
+            .1
+            .as_object_iter()
+            .unwrap()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>();
+        assert_eq!(nested, vec![r#"c="d""#, "e=[1,2,]"]);
+
+        assert!(ConstValue::from(1).as_object_iter().is_none());
+        assert!(ConstValue::from(vec![1, 2]).as_object_iter().is_none());
+        assert_eq!(ConstValue::Null.entries().count(), 0);
+
+        let value = Value::Object(
+            vec![(Name::new("x"), Value::Variable(Name::new("y")))]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(
+            value.entries().collect::<Vec<_>>(),
+            vec![(&Name::new("x"), &Value::Variable(Name::new("y")))]
+        );
+        assert!(Value::Null.as_object_iter().is_none());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_arbitrary_precision() {
+        for digits in &[
+            "12345678901234567890",
+            "-12345678901234567890123",
+            "0.1000000000000000000001",
+        ] {
+            let number: Number = digits.parse().unwrap();
+            let value = ConstValue::Number(number);
+            assert_eq!(value.to_string(), *digits);
+
+            let json = value.clone().into_json().unwrap();
+            assert_eq!(json.to_string(), *digits);
+            assert_eq!(ConstValue::from_json(json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_raw_json() {
+        let value = ConstValue::from_raw_json(r#"{ "a": [1, 2.50] }"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{ "a": [1, 2.50] }"#
+        );
+        assert_eq!(value.to_string(), "{a: [1,2.5,],}");
+        assert_eq!(
+            value.into_json().unwrap(),
+            serde_json::json!({ "a": [1, 2.5] })
+        );
+        assert!(ConstValue::from_raw_json("{ not json").is_err());
+        assert!(serde_json::from_str::<ConstValue>(r#"{ "a": 1 }"#)
+            .map(|value| matches!(value, ConstValue::Object(_)))
+            .unwrap());
+    }
+}
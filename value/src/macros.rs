@@ -1,4 +1,43 @@
-/// Construct a `ConstValue`.
+/// Construct a `ConstValue` from a JSON-like literal.
+///
+/// Any other expression is converted with [`to_value`](fn.to_value.html), and an enum value is
+/// written as `enum NAME`. Call [`ConstValue::into_value`](enum.ConstValue.html#method.into_value)
+/// on the result to get a `Value`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use async_graphql_value::{value, ConstValue, Name};
+///
+/// let value = value!({ "a": [1, 2], "b": null });
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(
+///     Name::new("a"),
+///     ConstValue::List(vec![ConstValue::Number(1.into()), ConstValue::Number(2.into())]),
+/// );
+/// map.insert(Name::new("b"), ConstValue::Null);
+/// assert_eq!(value, ConstValue::Object(map));
+///
+/// let role = "ADMIN";
+/// assert_eq!(
+///     value!({ "roles": [enum ADMIN, role], "count": 1 + 1 }),
+///     ConstValue::Object({
+///         let mut map = BTreeMap::new();
+///         map.insert(
+///             Name::new("roles"),
+///             ConstValue::List(vec![
+///                 ConstValue::Enum(Name::new("ADMIN")),
+///                 ConstValue::String("ADMIN".to_string()),
+///             ]),
+///         );
+///         map.insert(Name::new("count"), ConstValue::Number(2.into()));
+///         map
+///     }),
+/// );
+/// ```
 #[macro_export]
 macro_rules! value {
     ($($json:tt)+) => {
@@ -34,6 +73,11 @@ macro_rules! value_internal {
         $crate::value_internal!(@array [$($elems,)* $crate::value_internal!(false)] $($rest)*)
     };
 
+    // Next element is an enum value.
+    (@array [$($elems:expr,)*] enum $name:ident $($rest:tt)*) => {
+        $crate::value_internal!(@array [$($elems,)* $crate::value_internal!(enum $name)] $($rest)*)
+    };
+
     // Next element is an array.
     (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
         $crate::value_internal!(@array [$($elems,)* $crate::value_internal!([$($array)*])] $($rest)*)
@@ -98,6 +142,11 @@ macro_rules! value_internal {
         $crate::value_internal!(@object $object [$($key)+] ($crate::value_internal!(false)) $($rest)*);
     };
 
+    // Next value is an enum value.
+    (@object $object:ident ($($key:tt)+) (: enum $name:ident $($rest:tt)*) $copy:tt) => {
+        $crate::value_internal!(@object $object [$($key)+] ($crate::value_internal!(enum $name)) $($rest)*);
+    };
+
     // Next value is an array.
     (@object $object:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*) $copy:tt) => {
         $crate::value_internal!(@object $object [$($key)+] ($crate::value_internal!([$($array)*])) $($rest)*);
@@ -177,6 +226,10 @@ macro_rules! value_internal {
         $crate::ConstValue::Boolean(false)
     };
 
+    (enum $name:ident) => {
+        $crate::ConstValue::Enum($crate::Name::new(stringify!($name)))
+    };
+
     ([]) => {
         $crate::ConstValue::List($crate::value_internal_vec![])
     };
@@ -260,4 +313,23 @@ mod tests {
             ConstValue::Object(map)
         });
     }
+
+    #[test]
+    fn test_macro_enum() {
+        let a = ConstValue::Enum(Name::new("A"));
+        assert!(matches!(value!(enum A), ConstValue::Enum(name) if name == "A"));
+        assert!(matches!(
+            value!([enum A, enum B]),
+            ConstValue::List(items) if matches!(&items[1], ConstValue::Enum(name) if name == "B")
+        ));
+        assert!(matches!(
+            value!({ "a": enum A, "b": [enum A] }),
+            ConstValue::Object(map) if matches!(&map["a"], ConstValue::Enum(name) if name == "A")
+        ));
+        assert_eq!(value!({ "a": enum A }), {
+            let mut map = BTreeMap::new();
+            map.insert(Name::new("a"), a);
+            ConstValue::Object(map)
+        });
+    }
 }